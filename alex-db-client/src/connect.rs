@@ -25,18 +25,12 @@ pub async fn connect<'a>(
                 .map_err(|_| ClientError::String("API key parse error"))?;
             api_key = api_key.replace('\n', "");
 
-            match Uuid::parse_str(&api_key) {
-                Err(_) => None,
-                Ok(api_key) => Some(api_key),
-            }
+            Uuid::parse_str(&api_key).ok()
         }
-        Some(api_key) => match Uuid::parse_str(api_key) {
-            Err(_) => None,
-            Ok(api_key) => Some(api_key),
-        },
+        Some(api_key) => Uuid::parse_str(api_key).ok(),
     };
 
-    for mut connection in context.connections.iter_mut() {
+    for connection in context.connections.iter_mut() {
         connection.is_default = false;
     }
 
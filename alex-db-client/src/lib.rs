@@ -1,6 +1,6 @@
 use crate::context::Context;
 use reedline_repl_rs::{
-    clap::{Arg, Command},
+    clap::{Arg, ArgAction, Command},
     {Repl, Result},
 };
 
@@ -23,20 +23,42 @@ pub async fn run() -> Result<()> {
                 .about("Append value"),
             |args, context| Box::pin(requests::values::append(args, context)),
         )
+        .with_command_async(
+            Command::new("cas")
+                .arg(Arg::new("key").required(true))
+                .arg(Arg::new("expected").required(true))
+                .arg(Arg::new("new").required(true))
+                .display_order(2)
+                .about("Replace value if it equals the expected value"),
+            |args, context| Box::pin(requests::values::compare_and_swap(args, context)),
+        )
         .with_command_async(
             Command::new("connect")
                 .arg(Arg::new("address").required(true))
                 .arg(Arg::new("api_key").required(false))
-                .display_order(2)
+                .display_order(3)
                 .about("Connect to database server"),
             |args, context| Box::pin(connect::connect(args, context)),
         )
+        .with_command_async(
+            Command::new("copy")
+                .arg(Arg::new("key").required(true))
+                .arg(Arg::new("to").required(true))
+                .arg(
+                    Arg::new("overwrite")
+                        .long("overwrite")
+                        .action(ArgAction::SetTrue),
+                )
+                .display_order(4)
+                .about("Copy value to a new key, use --overwrite to replace an existing one"),
+            |args, context| Box::pin(requests::values::copy(args, context)),
+        )
         .with_command_async(
             Command::new("create")
                 .arg(Arg::new("key").required(true))
                 .arg(Arg::new("value").required(true))
                 .arg(Arg::new("ttl").required(false))
-                .display_order(3)
+                .display_order(5)
                 .about("Create value"),
             |args, context| Box::pin(requests::values::create(args, context)),
         )
@@ -44,40 +66,82 @@ pub async fn run() -> Result<()> {
             Command::new("decrement")
                 .arg(Arg::new("key").required(true))
                 .arg(Arg::new("decrement").required(false))
-                .display_order(4)
+                .display_order(6)
                 .about("Decrement value"),
             |args, context| Box::pin(requests::values::decrement(args, context)),
         )
         .with_command_async(
             Command::new("delete")
                 .arg(Arg::new("key").required(true))
-                .display_order(5)
+                .display_order(7)
                 .about("Delete value"),
             |args, context| Box::pin(requests::values::delete(args, context)),
         )
+        .with_command_async(
+            Command::new("delete_field")
+                .arg(Arg::new("key").required(true))
+                .arg(Arg::new("field").required(true))
+                .display_order(8)
+                .about("Delete field of object value"),
+            |args, context| Box::pin(requests::values::delete_field(args, context)),
+        )
+        .with_command_async(
+            Command::new("expire")
+                .arg(Arg::new("key").required(true))
+                .arg(Arg::new("ttl").required(true))
+                .display_order(9)
+                .about("Set value expiration in seconds, in milliseconds with the ms suffix or as a RFC 3339 timestamp"),
+            |args, context| Box::pin(requests::values::expire(args, context)),
+        )
+        .with_command_async(
+            Command::new("fields")
+                .arg(Arg::new("key").required(true))
+                .display_order(10)
+                .about("List fields of object value"),
+            |args, context| Box::pin(requests::values::fields(args, context)),
+        )
         .with_command_async(
             Command::new("increment")
                 .arg(Arg::new("key").required(true))
                 .arg(Arg::new("increment").required(false))
-                .display_order(6)
+                .display_order(11)
                 .about("Increment value"),
             |args, context| Box::pin(requests::values::increment(args, context)),
         )
+        .with_command_async(
+            Command::new("increment_field")
+                .arg(Arg::new("key").required(true))
+                .arg(Arg::new("field").required(true))
+                .arg(Arg::new("increment").required(false))
+                .display_order(12)
+                .about("Increment field of object value"),
+            |args, context| Box::pin(requests::values::increment_field(args, context)),
+        )
         .with_command_async(
             Command::new("list")
                 .arg(Arg::new("sort").required(false))
                 .arg(Arg::new("direction").required(false))
                 .arg(Arg::new("limit").required(false))
                 .arg(Arg::new("page").required(false))
-                .display_order(7)
+                .arg(Arg::new("cursor").long("cursor").required(false))
+                .arg(Arg::new("prefix").long("prefix").required(false))
+                .arg(Arg::new("pattern").long("pattern").required(false))
+                .display_order(13)
                 .about("List values"),
             |args, context| Box::pin(requests::values::list(args, context)),
         )
+        .with_command_async(
+            Command::new("persist")
+                .arg(Arg::new("key").required(true))
+                .display_order(14)
+                .about("Remove value expiration"),
+            |args, context| Box::pin(requests::values::persist(args, context)),
+        )
         .with_command_async(
             Command::new("pop_back")
                 .arg(Arg::new("key").required(true))
                 .arg(Arg::new("pop_back").required(false))
-                .display_order(8)
+                .display_order(15)
                 .about("Pop back value"),
             |args, context| Box::pin(requests::values::pop_back(args, context)),
         )
@@ -85,7 +149,7 @@ pub async fn run() -> Result<()> {
             Command::new("pop_front")
                 .arg(Arg::new("key").required(true))
                 .arg(Arg::new("pop_front").required(false))
-                .display_order(9)
+                .display_order(16)
                 .about("Pop front value"),
             |args, context| Box::pin(requests::values::pop_front(args, context)),
         )
@@ -93,40 +157,77 @@ pub async fn run() -> Result<()> {
             Command::new("prepend")
                 .arg(Arg::new("key").required(true))
                 .arg(Arg::new("value").required(true))
-                .display_order(10)
+                .display_order(17)
                 .about("Prepend value"),
             |args, context| Box::pin(requests::values::prepend(args, context)),
         )
         .with_command_async(
             Command::new("read")
                 .arg(Arg::new("key").required(true))
-                .display_order(11)
+                .display_order(18)
                 .about("Read value"),
             |args, context| Box::pin(requests::values::read(args, context)),
         )
+        .with_command_async(
+            Command::new("read_field")
+                .arg(Arg::new("key").required(true))
+                .arg(Arg::new("field").required(true))
+                .display_order(19)
+                .about("Read field of object value"),
+            |args, context| Box::pin(requests::values::read_field(args, context)),
+        )
+        .with_command_async(
+            Command::new("rename")
+                .arg(Arg::new("key").required(true))
+                .arg(Arg::new("to").required(true))
+                .arg(
+                    Arg::new("overwrite")
+                        .long("overwrite")
+                        .action(ArgAction::SetTrue),
+                )
+                .display_order(20)
+                .about("Rename value key, use --overwrite to replace an existing one"),
+            |args, context| Box::pin(requests::values::rename(args, context)),
+        )
         .with_command_async(
             Command::new("test_create")
                 .arg(Arg::new("number").required(true))
                 .arg(Arg::new("ttl").required(false))
-                .display_order(12)
+                .display_order(21)
                 .about("Create test values"),
             |args, context| Box::pin(requests::values::test_create(args, context)),
         )
         .with_command_async(
             Command::new("test_delete")
                 .arg(Arg::new("number").required(true))
-                .display_order(13)
+                .display_order(22)
                 .about("Delete test values"),
             |args, context| Box::pin(requests::values::test_delete(args, context)),
         )
+        .with_command_async(
+            Command::new("ttl")
+                .arg(Arg::new("key").required(true))
+                .display_order(23)
+                .about("Read value expiration"),
+            |args, context| Box::pin(requests::values::ttl(args, context)),
+        )
         .with_command_async(
             Command::new("update")
                 .arg(Arg::new("key").required(true))
                 .arg(Arg::new("value").required(true))
                 .arg(Arg::new("ttl").required(false))
-                .display_order(14)
-                .about("Update value"),
+                .display_order(24)
+                .about("Update value, use keep as ttl to keep the expiration"),
             |args, context| Box::pin(requests::values::update(args, context)),
+        )
+        .with_command_async(
+            Command::new("update_field")
+                .arg(Arg::new("key").required(true))
+                .arg(Arg::new("field").required(true))
+                .arg(Arg::new("value").required(true))
+                .display_order(25)
+                .about("Update field of object value, use JSON for object values"),
+            |args, context| Box::pin(requests::values::update_field(args, context)),
         );
 
     repl.run_async().await
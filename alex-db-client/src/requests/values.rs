@@ -5,10 +5,13 @@ use crate::{
 use alex_db_lib::{
     db::{Direction, Sort},
     value_record::{
-        Value, ValueAppend, ValueDecrement, ValueIncrement, ValuePopBack, ValuePopFront, ValuePost,
-        ValuePrepend, ValuePut, ValueResponse,
+        Value, ValueAppend, ValueCompareAndSwap, ValueCompareAndSwapResponse, ValueCopy,
+        ValueDecrement, ValueExpire, ValueFieldIncrement, ValueFieldPut, ValueFieldResponse,
+        ValueFieldsResponse, ValueIncrement, ValuePopBack, ValuePopFront, ValuePost, ValuePrepend,
+        ValuePut, ValueRename, ValueResponse, ValueTtlResponse,
     },
 };
+use chrono::{DateTime, Utc};
 use fake::{
     faker::lorem::en::Paragraph,
     Fake,
@@ -35,7 +38,12 @@ pub async fn append<'a>(
 
     let value = Value::Array(VecDeque::from([Value::from_str(value)?]));
 
-    let value_append = ValueAppend { append: value };
+    let value_append = ValueAppend {
+        append: value,
+        create: false,
+        expected_version: None,
+        ttl: None,
+    };
 
     let url = format!("{}/values/{key}/append", connection.address);
 
@@ -74,6 +82,132 @@ pub async fn append<'a>(
     }
 }
 
+pub async fn compare_and_swap<'a>(
+    args: ArgMatches,
+    context: &mut Context,
+) -> Result<Option<String>, ClientError<'a>> {
+    let connection = context
+        .get_default_connection()
+        .ok_or(ClientError::NoActiveConnection)?;
+
+    let key = args
+        .get_one::<String>("key")
+        .ok_or(ClientError::String("Key parse error"))?;
+
+    let expected = args
+        .get_one::<String>("expected")
+        .ok_or(ClientError::String("Expected value parse error"))?;
+
+    let new = args
+        .get_one::<String>("new")
+        .ok_or(ClientError::String("New value parse error"))?;
+
+    let value_compare_and_swap = ValueCompareAndSwap {
+        expected: Value::from_str(expected)?,
+        new: Value::from_str(new)?,
+    };
+
+    let url = format!("{}/values/{key}/cas", connection.address);
+
+    let mut request_builder = reqwest::Client::new()
+        .put(url)
+        .json(&value_compare_and_swap);
+
+    request_builder = match connection.api_key {
+        None => request_builder,
+        Some(api_key) => request_builder.header("X-Auth-Token", api_key.to_string()),
+    };
+
+    let request_response = request_builder.send().await?.text().await?;
+
+    let value_compare_and_swap_response: Result<ValueCompareAndSwapResponse, serde_json::Error> =
+        serde_json::from_str(&request_response);
+
+    match value_compare_and_swap_response {
+        Err(_) => {
+            let server_error: Result<ServerError, serde_json::Error> =
+                serde_json::from_str(&request_response);
+
+            match server_error {
+                Err(e) => Ok(Some(format!("Error: {e:?}"))),
+                Ok(server_error) => Ok(Some(format!("Server error: {}", server_error.error))),
+            }
+        }
+        Ok(value_compare_and_swap_response) => {
+            let mut response = String::new();
+            if value_compare_and_swap_response.swapped {
+                response.push_str("Value swapped\n");
+            } else {
+                response.push_str("Value not swapped\n");
+            }
+            response.push_str(&format!(
+                "Key: {}\nValue: {:?}\n",
+                value_compare_and_swap_response.key, value_compare_and_swap_response.value
+            ));
+
+            Ok(Some(response))
+        }
+    }
+}
+
+pub async fn copy<'a>(
+    args: ArgMatches,
+    context: &mut Context,
+) -> Result<Option<String>, ClientError<'a>> {
+    let connection = context
+        .get_default_connection()
+        .ok_or(ClientError::NoActiveConnection)?;
+
+    let key = args
+        .get_one::<String>("key")
+        .ok_or(ClientError::String("Key parse error"))?;
+
+    let to = args
+        .get_one::<String>("to")
+        .ok_or(ClientError::String("New key parse error"))?;
+
+    let value_copy = ValueCopy {
+        overwrite: args.get_flag("overwrite"),
+        to: to.to_string(),
+    };
+
+    let url = format!("{}/values/{key}/copy", connection.address);
+
+    let mut request_builder = reqwest::Client::new().put(url).json(&value_copy);
+
+    request_builder = match connection.api_key {
+        None => request_builder,
+        Some(api_key) => request_builder.header("X-Auth-Token", api_key.to_string()),
+    };
+
+    let request_response = request_builder.send().await?.text().await?;
+
+    let value_response: Result<ValueResponse, serde_json::Error> =
+        serde_json::from_str(&request_response);
+
+    match value_response {
+        Err(_) => {
+            let server_error: Result<ServerError, serde_json::Error> =
+                serde_json::from_str(&request_response);
+
+            match server_error {
+                Err(e) => Ok(Some(format!("Error: {e:?}"))),
+                Ok(server_error) => Ok(Some(format!("Server error: {}", server_error.error))),
+            }
+        }
+        Ok(value_response) => {
+            let mut response = String::new();
+            response.push_str("Value copied\n");
+            response.push_str(&format!(
+                "Key: {}\nValue: {:?}\n",
+                value_response.key, value_response.value
+            ));
+
+            Ok(Some(response))
+        }
+    }
+}
+
 pub async fn create<'a>(
     args: ArgMatches,
     context: &mut Context,
@@ -92,17 +226,13 @@ pub async fn create<'a>(
 
     let value = Value::from_str(value)?;
 
-    let ttl = match args.get_one::<String>("ttl") {
-        None => None,
-        Some(ttl) => match ttl.parse::<i64>() {
-            Err(_) => None,
-            Ok(ttl) => Some(ttl),
-        },
-    };
+    let value_expire = parse_value_expire(args.get_one::<String>("ttl"))?;
 
     let value_post = ValuePost {
+        expire_at: value_expire.expire_at,
         key: key.to_string(),
-        ttl,
+        ttl: value_expire.ttl,
+        ttl_ms: value_expire.ttl_ms,
         value,
     };
 
@@ -157,13 +287,15 @@ pub async fn decrement<'a>(
 
     let decrement = match args.get_one::<String>("decrement") {
         None => None,
-        Some(decrement) => match decrement.parse::<i64>() {
-            Err(_) => None,
-            Ok(decrement) => Some(decrement),
-        },
+        Some(decrement) => decrement.parse::<i64>().ok(),
     };
 
-    let value_decrement = ValueDecrement { decrement };
+    let value_decrement = ValueDecrement {
+        create: false,
+        decrement,
+        expected_version: None,
+        ttl: None,
+    };
 
     let url = format!("{}/values/{key}/decrement", connection.address);
 
@@ -241,6 +373,158 @@ pub async fn delete<'a>(
     }
 }
 
+pub async fn delete_field<'a>(
+    args: ArgMatches,
+    context: &mut Context,
+) -> Result<Option<String>, ClientError<'a>> {
+    let connection = context
+        .get_default_connection()
+        .ok_or(ClientError::NoActiveConnection)?;
+
+    let key = args
+        .get_one::<String>("key")
+        .ok_or(ClientError::String("Key parse error"))?;
+
+    let field = args
+        .get_one::<String>("field")
+        .ok_or(ClientError::String("Field parse error"))?;
+
+    let url = format!("{}/values/{key}/fields/{field}", connection.address);
+
+    let mut request_builder = reqwest::Client::new().delete(url);
+
+    request_builder = match connection.api_key {
+        None => request_builder,
+        Some(api_key) => request_builder.header("X-Auth-Token", api_key.to_string()),
+    };
+
+    let request_response = request_builder.send().await?.text().await?;
+
+    let value_field_response: Result<ValueFieldResponse, serde_json::Error> =
+        serde_json::from_str(&request_response);
+
+    match value_field_response {
+        Err(_) => {
+            let server_error: Result<ServerError, serde_json::Error> =
+                serde_json::from_str(&request_response);
+
+            match server_error {
+                Err(e) => Ok(Some(format!("Error: {e:?}"))),
+                Ok(server_error) => Ok(Some(format!("Server error: {}", server_error.error))),
+            }
+        }
+        Ok(value_field_response) => {
+            let mut response = String::new();
+            response.push_str("Field deleted\n");
+            response.push_str(&format_value_field_response(&value_field_response));
+
+            Ok(Some(response))
+        }
+    }
+}
+
+pub async fn expire<'a>(
+    args: ArgMatches,
+    context: &mut Context,
+) -> Result<Option<String>, ClientError<'a>> {
+    let connection = context
+        .get_default_connection()
+        .ok_or(ClientError::NoActiveConnection)?;
+
+    let key = args
+        .get_one::<String>("key")
+        .ok_or(ClientError::String("Key parse error"))?;
+
+    let ttl = args
+        .get_one::<String>("ttl")
+        .ok_or(ClientError::String("TTL parse error"))?;
+
+    let value_expire = parse_value_expire(Some(ttl))?;
+
+    let url = format!("{}/values/{key}/ttl", connection.address);
+
+    let mut request_builder = reqwest::Client::new().put(url).json(&value_expire);
+
+    request_builder = match connection.api_key {
+        None => request_builder,
+        Some(api_key) => request_builder.header("X-Auth-Token", api_key.to_string()),
+    };
+
+    let request_response = request_builder.send().await?.text().await?;
+
+    let value_ttl_response: Result<ValueTtlResponse, serde_json::Error> =
+        serde_json::from_str(&request_response);
+
+    match value_ttl_response {
+        Err(_) => {
+            let server_error: Result<ServerError, serde_json::Error> =
+                serde_json::from_str(&request_response);
+
+            match server_error {
+                Err(e) => Ok(Some(format!("Error: {e:?}"))),
+                Ok(server_error) => Ok(Some(format!("Server error: {}", server_error.error))),
+            }
+        }
+        Ok(value_ttl_response) => {
+            let mut response = String::new();
+            response.push_str("Value expiration updated\n");
+            response.push_str(&format_value_ttl_response(&value_ttl_response));
+
+            Ok(Some(response))
+        }
+    }
+}
+
+pub async fn fields<'a>(
+    args: ArgMatches,
+    context: &mut Context,
+) -> Result<Option<String>, ClientError<'a>> {
+    let connection = context
+        .get_default_connection()
+        .ok_or(ClientError::NoActiveConnection)?;
+
+    let key = args
+        .get_one::<String>("key")
+        .ok_or(ClientError::String("Key parse error"))?;
+
+    let url = format!("{}/values/{key}/fields", connection.address);
+
+    let mut request_builder = reqwest::Client::new().get(url);
+
+    request_builder = match connection.api_key {
+        None => request_builder,
+        Some(api_key) => request_builder.header("X-Auth-Token", api_key.to_string()),
+    };
+
+    let request_response = request_builder.send().await?.text().await?;
+
+    let value_fields_response: Result<ValueFieldsResponse, serde_json::Error> =
+        serde_json::from_str(&request_response);
+
+    match value_fields_response {
+        Err(_) => {
+            let server_error: Result<ServerError, serde_json::Error> =
+                serde_json::from_str(&request_response);
+
+            match server_error {
+                Err(e) => Ok(Some(format!("Error: {e:?}"))),
+                Ok(server_error) => Ok(Some(format!("Server error: {}", server_error.error))),
+            }
+        }
+        Ok(value_fields_response) => {
+            let mut response = String::new();
+            response.push_str("Fields listed\n");
+            response.push_str(&format!(
+                "Key: {}\nFields: {}\n",
+                value_fields_response.key,
+                value_fields_response.fields.join(", ")
+            ));
+
+            Ok(Some(response))
+        }
+    }
+}
+
 pub async fn increment<'a>(
     args: ArgMatches,
     context: &mut Context,
@@ -255,13 +539,15 @@ pub async fn increment<'a>(
 
     let increment = match args.get_one::<String>("increment") {
         None => None,
-        Some(increment) => match increment.parse::<i64>() {
-            Err(_) => None,
-            Ok(increment) => Some(increment),
-        },
+        Some(increment) => increment.parse::<i64>().ok(),
     };
 
-    let value_increment = ValueIncrement { increment };
+    let value_increment = ValueIncrement {
+        create: false,
+        expected_version: None,
+        increment,
+        ttl: None,
+    };
 
     let url = format!("{}/values/{key}/increment", connection.address);
 
@@ -300,6 +586,69 @@ pub async fn increment<'a>(
     }
 }
 
+pub async fn increment_field<'a>(
+    args: ArgMatches,
+    context: &mut Context,
+) -> Result<Option<String>, ClientError<'a>> {
+    let connection = context
+        .get_default_connection()
+        .ok_or(ClientError::NoActiveConnection)?;
+
+    let key = args
+        .get_one::<String>("key")
+        .ok_or(ClientError::String("Key parse error"))?;
+
+    let field = args
+        .get_one::<String>("field")
+        .ok_or(ClientError::String("Field parse error"))?;
+
+    let increment = match args.get_one::<String>("increment") {
+        None => None,
+        Some(increment) => increment.parse::<i64>().ok(),
+    };
+
+    let value_field_increment = ValueFieldIncrement {
+        expected_version: None,
+        increment,
+    };
+
+    let url = format!(
+        "{}/values/{key}/fields/{field}/increment",
+        connection.address
+    );
+
+    let mut request_builder = reqwest::Client::new().put(url).json(&value_field_increment);
+
+    request_builder = match connection.api_key {
+        None => request_builder,
+        Some(api_key) => request_builder.header("X-Auth-Token", api_key.to_string()),
+    };
+
+    let request_response = request_builder.send().await?.text().await?;
+
+    let value_field_response: Result<ValueFieldResponse, serde_json::Error> =
+        serde_json::from_str(&request_response);
+
+    match value_field_response {
+        Err(_) => {
+            let server_error: Result<ServerError, serde_json::Error> =
+                serde_json::from_str(&request_response);
+
+            match server_error {
+                Err(e) => Ok(Some(format!("Error: {e:?}"))),
+                Ok(server_error) => Ok(Some(format!("Server error: {}", server_error.error))),
+            }
+        }
+        Ok(value_field_response) => {
+            let mut response = String::new();
+            response.push_str("Field incremented\n");
+            response.push_str(&format_value_field_response(&value_field_response));
+
+            Ok(Some(response))
+        }
+    }
+}
+
 pub async fn list<'a>(
     args: ArgMatches,
     context: &mut Context,
@@ -334,12 +683,30 @@ pub async fn list<'a>(
     );
     let mut request_builder = reqwest::Client::new().get(url);
 
+    if let Some(cursor) = args.get_one::<String>("cursor") {
+        request_builder = request_builder.query(&[("cursor", cursor)]);
+    }
+
+    if let Some(prefix) = args.get_one::<String>("prefix") {
+        request_builder = request_builder.query(&[("prefix", prefix)]);
+    }
+
+    if let Some(pattern) = args.get_one::<String>("pattern") {
+        request_builder = request_builder.query(&[("pattern", pattern)]);
+    }
+
     request_builder = match connection.api_key {
         None => request_builder,
         Some(api_key) => request_builder.header("X-Auth-Token", api_key.to_string()),
     };
 
-    let request_response = request_builder.send().await?.text().await?;
+    let request_response = request_builder.send().await?;
+    let next_cursor = request_response
+        .headers()
+        .get("X-Next-Cursor")
+        .and_then(|next_cursor| next_cursor.to_str().ok())
+        .map(|next_cursor| next_cursor.to_string());
+    let request_response = request_response.text().await?;
 
     let value_responses: Result<Vec<ValueResponse>, serde_json::Error> =
         serde_json::from_str(&request_response);
@@ -365,6 +732,55 @@ pub async fn list<'a>(
                     value_response.value
                 ));
             }
+            if let Some(next_cursor) = next_cursor {
+                response.push_str(&format!("Next cursor: {next_cursor}\n"));
+            }
+            Ok(Some(response))
+        }
+    }
+}
+
+pub async fn persist<'a>(
+    args: ArgMatches,
+    context: &mut Context,
+) -> Result<Option<String>, ClientError<'a>> {
+    let connection = context
+        .get_default_connection()
+        .ok_or(ClientError::NoActiveConnection)?;
+
+    let key = args
+        .get_one::<String>("key")
+        .ok_or(ClientError::String("Key parse error"))?;
+
+    let url = format!("{}/values/{key}/ttl", connection.address);
+
+    let mut request_builder = reqwest::Client::new().delete(url);
+
+    request_builder = match connection.api_key {
+        None => request_builder,
+        Some(api_key) => request_builder.header("X-Auth-Token", api_key.to_string()),
+    };
+
+    let request_response = request_builder.send().await?.text().await?;
+
+    let value_ttl_response: Result<ValueTtlResponse, serde_json::Error> =
+        serde_json::from_str(&request_response);
+
+    match value_ttl_response {
+        Err(_) => {
+            let server_error: Result<ServerError, serde_json::Error> =
+                serde_json::from_str(&request_response);
+
+            match server_error {
+                Err(e) => Ok(Some(format!("Error: {e:?}"))),
+                Ok(server_error) => Ok(Some(format!("Server error: {}", server_error.error))),
+            }
+        }
+        Ok(value_ttl_response) => {
+            let mut response = String::new();
+            response.push_str("Value expiration removed\n");
+            response.push_str(&format_value_ttl_response(&value_ttl_response));
+
             Ok(Some(response))
         }
     }
@@ -384,13 +800,13 @@ pub async fn pop_back<'a>(
 
     let pop_back = match args.get_one::<String>("pop_back") {
         None => None,
-        Some(pop_back) => match pop_back.parse::<usize>() {
-            Err(_) => None,
-            Ok(pop_back) => Some(pop_back),
-        },
+        Some(pop_back) => pop_back.parse::<usize>().ok(),
     };
 
-    let value_pop_back = ValuePopBack { pop_back };
+    let value_pop_back = ValuePopBack {
+        expected_version: None,
+        pop_back,
+    };
 
     let url = format!("{}/values/{key}/pop-back", connection.address);
 
@@ -442,13 +858,13 @@ pub async fn pop_front<'a>(
 
     let pop_front = match args.get_one::<String>("pop_front") {
         None => None,
-        Some(pop_front) => match pop_front.parse::<usize>() {
-            Err(_) => None,
-            Ok(pop_front) => Some(pop_front),
-        },
+        Some(pop_front) => pop_front.parse::<usize>().ok(),
     };
 
-    let value_pop_front = ValuePopFront { pop_front };
+    let value_pop_front = ValuePopFront {
+        expected_version: None,
+        pop_front,
+    };
 
     let url = format!("{}/values/{key}/pop-front", connection.address);
 
@@ -504,7 +920,12 @@ pub async fn prepend<'a>(
 
     let value = Value::Array(VecDeque::from([Value::from_str(value)?]));
 
-    let value_prepend = ValuePrepend { prepend: value };
+    let value_prepend = ValuePrepend {
+        create: false,
+        expected_version: None,
+        prepend: value,
+        ttl: None,
+    };
 
     let url = format!("{}/values/{key}/prepend", connection.address);
 
@@ -592,6 +1013,114 @@ pub async fn read<'a>(
     }
 }
 
+pub async fn read_field<'a>(
+    args: ArgMatches,
+    context: &mut Context,
+) -> Result<Option<String>, ClientError<'a>> {
+    let connection = context
+        .get_default_connection()
+        .ok_or(ClientError::NoActiveConnection)?;
+
+    let key = args
+        .get_one::<String>("key")
+        .ok_or(ClientError::String("Key parse error"))?;
+
+    let field = args
+        .get_one::<String>("field")
+        .ok_or(ClientError::String("Field parse error"))?;
+
+    let url = format!("{}/values/{key}/fields/{field}", connection.address);
+
+    let mut request_builder = reqwest::Client::new().get(url);
+
+    request_builder = match connection.api_key {
+        None => request_builder,
+        Some(api_key) => request_builder.header("X-Auth-Token", api_key.to_string()),
+    };
+
+    let request_response = request_builder.send().await?.text().await?;
+
+    let value_field_response: Result<ValueFieldResponse, serde_json::Error> =
+        serde_json::from_str(&request_response);
+
+    match value_field_response {
+        Err(_) => {
+            let server_error: Result<ServerError, serde_json::Error> =
+                serde_json::from_str(&request_response);
+
+            match server_error {
+                Err(e) => Ok(Some(format!("Error: {e:?}"))),
+                Ok(server_error) => Ok(Some(format!("Server error: {}", server_error.error))),
+            }
+        }
+        Ok(value_field_response) => {
+            let mut response = String::new();
+            response.push_str("Field readed\n");
+            response.push_str(&format_value_field_response(&value_field_response));
+
+            Ok(Some(response))
+        }
+    }
+}
+
+pub async fn rename<'a>(
+    args: ArgMatches,
+    context: &mut Context,
+) -> Result<Option<String>, ClientError<'a>> {
+    let connection = context
+        .get_default_connection()
+        .ok_or(ClientError::NoActiveConnection)?;
+
+    let key = args
+        .get_one::<String>("key")
+        .ok_or(ClientError::String("Key parse error"))?;
+
+    let to = args
+        .get_one::<String>("to")
+        .ok_or(ClientError::String("New key parse error"))?;
+
+    let value_rename = ValueRename {
+        overwrite: args.get_flag("overwrite"),
+        to: to.to_string(),
+    };
+
+    let url = format!("{}/values/{key}/rename", connection.address);
+
+    let mut request_builder = reqwest::Client::new().put(url).json(&value_rename);
+
+    request_builder = match connection.api_key {
+        None => request_builder,
+        Some(api_key) => request_builder.header("X-Auth-Token", api_key.to_string()),
+    };
+
+    let request_response = request_builder.send().await?.text().await?;
+
+    let value_response: Result<ValueResponse, serde_json::Error> =
+        serde_json::from_str(&request_response);
+
+    match value_response {
+        Err(_) => {
+            let server_error: Result<ServerError, serde_json::Error> =
+                serde_json::from_str(&request_response);
+
+            match server_error {
+                Err(e) => Ok(Some(format!("Error: {e:?}"))),
+                Ok(server_error) => Ok(Some(format!("Server error: {}", server_error.error))),
+            }
+        }
+        Ok(value_response) => {
+            let mut response = String::new();
+            response.push_str("Value renamed\n");
+            response.push_str(&format!(
+                "Key: {}\nValue: {:?}\n",
+                value_response.key, value_response.value
+            ));
+
+            Ok(Some(response))
+        }
+    }
+}
+
 pub async fn test_create<'a>(
     args: ArgMatches,
     context: &mut Context,
@@ -602,10 +1131,7 @@ pub async fn test_create<'a>(
 
     let mut number = match args.get_one::<String>("number") {
         None => 16000,
-        Some(number) => match number.parse::<usize>() {
-            Err(_) => 16000,
-            Ok(number) => number,
-        },
+        Some(number) => number.parse::<usize>().unwrap_or(16000),
     };
 
     if number == 0 {
@@ -618,17 +1144,13 @@ pub async fn test_create<'a>(
         let value = Paragraph(2..50).fake::<String>();
         let value = Value::from_str(&value)?;
 
-        let ttl = match args.get_one::<String>("ttl") {
-            None => None,
-            Some(ttl) => match ttl.parse::<i64>() {
-                Err(_) => None,
-                Ok(ttl) => Some(ttl),
-            },
-        };
+        let value_expire = parse_value_expire(args.get_one::<String>("ttl"))?;
 
         let value_post = ValuePost {
+            expire_at: value_expire.expire_at,
             key,
-            ttl,
+            ttl: value_expire.ttl,
+            ttl_ms: value_expire.ttl_ms,
             value,
         };
 
@@ -657,10 +1179,7 @@ pub async fn test_delete<'a>(
 
     let mut number = match args.get_one::<String>("number") {
         None => 16000,
-        Some(number) => match number.parse::<usize>() {
-            Err(_) => 16000,
-            Ok(number) => number,
-        },
+        Some(number) => number.parse::<usize>().unwrap_or(16000),
     };
 
     if number == 0 {
@@ -685,6 +1204,52 @@ pub async fn test_delete<'a>(
     Ok(Some(format!("{number} values deleted")))
 }
 
+pub async fn ttl<'a>(
+    args: ArgMatches,
+    context: &mut Context,
+) -> Result<Option<String>, ClientError<'a>> {
+    let connection = context
+        .get_default_connection()
+        .ok_or(ClientError::NoActiveConnection)?;
+
+    let key = args
+        .get_one::<String>("key")
+        .ok_or(ClientError::String("Key parse error"))?;
+
+    let url = format!("{}/values/{key}/ttl", connection.address);
+
+    let mut request_builder = reqwest::Client::new().get(url);
+
+    request_builder = match connection.api_key {
+        None => request_builder,
+        Some(api_key) => request_builder.header("X-Auth-Token", api_key.to_string()),
+    };
+
+    let request_response = request_builder.send().await?.text().await?;
+
+    let value_ttl_response: Result<ValueTtlResponse, serde_json::Error> =
+        serde_json::from_str(&request_response);
+
+    match value_ttl_response {
+        Err(_) => {
+            let server_error: Result<ServerError, serde_json::Error> =
+                serde_json::from_str(&request_response);
+
+            match server_error {
+                Err(e) => Ok(Some(format!("Error: {e:?}"))),
+                Ok(server_error) => Ok(Some(format!("Server error: {}", server_error.error))),
+            }
+        }
+        Ok(value_ttl_response) => {
+            let mut response = String::new();
+            response.push_str("Value expiration readed\n");
+            response.push_str(&format_value_ttl_response(&value_ttl_response));
+
+            Ok(Some(response))
+        }
+    }
+}
+
 pub async fn update<'a>(
     args: ArgMatches,
     context: &mut Context,
@@ -703,16 +1268,20 @@ pub async fn update<'a>(
 
     let value = Value::from_str(value)?;
 
-    let ttl = match args.get_one::<String>("ttl") {
-        None => None,
-        Some(ttl) => match ttl.parse::<i64>() {
-            Err(_) => None,
-            Ok(ttl) => Some(ttl),
-        },
+    let ttl = args.get_one::<String>("ttl");
+    let keep_ttl = matches!(ttl.map(String::as_str), Some("keep"));
+    let value_expire = parse_value_expire(if keep_ttl { None } else { ttl })?;
+
+    let value_put = ValuePut {
+        expected_version: None,
+        expire_at: value_expire.expire_at,
+        keep_ttl,
+        ttl: value_expire.ttl,
+        ttl_ms: value_expire.ttl_ms,
+        upsert: false,
+        value,
     };
 
-    let value_put = ValuePut { ttl, value };
-
     let url = format!("{}/values/{key}", connection.address);
 
     let mut request_builder = reqwest::Client::new().put(url).json(&value_put);
@@ -749,3 +1318,114 @@ pub async fn update<'a>(
         }
     }
 }
+
+pub async fn update_field<'a>(
+    args: ArgMatches,
+    context: &mut Context,
+) -> Result<Option<String>, ClientError<'a>> {
+    let connection = context
+        .get_default_connection()
+        .ok_or(ClientError::NoActiveConnection)?;
+
+    let key = args
+        .get_one::<String>("key")
+        .ok_or(ClientError::String("Key parse error"))?;
+
+    let field = args
+        .get_one::<String>("field")
+        .ok_or(ClientError::String("Field parse error"))?;
+
+    let value = args
+        .get_one::<String>("value")
+        .ok_or(ClientError::String("Value parse error"))?;
+
+    let value_field_put = ValueFieldPut {
+        expected_version: None,
+        value: Value::from_str(value)?,
+    };
+
+    let url = format!("{}/values/{key}/fields/{field}", connection.address);
+
+    let mut request_builder = reqwest::Client::new().put(url).json(&value_field_put);
+
+    request_builder = match connection.api_key {
+        None => request_builder,
+        Some(api_key) => request_builder.header("X-Auth-Token", api_key.to_string()),
+    };
+
+    let request_response = request_builder.send().await?.text().await?;
+
+    let value_field_response: Result<ValueFieldResponse, serde_json::Error> =
+        serde_json::from_str(&request_response);
+
+    match value_field_response {
+        Err(_) => {
+            let server_error: Result<ServerError, serde_json::Error> =
+                serde_json::from_str(&request_response);
+
+            match server_error {
+                Err(e) => Ok(Some(format!("Error: {e:?}"))),
+                Ok(server_error) => Ok(Some(format!("Server error: {}", server_error.error))),
+            }
+        }
+        Ok(value_field_response) => {
+            let mut response = String::new();
+            response.push_str("Field updated\n");
+            response.push_str(&format_value_field_response(&value_field_response));
+
+            Ok(Some(response))
+        }
+    }
+}
+
+fn format_value_field_response(value_field_response: &ValueFieldResponse) -> String {
+    format!(
+        "Key: {}\nField: {}\nValue: {:?}\n",
+        value_field_response.key, value_field_response.field, value_field_response.value
+    )
+}
+
+fn format_value_ttl_response(value_ttl_response: &ValueTtlResponse) -> String {
+    let expire_at = match value_ttl_response.expire_at {
+        None => "none".to_string(),
+        Some(expire_at) => expire_at.to_rfc3339(),
+    };
+    let ttl = match value_ttl_response.ttl_ms {
+        None => "none".to_string(),
+        Some(ttl_ms) => format!("{ttl_ms}ms"),
+    };
+
+    format!(
+        "Key: {}\nExpire at: {expire_at}\nTTL: {ttl}\n",
+        value_ttl_response.key
+    )
+}
+
+/// Parses an expiration given in seconds, in milliseconds with the `ms` suffix or as a RFC 3339 timestamp.
+fn parse_value_expire<'a>(ttl: Option<&String>) -> Result<ValueExpire, ClientError<'a>> {
+    let mut value_expire = ValueExpire {
+        expected_version: None,
+        expire_at: None,
+        ttl: None,
+        ttl_ms: None,
+    };
+
+    if let Some(ttl) = ttl {
+        if let Some(ttl_ms) = ttl.strip_suffix("ms") {
+            value_expire.ttl_ms = Some(
+                ttl_ms
+                    .parse::<i64>()
+                    .map_err(|_| ClientError::String("TTL parse error"))?,
+            );
+        } else if let Ok(ttl) = ttl.parse::<i64>() {
+            value_expire.ttl = Some(ttl);
+        } else {
+            value_expire.expire_at = Some(
+                ttl.parse::<DateTime<Utc>>()
+                    .map_err(|_| ClientError::String("TTL parse error"))?,
+            );
+        }
+    }
+
+    Ok(value_expire)
+}
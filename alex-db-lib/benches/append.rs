@@ -10,8 +10,10 @@ fn append(db: Arc<Db>) {
     for i in 0..u16::MAX {
         let key = format!("test_key_{i}");
         let value_post = ValuePost {
+            expire_at: None,
             key,
             ttl: None,
+            ttl_ms: None,
             value: Value::Array(VecDeque::from([Value::String("test_value".to_string())])),
         };
 
@@ -24,6 +26,9 @@ fn append(db: Arc<Db>) {
             append: Value::Array(VecDeque::from([Value::String(
                 "test_value_appended".to_string(),
             )])),
+            create: false,
+            expected_version: None,
+            ttl: None,
         };
 
         db.try_append(&key, value_append).unwrap();
@@ -32,7 +37,7 @@ fn append(db: Arc<Db>) {
     for i in 0..u16::MAX {
         let key = format!("test_key_{i}");
 
-        db.try_delete(&key).unwrap();
+        db.try_delete(&key, None).unwrap();
     }
 }
 
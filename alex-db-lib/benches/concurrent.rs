@@ -0,0 +1,126 @@
+use alex_db_lib::{
+    config::Config,
+    db::Db,
+    value_record::{Value, ValueIncrement, ValuePost},
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::{sync::Arc, thread};
+
+const KEYS: usize = u16::MAX as usize;
+
+fn create(db: &Db) {
+    for i in 0..KEYS {
+        let key = format!("test_key_{i}");
+        let value_post = ValuePost {
+            expire_at: None,
+            key,
+            ttl: None,
+            ttl_ms: None,
+            value: Value::Integer(0),
+        };
+
+        db.try_create(value_post).unwrap();
+    }
+}
+
+fn run(db: Arc<Db>, threads: usize, operation: fn(&Db, &str, usize)) {
+    let mut handles = vec![];
+
+    for thread_index in 0..threads {
+        let db = db.clone();
+
+        handles.append(&mut vec![thread::spawn(move || {
+            for i in (thread_index..KEYS).step_by(threads) {
+                let key = format!("test_key_{i}");
+
+                operation(&db, &key, i);
+            }
+        })]);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
+
+fn read(db: &Db, key: &str, _i: usize) {
+    db.try_read(key).unwrap();
+}
+
+fn increment(db: &Db, key: &str, _i: usize) {
+    let value_increment = ValueIncrement {
+        create: false,
+        expected_version: None,
+        increment: None,
+        ttl: None,
+    };
+
+    db.try_increment(key, value_increment).unwrap();
+}
+
+fn mixed(db: &Db, key: &str, i: usize) {
+    if i % 4 == 0 {
+        increment(db, key, i);
+    } else {
+        read(db, key, i);
+    }
+}
+
+fn batch(db: &Db, _key: &str, i: usize) {
+    let values = (i..i + 4)
+        .map(|j| ValuePost {
+            expire_at: None,
+            key: format!("test_key_{}", j % KEYS),
+            ttl: None,
+            ttl_ms: None,
+            value: Value::Integer(j as i64),
+        })
+        .collect::<Vec<ValuePost>>();
+
+    db.try_upsert_many(values).unwrap();
+}
+
+fn mixed_batch(db: &Db, key: &str, i: usize) {
+    match i % 8 {
+        0 => batch(db, key, i),
+        1 | 2 => increment(db, key, i),
+        _ => read(db, key, i),
+    }
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let config = Config::default();
+    let db = Arc::new(Db::new(config));
+    create(&db);
+
+    let mut group = c.benchmark_group("concurrent");
+    group.sample_size(10);
+
+    for threads in [1, 2, 4, 8, 16] {
+        group.bench_with_input(
+            BenchmarkId::new("read", threads),
+            &threads,
+            |b, &threads| b.iter(|| run(db.clone(), threads, read)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("increment", threads),
+            &threads,
+            |b, &threads| b.iter(|| run(db.clone(), threads, increment)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("mixed", threads),
+            &threads,
+            |b, &threads| b.iter(|| run(db.clone(), threads, mixed)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("mixed_batch", threads),
+            &threads,
+            |b, &threads| b.iter(|| run(db.clone(), threads, mixed_batch)),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -10,8 +10,10 @@ fn increment(db: Arc<Db>) {
     for i in 0..u16::MAX {
         let key = format!("test_key_{i}");
         let value_post = ValuePost {
+            expire_at: None,
             key,
             ttl: None,
+            ttl_ms: None,
             value: Value::Integer(i as i64),
         };
 
@@ -21,7 +23,10 @@ fn increment(db: Arc<Db>) {
     for i in 0..u16::MAX {
         let key = format!("test_key_{i}");
         let value_increment = ValueIncrement {
+            create: false,
+            expected_version: None,
             increment: Some(i as i64),
+            ttl: None,
         };
 
         db.try_increment(&key, value_increment).unwrap();
@@ -30,7 +35,7 @@ fn increment(db: Arc<Db>) {
     for i in 0..u16::MAX {
         let key = format!("test_key_{i}");
 
-        db.try_delete(&key).unwrap();
+        db.try_delete(&key, None).unwrap();
     }
 }
 
@@ -1,6 +1,6 @@
 use alex_db_lib::{
     config::Config,
-    db::{Db, Direction, Sort},
+    db::{Db, Direction, ListFilter, Sort},
     value_record::{Value, ValuePost},
 };
 use criterion::{criterion_group, criterion_main, Criterion};
@@ -10,8 +10,10 @@ fn list(db: Arc<Db>) {
     for i in 0..u16::MAX {
         let key = format!("test_key_{i}");
         let value_post = ValuePost {
+            expire_at: None,
             key,
             ttl: None,
+            ttl_ms: None,
             value: Value::String("test_value".to_string()),
         };
 
@@ -20,7 +22,9 @@ fn list(db: Arc<Db>) {
 
     for i in 0..u8::MAX {
         db.list(
+            None,
             Direction::Asc,
+            &ListFilter::default(),
             Some(u8::MAX as usize),
             Some(i as usize),
             Sort::CreatedAt,
@@ -31,7 +35,7 @@ fn list(db: Arc<Db>) {
     for i in 0..u16::MAX {
         let key = format!("test_key_{i}");
 
-        db.try_delete(&key).unwrap();
+        db.try_delete(&key, None).unwrap();
     }
 }
 
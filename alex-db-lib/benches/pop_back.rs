@@ -10,8 +10,10 @@ fn pop_back(db: Arc<Db>) {
     for i in 0..u16::MAX {
         let key = format!("test_key_{i}");
         let value_post = ValuePost {
+            expire_at: None,
             key,
             ttl: None,
+            ttl_ms: None,
             value: Value::Array(VecDeque::from([
                 Value::String("test_value1".to_string()),
                 Value::String("test_value2".to_string()),
@@ -23,7 +25,10 @@ fn pop_back(db: Arc<Db>) {
 
     for i in 0..u16::MAX {
         let key = format!("test_key_{i}");
-        let value_pop_back = ValuePopBack { pop_back: None };
+        let value_pop_back = ValuePopBack {
+            expected_version: None,
+            pop_back: None,
+        };
 
         db.try_pop_back(&key, value_pop_back).unwrap();
     }
@@ -31,7 +36,7 @@ fn pop_back(db: Arc<Db>) {
     for i in 0..u16::MAX {
         let key = format!("test_key_{i}");
 
-        db.try_delete(&key).unwrap();
+        db.try_delete(&key, None).unwrap();
     }
 }
 
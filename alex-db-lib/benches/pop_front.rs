@@ -10,8 +10,10 @@ fn pop_front(db: Arc<Db>) {
     for i in 0..u16::MAX {
         let key = format!("test_key_{i}");
         let value_post = ValuePost {
+            expire_at: None,
             key,
             ttl: None,
+            ttl_ms: None,
             value: Value::Array(VecDeque::from([
                 Value::String("test_value1".to_string()),
                 Value::String("test_value2".to_string()),
@@ -23,7 +25,10 @@ fn pop_front(db: Arc<Db>) {
 
     for i in 0..u16::MAX {
         let key = format!("test_key_{i}");
-        let value_pop_front = ValuePopFront { pop_front: None };
+        let value_pop_front = ValuePopFront {
+            expected_version: None,
+            pop_front: None,
+        };
 
         db.try_pop_front(&key, value_pop_front).unwrap();
     }
@@ -31,7 +36,7 @@ fn pop_front(db: Arc<Db>) {
     for i in 0..u16::MAX {
         let key = format!("test_key_{i}");
 
-        db.try_delete(&key).unwrap();
+        db.try_delete(&key, None).unwrap();
     }
 }
 
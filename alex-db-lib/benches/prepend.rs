@@ -10,8 +10,10 @@ fn prepend(db: Arc<Db>) {
     for i in 0..u16::MAX {
         let key = format!("test_key_{i}");
         let value_post = ValuePost {
+            expire_at: None,
             key,
             ttl: None,
+            ttl_ms: None,
             value: Value::Array(VecDeque::from([Value::String("test_value".to_string())])),
         };
 
@@ -21,9 +23,12 @@ fn prepend(db: Arc<Db>) {
     for i in 0..u16::MAX {
         let key = format!("test_key_{i}");
         let value_prepend = ValuePrepend {
+            create: false,
+            expected_version: None,
             prepend: Value::Array(VecDeque::from([Value::String(
                 "test_value_prepended".to_string(),
             )])),
+            ttl: None,
         };
 
         db.try_prepend(&key, value_prepend).unwrap();
@@ -32,7 +37,7 @@ fn prepend(db: Arc<Db>) {
     for i in 0..u16::MAX {
         let key = format!("test_key_{i}");
 
-        db.try_delete(&key).unwrap();
+        db.try_delete(&key, None).unwrap();
     }
 }
 
@@ -10,8 +10,10 @@ fn update(db: Arc<Db>) {
     for i in 0..u16::MAX {
         let key = format!("test_key_{i}");
         let value_post = ValuePost {
+            expire_at: None,
             key,
             ttl: None,
+            ttl_ms: None,
             value: Value::String("test_value".to_string()),
         };
 
@@ -21,7 +23,12 @@ fn update(db: Arc<Db>) {
     for i in 0..u16::MAX {
         let key = format!("test_key_{i}");
         let value_put = ValuePut {
+            expected_version: None,
+            expire_at: None,
+            keep_ttl: false,
             ttl: None,
+            ttl_ms: None,
+            upsert: false,
             value: Value::Integer(i as i64),
         };
 
@@ -31,7 +38,7 @@ fn update(db: Arc<Db>) {
     for i in 0..u16::MAX {
         let key = format!("test_key_{i}");
 
-        db.try_delete(&key).unwrap();
+        db.try_delete(&key, None).unwrap();
     }
 }
 
@@ -0,0 +1,54 @@
+use crate::value_record::{ValuePost, ValueRecord, ValueResponse};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// Keys of the records read or deleted in a batch.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Validate)]
+pub struct BatchKeys {
+    pub keys: Vec<String>,
+}
+
+/// Records set in a batch.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Validate)]
+pub struct BatchSetPost {
+    /// Replace existing records instead of reporting them as conflicts.
+    #[serde(default)]
+    pub upsert: bool,
+    #[validate]
+    pub values: Vec<ValuePost>,
+}
+
+/// Outcome of a batch operation for a single key.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchStatus {
+    Conflict,
+    Created,
+    Deleted,
+    Found,
+    NotFound,
+    Updated,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct BatchResult {
+    pub key: String,
+    pub status: BatchStatus,
+    pub value: Option<ValueResponse>,
+}
+
+impl BatchResult {
+    pub fn new(key: &str, status: BatchStatus, value_record: Option<ValueRecord>) -> Self {
+        BatchResult {
+            key: key.into(),
+            status,
+            value: value_record.map(ValueResponse::from),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct BatchResponse {
+    pub results: Vec<BatchResult>,
+}
@@ -1,37 +1,77 @@
+use crate::{encryption::EncryptionKey, snapshot::SnapshotCompression, wal::WalSyncPolicy};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Config {
     pub data_dir: Option<String>,
     pub enable_security_api_keys: bool,
+    #[serde(skip)]
+    pub encryption_key: Option<EncryptionKey>,
+    #[serde(skip)]
+    pub previous_encryption_keys: Vec<EncryptionKey>,
     pub save_triggered_after_ms: i64,
     pub save_triggered_by_threshold: u16,
     pub sleep_time_between_gc_ms: u64,
     pub sleep_time_between_saves_ms: u64,
+    pub snapshot_compression: SnapshotCompression,
+    pub use_persisted_indexes: bool,
+    pub wal_sync_policy: WalSyncPolicy,
 }
 
 impl Config {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         data_dir: Option<String>,
         enable_security_api_keys: bool,
+        encryption_key: Option<EncryptionKey>,
+        previous_encryption_keys: Vec<EncryptionKey>,
         save_triggered_after_ms: i64,
         save_triggered_by_threshold: u16,
         sleep_time_between_gc_ms: u64,
         sleep_time_between_saves_ms: u64,
+        snapshot_compression: SnapshotCompression,
+        use_persisted_indexes: bool,
+        wal_sync_policy: WalSyncPolicy,
     ) -> Self {
         Self {
             data_dir,
             enable_security_api_keys,
+            encryption_key,
+            previous_encryption_keys,
             save_triggered_after_ms,
             save_triggered_by_threshold,
             sleep_time_between_gc_ms,
             sleep_time_between_saves_ms,
+            snapshot_compression,
+            use_persisted_indexes,
+            wal_sync_policy,
         }
     }
+
+    /// Returns the keys accepted for decryption, the current key first.
+    pub fn decryption_keys(&self) -> Vec<EncryptionKey> {
+        self.encryption_key
+            .iter()
+            .chain(self.previous_encryption_keys.iter())
+            .cloned()
+            .collect()
+    }
 }
 
 impl Default for Config {
     fn default() -> Self {
-        Config::new(None, true, 60000, 8, 1000, 10000)
+        Config::new(
+            None,
+            true,
+            None,
+            vec![],
+            60000,
+            8,
+            1000,
+            10000,
+            SnapshotCompression::default(),
+            false,
+            WalSyncPolicy::default(),
+        )
     }
 }
@@ -0,0 +1,46 @@
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+use uuid::Uuid;
+
+/// Position in an index after which listing continues.
+///
+/// Cursors are passed around as opaque strings, so their encoding can change without
+/// affecting clients.
+///
+/// # Examples
+///
+/// ```
+/// use alex_db_lib::cursor::Cursor;
+/// use uuid::Uuid;
+///
+/// let cursor = Cursor::Timestamp(1676332800000000000, Uuid::new_v4());
+/// let encoded = cursor.to_string();
+///
+/// assert_eq!(cursor, encoded.parse::<Cursor>().unwrap());
+/// assert!("wrong_cursor".parse::<Cursor>().is_err());
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Cursor {
+    Key(String),
+    Timestamp(i64, Uuid),
+}
+
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cursor = serde_json::to_vec(self).map_err(|_| fmt::Error)?;
+
+        write!(f, "{}", hex::encode(cursor))
+    }
+}
+
+impl FromStr for Cursor {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let cursor = hex::decode(s).map_err(|_| Error::CursorParse)?;
+
+        serde_json::from_slice(&cursor).map_err(|_| Error::CursorParse)
+    }
+}
@@ -1,38 +1,53 @@
 use crate::{
+    batch::{BatchResult, BatchStatus},
     config::Config,
+    cursor::Cursor,
     error::Error,
-    stat_record::StatRecord,
+    glob,
+    snapshot::{Snapshot, SnapshotRef},
+    stat_record::{Operation, StatRecord, Stats},
+    storage::{DirectoryStorage, StorageBackend},
+    transaction::{TransactionOperation, TransactionResult},
     value_index::ValueIndex,
     value_record::{
-        Value, ValueAppend, ValueDecrement, ValueIncrement, ValuePopBack, ValuePopFront, ValuePost,
-        ValuePrepend, ValuePut, ValueRecord, ValueResponse,
+        Value, ValueAppend, ValueCompareAndSwapResponse, ValueDecrement, ValueExpire,
+        ValueFieldIncrement, ValueFieldPut, ValueFieldResponse, ValueFieldsResponse,
+        ValueIncrement, ValueListResponse, ValuePopBack, ValuePopFront, ValuePost, ValuePrepend,
+        ValuePut, ValueRecord, ValueResponse, ValueTtlResponse,
     },
+    value_shards::{key_shard_index, new_id, shard_index, ValueShards, ValueShardsWriteGuard},
+    wal::{Wal, WalEntry},
     Result,
 };
-use chrono::{Duration, Utc};
-use lz4_flex::{compress_prepend_size, decompress_size_prepended};
-use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, fs, path::Path, str::FromStr, sync::RwLock};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::{
+    collections::{BTreeSet, HashMap},
+    str::FromStr,
+    sync::{Arc, RwLock},
+    time::Instant,
+};
 use uuid::Uuid;
 
-pub const API_KEYS_FILE: &str = "api_keys.sec";
-pub const VALUES_CREATED_AT_INDEX_FILE: &str = "values_created_at.idx";
-pub const VALUES_DATABASE_FILE: &str = "values.db";
-pub const VALUES_DELETE_AT_INDEX_FILE: &str = "values_delete_at.idx";
-pub const VALUES_KEY_INDEX_FILE: &str = "values_key.idx";
-pub const VALUES_UPDATED_AT_INDEX_FILE: &str = "values_updated_at.idx";
-
-#[derive(Debug, Deserialize, Serialize)]
+/// The database.
+///
+/// Values are split into shards with their own locks and indexes are lock-free,
+/// so operations on different keys proceed in parallel. Writers hold the checkpoint
+/// lock for reading, saving holds it for writing to get a consistent snapshot.
+#[derive(Debug)]
 pub struct Db {
     api_keys: RwLock<Vec<Uuid>>,
+    checkpoint: RwLock<()>,
     pub config: Config,
-    pub stats: RwLock<StatRecord>,
-    pub values: RwLock<HashMap<Uuid, ValueRecord>>,
+    pub stats: Stats,
+    pub values: ValueShards,
     pub values_indexes: ValueIndex,
+    storage: Option<Arc<dyn StorageBackend>>,
+    wal: Option<Wal>,
 }
 
 impl Db {
-    /// Creates new DB.
+    /// Creates new DB. When the data directory is configured, the database is stored in it.
     ///
     /// # Examples
     ///
@@ -42,15 +57,49 @@ impl Db {
     /// let config = Config::default();
     /// let db = Db::new(config);
     ///
-    /// assert_eq!(0, db.values.read().unwrap().len());
+    /// assert_eq!(0, db.values.len().unwrap());
     /// ```
     pub fn new(config: Config) -> Self {
+        let storage = config
+            .data_dir
+            .as_ref()
+            .map(|data_dir| Arc::new(DirectoryStorage::new(data_dir)) as Arc<dyn StorageBackend>);
+
+        Self {
+            api_keys: RwLock::new(vec![]),
+            checkpoint: RwLock::new(()),
+            config,
+            stats: Stats::default(),
+            values: ValueShards::default(),
+            values_indexes: ValueIndex::default(),
+            storage,
+            wal: None,
+        }
+    }
+
+    /// Creates new DB that is stored in the specified storage backend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, storage::MemoryStorage};
+    /// use std::sync::Arc;
+    ///
+    /// let config = Config::default();
+    /// let db = Db::with_storage(config, Arc::new(MemoryStorage::default()));
+    ///
+    /// assert_eq!(0, db.values.len().unwrap());
+    /// ```
+    pub fn with_storage(config: Config, storage: Arc<dyn StorageBackend>) -> Self {
         Self {
             api_keys: RwLock::new(vec![]),
+            checkpoint: RwLock::new(()),
             config,
-            stats: RwLock::new(StatRecord::default()),
-            values: RwLock::new(HashMap::new()),
+            stats: Stats::default(),
+            values: ValueShards::default(),
             values_indexes: ValueIndex::default(),
+            storage: Some(storage),
+            wal: None,
         }
     }
 
@@ -75,300 +124,540 @@ impl Db {
         Ok(None)
     }
 
+    /// Deletes the records whose time to live has passed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, value_index::ValueIndex, value_record::{Value, ValueRecord}, value_shards::ValueShards};
+    /// use chrono::{Duration, Utc};
+    /// use std::collections::HashMap;
+    /// use uuid::Uuid;
+    ///
+    /// let mut db = Db::new(Config::default());
+    /// let now = Utc::now();
+    /// let delete_at = now - Duration::seconds(1);
+    /// let id = Uuid::new_v4();
+    /// let other_id = Uuid::new_v4();
+    /// let value_record = ValueRecord::new(id, "test_key", &Value::Integer(10), now, Some(delete_at), now, 1);
+    /// let other_value_record = ValueRecord::new(other_id, "other_test_key", &Value::Integer(20), now, Some(delete_at), now, 1);
+    /// let values = HashMap::from([(id, value_record), (other_id, other_value_record)]);
+    /// db.values_indexes = ValueIndex::from_values(&values);
+    /// db.values = ValueShards::from_values(values);
+    /// db.gc().unwrap();
+    ///
+    /// assert_eq!(0, db.values.len().unwrap());
+    /// assert_eq!(0, db.values_indexes.delete_at.len());
+    /// assert_eq!(0, db.values_indexes.created_at.len());
+    /// assert_eq!(1, db.get_stats().unwrap().gc_runs);
+    /// assert_eq!(2, db.get_stats().unwrap().operations.gc_expired);
+    /// ```
     pub fn gc(&self) -> Result<()> {
-        let values_delete_at_index = self
-            .values_indexes
-            .delete_at
-            .read()
-            .map_err(|_| Error::Lock)?;
+        self.stats.inc_gc_runs();
+
         let now = Utc::now();
         let mut ids = vec![];
 
-        for (key, value) in values_delete_at_index.iter() {
-            if now.timestamp_nanos() > *key {
-                ids.append(&mut vec![*value]);
-            }
+        for entry in self
+            .values_indexes
+            .delete_at
+            .range(..(now.timestamp_nanos_opt().unwrap_or_default(), Uuid::nil()))
+        {
+            let (_delete_at, id) = entry.value();
+            ids.append(&mut vec![*id]);
         }
 
-        drop(values_delete_at_index);
-
         for id in ids {
-            self.try_delete_by_id(id)?;
+            self.try_delete_by_id(id, Some(now), None)?;
         }
 
         Ok(())
     }
 
     pub fn get_stats(&self) -> Result<StatRecord> {
-        let stats = self.stats.read().map_err(|_| Error::Lock)?.to_owned();
-
-        Ok(stats)
+        self.stats.to_stat_record()
     }
 
+    /// Restores the database from the storage backend and replays the write-ahead log on top of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, value_record::{Value, ValuePost}};
+    ///
+    /// let data_dir = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+    /// std::fs::create_dir_all(&data_dir).unwrap();
+    /// let config = Config {
+    ///     data_dir: Some(data_dir.to_str().unwrap().to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let mut db = Db::new(config.clone());
+    /// db.restore().unwrap();
+    ///
+    /// let key = "test_key".to_string();
+    /// let value = Value::Integer(10);
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value: value.clone() };
+    /// db.try_create(value_post).unwrap();
+    /// drop(db);
+    ///
+    /// let mut db = Db::new(config);
+    /// db.restore().unwrap();
+    /// let value_response = db.try_read(&key).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_response.value, value);
+    /// # std::fs::remove_dir_all(data_dir).unwrap();
+    /// ```
+    ///
+    /// Restoring fails on a wrong encryption key. After rotation the previous key is still
+    /// accepted for decryption and the data is re-encrypted with the new key on the next save.
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, encryption::EncryptionKey, storage::MemoryStorage, value_record::{Value, ValuePost}};
+    /// use std::sync::Arc;
+    ///
+    /// let storage = Arc::new(MemoryStorage::default());
+    /// let key = EncryptionKey::generate();
+    /// let new_key = EncryptionKey::generate();
+    /// let config = Config { encryption_key: Some(key.clone()), ..Default::default() };
+    /// let mut db = Db::with_storage(config, storage.clone());
+    /// db.restore().unwrap();
+    ///
+    /// let key_name = "test_key".to_string();
+    /// let value_post = ValuePost { expire_at: None, key: key_name.clone(), ttl: None, ttl_ms: None, value: Value::Integer(10) };
+    /// db.try_create(value_post).unwrap();
+    /// db.save().unwrap();
+    ///
+    /// let config = Config { encryption_key: Some(new_key.clone()), ..Default::default() };
+    /// let mut db = Db::with_storage(config, storage.clone());
+    ///
+    /// assert!(db.restore().is_err());
+    ///
+    /// let config = Config { encryption_key: Some(new_key.clone()), previous_encryption_keys: vec![key], ..Default::default() };
+    /// let mut db = Db::with_storage(config, storage.clone());
+    /// db.restore().unwrap();
+    /// db.save().unwrap();
+    ///
+    /// let config = Config { encryption_key: Some(new_key), ..Default::default() };
+    /// let mut db = Db::with_storage(config, storage);
+    /// db.restore().unwrap();
+    ///
+    /// assert!(db.try_read(&key_name).unwrap().is_some());
+    /// ```
     pub fn restore(&mut self) -> Result<()> {
-        if let Some(data_dir) = &self.config.data_dir {
-            let api_keys_file_path = format!("{data_dir}/{API_KEYS_FILE}");
-            if Path::new(&api_keys_file_path).exists() {
-                let compressed = fs::read(api_keys_file_path)?;
-                let uncompressed = decompress_size_prepended(&compressed)?;
-                let serialized = String::from_utf8(uncompressed)?;
-                self.api_keys = serde_json::from_str(&serialized)?;
-            }
+        if let Some(storage) = &self.storage {
+            let snapshot = match storage.load_snapshot()? {
+                None => storage.load_legacy_snapshot()?,
+                Some(encoded) => Some(Snapshot::decode(&encoded, &self.config.decryption_keys())?),
+            };
+
+            if let Some(snapshot) = snapshot {
+                let mut values_indexes = None;
+
+                if self.config.use_persisted_indexes {
+                    if let (
+                        Some(created_at_index),
+                        Some(delete_at_index),
+                        Some(key_index),
+                        Some(updated_at_index),
+                    ) = (
+                        snapshot.created_at_index,
+                        snapshot.delete_at_index,
+                        snapshot.key_index,
+                        snapshot.updated_at_index,
+                    ) {
+                        let persisted_values_indexes = ValueIndex {
+                            created_at: created_at_index.into_iter().collect(),
+                            delete_at: delete_at_index.into_iter().collect(),
+                            key: key_index.into_iter().collect(),
+                            updated_at: updated_at_index.into_iter().collect(),
+                        };
+
+                        if persisted_values_indexes.is_consistent_with(&snapshot.values) {
+                            values_indexes = Some(persisted_values_indexes);
+                        }
+                    }
+                }
 
-            let values_created_at_index_file_path =
-                format!("{data_dir}/{VALUES_CREATED_AT_INDEX_FILE}");
-            if Path::new(&values_created_at_index_file_path).exists() {
-                let compressed = fs::read(values_created_at_index_file_path)?;
-                let uncompressed = decompress_size_prepended(&compressed)?;
-                let serialized = String::from_utf8(uncompressed)?;
-                self.values_indexes.created_at = serde_json::from_str(&serialized)?;
+                self.api_keys = RwLock::new(snapshot.api_keys);
+                self.values_indexes =
+                    values_indexes.unwrap_or_else(|| ValueIndex::from_values(&snapshot.values));
+                self.values = ValueShards::from_values(snapshot.values);
             }
 
-            let values_delete_at_index_file_path =
-                format!("{data_dir}/{VALUES_DELETE_AT_INDEX_FILE}");
-            if Path::new(&values_delete_at_index_file_path).exists() {
-                let compressed = fs::read(values_delete_at_index_file_path)?;
-                let uncompressed = decompress_size_prepended(&compressed)?;
-                let serialized = String::from_utf8(uncompressed)?;
-                self.values_indexes.delete_at = serde_json::from_str(&serialized)?;
-            }
+            let (wal, wal_entries) = Wal::open(
+                storage.clone(),
+                self.config.wal_sync_policy,
+                self.config.encryption_key.clone(),
+                &self.config.decryption_keys(),
+            )?;
+            self.replay(wal_entries)?;
+            self.wal = Some(wal);
+        }
 
-            let values_key_index_file_path = format!("{data_dir}/{VALUES_KEY_INDEX_FILE}");
-            if Path::new(&values_key_index_file_path).exists() {
-                let compressed = fs::read(values_key_index_file_path)?;
-                let uncompressed = decompress_size_prepended(&compressed)?;
-                let serialized = String::from_utf8(uncompressed)?;
-                self.values_indexes.key = serde_json::from_str(&serialized)?;
-            }
+        Ok(())
+    }
+
+    fn replay(&self, wal_entries: Vec<WalEntry>) -> Result<()> {
+        for wal_entry in wal_entries {
+            let (id, value_record) = match wal_entry {
+                WalEntry::Delete(id) => (id, None),
+                WalEntry::Transaction(wal_entries) => {
+                    self.replay(wal_entries)?;
+
+                    continue;
+                }
+                WalEntry::Upsert(value_record) => (value_record.id, Some(value_record)),
+            };
+
+            let mut values = self.values.shard(&id).write().map_err(|_| Error::Lock)?;
 
-            let values_updated_at_index_file_path =
-                format!("{data_dir}/{VALUES_UPDATED_AT_INDEX_FILE}");
-            if Path::new(&values_updated_at_index_file_path).exists() {
-                let compressed = fs::read(values_updated_at_index_file_path)?;
-                let uncompressed = decompress_size_prepended(&compressed)?;
-                let serialized = String::from_utf8(uncompressed)?;
-                self.values_indexes.updated_at = serde_json::from_str(&serialized)?;
+            if let Some(original_value) = values.remove(&id) {
+                self.values_indexes.remove(&original_value);
             }
 
-            let values_file_path = format!("{data_dir}/{VALUES_DATABASE_FILE}");
-            if Path::new(&values_file_path).exists() {
-                let compressed = fs::read(values_file_path)?;
-                let uncompressed = decompress_size_prepended(&compressed)?;
-                let serialized = String::from_utf8(uncompressed)?;
-                self.values = serde_json::from_str(&serialized)?;
+            if let Some(value_record) = value_record {
+                self.values_indexes.insert(&value_record);
+                values.insert(id, value_record);
             }
         }
 
         Ok(())
     }
 
+    /// Saves the database regardless of the save thresholds. Used for the final save on shutdown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, storage::MemoryStorage, value_record::{Value, ValuePost}};
+    /// use std::sync::Arc;
+    ///
+    /// let storage = Arc::new(MemoryStorage::default());
+    /// let config = Config { save_triggered_after_ms: 60000, save_triggered_by_threshold: 8, ..Default::default() };
+    /// let mut db = Db::with_storage(config, storage.clone());
+    /// db.restore().unwrap();
+    /// db.save().unwrap();
+    ///
+    /// let value_post = ValuePost { expire_at: None, key: "test_key".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(10) };
+    /// db.try_create(value_post).unwrap();
+    /// db.save().unwrap();
+    ///
+    /// assert!(!storage.wal().unwrap().is_empty());
+    ///
+    /// db.force_save().unwrap();
+    ///
+    /// assert!(storage.wal().unwrap().is_empty());
+    /// assert!(db.get_stats().unwrap().last_save.unwrap().succeeded);
+    /// ```
+    pub fn force_save(&self) -> Result<()> {
+        self.save_snapshot(true)
+    }
+
+    /// Saves the database into a single snapshot, replacing the previous one atomically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, storage::SNAPSHOT_FILE, value_record::{Value, ValuePost}};
+    ///
+    /// let data_dir = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+    /// std::fs::create_dir_all(&data_dir).unwrap();
+    /// let config = Config {
+    ///     data_dir: Some(data_dir.to_str().unwrap().to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let mut db = Db::new(config.clone());
+    /// db.restore().unwrap();
+    ///
+    /// let key = "test_key".to_string();
+    /// let value = Value::Integer(10);
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value: value.clone() };
+    /// db.try_create(value_post).unwrap();
+    /// db.save().unwrap();
+    ///
+    /// assert!(data_dir.join(SNAPSHOT_FILE).exists());
+    ///
+    /// let mut db = Db::new(config.clone());
+    /// db.restore().unwrap();
+    /// let value_response = db.try_read(&key).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_response.value, value);
+    ///
+    /// let snapshot = std::fs::read(data_dir.join(SNAPSHOT_FILE)).unwrap();
+    /// std::fs::write(data_dir.join(SNAPSHOT_FILE), &snapshot[..snapshot.len() / 2]).unwrap();
+    /// let mut db = Db::new(config);
+    ///
+    /// assert!(db.restore().is_err());
+    /// # std::fs::remove_dir_all(data_dir).unwrap();
+    /// ```
     pub fn save(&self) -> Result<()> {
-        if let Some(data_dir) = &self.config.data_dir {
-            let mut stats = self.stats.write().map_err(|_| Error::Lock)?;
-
-            if stats.can_save(
-                self.config.save_triggered_after_ms,
-                self.config.save_triggered_by_threshold,
-            ) {
-                let api_keys = self.api_keys.read().map_err(|_| Error::Lock)?.to_owned();
-                let api_keys_file_path = format!("{data_dir}/{API_KEYS_FILE}");
-                let serialized = serde_json::to_vec(&*api_keys)?;
-                let compressed = compress_prepend_size(&serialized);
-                fs::write(api_keys_file_path, compressed)?;
-
-                let values_created_at_index = self
-                    .values_indexes
-                    .created_at
-                    .read()
-                    .map_err(|_| Error::Lock)?;
-                let values_created_at_index_file_path =
-                    format!("{data_dir}/{VALUES_CREATED_AT_INDEX_FILE}");
-                let serialized = serde_json::to_vec(&*values_created_at_index)?;
-                let compressed = compress_prepend_size(&serialized);
-                fs::write(values_created_at_index_file_path, compressed)?;
-
-                let values_delete_at_index = self
-                    .values_indexes
-                    .delete_at
-                    .read()
-                    .map_err(|_| Error::Lock)?;
-                let values_delete_at_index_file_path =
-                    format!("{data_dir}/{VALUES_DELETE_AT_INDEX_FILE}");
-                let serialized = serde_json::to_vec(&*values_delete_at_index)?;
-                let compressed = compress_prepend_size(&serialized);
-                fs::write(values_delete_at_index_file_path, compressed)?;
-
-                let values_key_index = self.values_indexes.key.read().map_err(|_| Error::Lock)?;
-                let values_key_index_file_path = format!("{data_dir}/{VALUES_KEY_INDEX_FILE}");
-                let serialized = serde_json::to_vec(&*values_key_index)?;
-                let compressed = compress_prepend_size(&serialized);
-                fs::write(values_key_index_file_path, compressed)?;
-
-                let values_updated_at_index = self
-                    .values_indexes
-                    .updated_at
-                    .read()
-                    .map_err(|_| Error::Lock)?;
-                let values_updated_at_index_file_path =
-                    format!("{data_dir}/{VALUES_UPDATED_AT_INDEX_FILE}");
-                let serialized = serde_json::to_vec(&*values_updated_at_index)?;
-                let compressed = compress_prepend_size(&serialized);
-                fs::write(values_updated_at_index_file_path, compressed)?;
-
-                let values = self.values.read().map_err(|_| Error::Lock)?;
-                let values_file_path = format!("{data_dir}/{VALUES_DATABASE_FILE}");
-                let serialized = serde_json::to_vec(&*values)?;
-                let compressed = compress_prepend_size(&serialized);
-                fs::write(values_file_path, compressed)?;
-
-                stats.update_saved_writes();
+        self.save_snapshot(false)
+    }
+
+    /// Syncs the write-ahead log once the interval of its sync policy has passed.
+    pub fn sync_wal(&self) -> Result<()> {
+        if let Some(wal) = &self.wal {
+            wal.sync()?;
+        }
+
+        Ok(())
+    }
+
+    fn save_snapshot(&self, force: bool) -> Result<()> {
+        if let Some(storage) = &self.storage {
+            let _checkpoint = self.checkpoint.write().map_err(|_| Error::Lock)?;
+
+            if force
+                || self.stats.can_save(
+                    self.config.save_triggered_after_ms,
+                    self.config.save_triggered_by_threshold,
+                )?
+            {
+                let started_at = Instant::now();
+                let result = self.write_snapshot(storage);
+                self.stats
+                    .update_last_save(started_at.elapsed().as_micros(), result.is_ok())?;
+                result?;
+
+                self.stats.update_saved_writes()?;
             }
         }
 
         Ok(())
     }
 
+    fn write_snapshot(&self, storage: &Arc<dyn StorageBackend>) -> Result<()> {
+        let api_keys = self.api_keys.read().map_err(|_| Error::Lock)?;
+        let values_shards = self.values.read_all()?;
+        let values = values_shards
+            .iter()
+            .map(|values_shard| &**values_shard)
+            .collect::<Vec<&HashMap<Uuid, ValueRecord>>>();
+
+        let snapshot_ref = SnapshotRef {
+            api_keys: &api_keys,
+            values: &values,
+            values_indexes: self
+                .config
+                .use_persisted_indexes
+                .then(|| &self.values_indexes),
+        };
+        storage.save_snapshot(&snapshot_ref.encode(
+            self.config.snapshot_compression,
+            self.config.encryption_key.as_ref(),
+        )?)?;
+
+        if let Some(wal) = &self.wal {
+            wal.truncate()?;
+        }
+
+        Ok(())
+    }
+
     /// Returns a list of records from the database.
     ///
+    /// Records can be filtered by a key prefix, which is looked up in the key index,
+    /// and by a glob pattern of the key. When sorting by a timestamp, they can also be limited
+    /// to a time range of it, which is looked up in the index of the timestamp.
+    ///
+    /// A full page comes with a cursor, which continues listing right after its last record in
+    /// the index. Unlike pages, cursors don't skip or repeat records inserted meanwhile.
+    ///
     /// # Examples
     ///
     /// ```
-    /// use alex_db_lib::{config::Config, db::{Db, Direction, Sort}, value_record::{Value, ValuePost}};
+    /// use alex_db_lib::{config::Config, db::{Db, Direction, ListFilter, Sort}, value_record::{Value, ValuePost}};
     ///
     /// let config = Config::default();
     /// let mut db = Db::new(config);
     ///
-    /// assert_eq!(0, db.stats.read().unwrap().reads);
+    /// assert_eq!(0, db.get_stats().unwrap().reads);
     ///
-    /// let value_responses = db.list(Direction::Asc, None, None, Sort::CreatedAt).unwrap();
+    /// let value_list = db.list(None, Direction::Asc, &ListFilter::default(), None, None, Sort::CreatedAt).unwrap();
     ///
-    /// assert_eq!(0, value_responses.len());
-    /// assert_eq!(0, db.stats.read().unwrap().reads);
+    /// assert_eq!(0, value_list.values.len());
+    /// assert_eq!(0, db.get_stats().unwrap().reads);
     ///
     /// let key = "test_key".to_string();
     /// let value = Value::Boolean(true);
-    /// let value_post = ValuePost { key: key.clone(), ttl: None, value: value.clone() };
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value: value.clone() };
     /// db.try_create(value_post);
-    /// let value_responses = db.list(Direction::Asc, None, None, Sort::CreatedAt).unwrap();
+    /// let value_list = db.list(None, Direction::Asc, &ListFilter::default(), None, None, Sort::CreatedAt).unwrap();
+    ///
+    /// assert_eq!(1, value_list.values.len());
+    /// assert_eq!(1, db.get_stats().unwrap().reads);
+    ///
+    /// let value_post = ValuePost { expire_at: None, key: "expired_key".to_string(), ttl: None, ttl_ms: Some(0), value };
+    /// db.try_create(value_post);
+    /// let value_list = db.list(None, Direction::Asc, &ListFilter::default(), None, None, Sort::CreatedAt).unwrap();
+    ///
+    /// assert_eq!(1, value_list.values.len());
+    /// assert_eq!(1, db.values.len().unwrap());
+    /// ```
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::{Db, Direction, ListFilter, Sort}, value_record::{Value, ValuePost}};
+    ///
+    /// let config = Config::default();
+    /// let mut db = Db::new(config);
+    ///
+    /// for key in ["user:1:session", "user:1:token", "user:2:session", "users"] {
+    ///     let value_post = ValuePost { expire_at: None, key: key.to_string(), ttl: None, ttl_ms: None, value: Value::Integer(10) };
+    ///     db.try_create(value_post).unwrap();
+    /// }
+    ///
+    /// let filter = ListFilter { prefix: Some("user:".to_string()), ..Default::default() };
+    /// let value_list = db.list(None, Direction::Desc, &filter, None, None, Sort::Key).unwrap();
     ///
-    /// assert_eq!(1, value_responses.len());
-    /// assert_eq!(1, db.stats.read().unwrap().reads);
+    /// assert_eq!(vec!["user:2:session", "user:1:token", "user:1:session"], value_list.values.iter().map(|value| value.key.as_str()).collect::<Vec<&str>>());
+    ///
+    /// let filter = ListFilter { pattern: Some("*:session".to_string()), prefix: Some("user:".to_string()), ..Default::default() };
+    /// let value_list = db.list(None, Direction::Asc, &filter, Some(1), Some(2), Sort::CreatedAt).unwrap();
+    ///
+    /// assert_eq!(1, value_list.values.len());
+    /// assert_eq!("user:2:session", value_list.values[0].key);
+    ///
+    /// let value_list = db.list(None, Direction::Asc, &ListFilter::default(), Some(2), None, Sort::Key).unwrap();
+    ///
+    /// assert_eq!(vec!["user:1:session", "user:1:token"], value_list.values.iter().map(|value| value.key.as_str()).collect::<Vec<&str>>());
+    ///
+    /// let value_post = ValuePost { expire_at: None, key: "user:0".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(10) };
+    /// db.try_create(value_post).unwrap();
+    /// let value_list = db.list(value_list.next_cursor.as_deref(), Direction::Asc, &ListFilter::default(), Some(2), None, Sort::Key).unwrap();
+    ///
+    /// assert_eq!(vec!["user:2:session", "users"], value_list.values.iter().map(|value| value.key.as_str()).collect::<Vec<&str>>());
+    /// assert!(value_list.next_cursor.is_none());
+    ///
+    /// let value_list = db.list(Some("wrong_cursor"), Direction::Asc, &ListFilter::default(), None, None, Sort::Key);
+    ///
+    /// assert!(value_list.is_err());
+    /// ```
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::{Db, Direction, ListFilter, Sort}, value_record::{Value, ValuePost}};
+    /// use chrono::{Duration, Utc};
+    ///
+    /// let config = Config::default();
+    /// let mut db = Db::new(config);
+    ///
+    /// let value_post = ValuePost { expire_at: None, key: "test_key1".to_string(), ttl: Some(10), ttl_ms: None, value: Value::Integer(10) };
+    /// db.try_create(value_post).unwrap();
+    /// let value_post = ValuePost { expire_at: None, key: "test_key2".to_string(), ttl: Some(1000), ttl_ms: None, value: Value::Integer(20) };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// let filter = ListFilter { ends_at: Some(Utc::now() + Duration::seconds(100)), ..Default::default() };
+    /// let value_list = db.list(None, Direction::Asc, &filter, None, None, Sort::DeleteAt).unwrap();
+    ///
+    /// assert_eq!(1, value_list.values.len());
+    /// assert_eq!("test_key1", value_list.values[0].key);
+    ///
+    /// let filter = ListFilter { starts_at: Some(Utc::now() - Duration::hours(1)), ..Default::default() };
+    /// let value_list = db.list(None, Direction::Desc, &filter, None, None, Sort::UpdatedAt).unwrap();
+    ///
+    /// assert_eq!(2, value_list.values.len());
+    ///
+    /// let filter = ListFilter { starts_at: Some(Utc::now() + Duration::hours(1)), ..Default::default() };
+    /// let value_list = db.list(None, Direction::Desc, &filter, None, None, Sort::UpdatedAt).unwrap();
+    ///
+    /// assert!(value_list.values.is_empty());
     /// ```
     pub fn list(
         &self,
+        cursor: Option<&str>,
         direction: Direction,
+        filter: &ListFilter,
         limit: Option<usize>,
         page: Option<usize>,
         sort: Sort,
-    ) -> Result<Vec<ValueResponse>> {
-        let mut stats = self.stats.write().map_err(|_| Error::Lock)?;
-        stats.inc_requests();
+    ) -> Result<ValueListResponse> {
+        self.stats.track(Operation::List, || {
+            let cursor = match cursor {
+                None => None,
+                Some(cursor) => Some(cursor.parse::<Cursor>()?),
+            };
+            let limit = limit.unwrap_or(100);
+            let page = page.unwrap_or(1);
+
+            // A cursor continues right after the last listed record, so pages are skipped without it only.
+            let skip = match cursor {
+                None => (page - 1) * limit,
+                Some(_) => 0,
+            };
+
+            let positions =
+                self.values_indexes
+                    .positions(cursor.as_ref(), &direction, filter, &sort)?;
+            let (value_records, next_cursor) = self.page_values(positions, filter, skip, limit)?;
+
+            let mut result = vec![];
+
+            for value_record in value_records {
+                result.append(&mut vec![value_record.into()]);
+                self.stats.inc_reads();
+            }
 
-        let values = self.values.read().map_err(|_| Error::Lock)?;
-        let mut result = vec![];
-        let mut ids = vec![];
+            Ok(ValueListResponse {
+                next_cursor: next_cursor.map(|cursor| cursor.to_string()),
+                values: result,
+            })
+        })
+    }
 
-        match sort {
-            Sort::CreatedAt => {
-                let values_created_at_index = self
-                    .values_indexes
-                    .created_at
-                    .read()
-                    .map_err(|_| Error::Lock)?;
-
-                match direction {
-                    Direction::Asc => {
-                        for (_key, value) in values_created_at_index.iter() {
-                            ids.append(&mut vec![*value]);
-                        }
-                    }
-                    Direction::Desc => {
-                        for (_key, value) in values_created_at_index.iter().rev() {
-                            ids.append(&mut vec![*value]);
-                        }
-                    }
-                }
-            }
-            Sort::DeleteAt => {
-                let values_delete_at_index = self
-                    .values_indexes
-                    .delete_at
-                    .read()
-                    .map_err(|_| Error::Lock)?;
-
-                match direction {
-                    Direction::Asc => {
-                        for (_key, value) in values_delete_at_index.iter() {
-                            ids.append(&mut vec![*value]);
-                        }
-                    }
-                    Direction::Desc => {
-                        for (_key, value) in values_delete_at_index.iter().rev() {
-                            ids.append(&mut vec![*value]);
-                        }
-                    }
-                }
+    /// Returns a page of records in the order of the specified index positions, leaving out
+    /// records not matching the filter, expired records and records deleted after their ids were
+    /// read from an index. Expired records are removed.
+    ///
+    /// When more positions follow a full page, the position of its last record is returned
+    /// as the cursor to continue from.
+    fn page_values(
+        &self,
+        positions: impl Iterator<Item = (Cursor, Uuid)>,
+        filter: &ListFilter,
+        skip: usize,
+        limit: usize,
+    ) -> Result<(Vec<ValueRecord>, Option<Cursor>)> {
+        let now = Utc::now();
+        let mut expired_ids = vec![];
+        let mut last_position = None;
+        let mut next_cursor = None;
+        let mut skipped = 0;
+        let mut value_records = vec![];
+
+        for (position, id) in positions {
+            if value_records.len() >= limit {
+                next_cursor = last_position;
+                break;
             }
-            Sort::Key => {
-                let values_key_index = self.values_indexes.key.read().map_err(|_| Error::Lock)?;
 
-                match direction {
-                    Direction::Asc => {
-                        for (_key, value) in values_key_index.iter() {
-                            ids.append(&mut vec![*value]);
-                        }
-                    }
-                    Direction::Desc => {
-                        for (_key, value) in values_key_index.iter().rev() {
-                            ids.append(&mut vec![*value]);
-                        }
-                    }
-                }
-            }
-            Sort::UpdatedAt => {
-                let values_updated_at_index = self
-                    .values_indexes
-                    .updated_at
-                    .read()
-                    .map_err(|_| Error::Lock)?;
-
-                match direction {
-                    Direction::Asc => {
-                        for (_key, value) in values_updated_at_index.iter() {
-                            ids.append(&mut vec![*value]);
-                        }
-                    }
-                    Direction::Desc => {
-                        for (_key, value) in values_updated_at_index.iter().rev() {
-                            ids.append(&mut vec![*value]);
-                        }
-                    }
-                }
+            let values = self.values.shard(&id).read().map_err(|_| Error::Lock)?;
+            let value_record = match values.get(&id) {
+                None => continue,
+                Some(value_record) => value_record,
+            };
+
+            if value_record.is_expired(now) {
+                expired_ids.append(&mut vec![id]);
+            } else if !filter.matches(&value_record.key) {
+                continue;
+            } else if skipped < skip {
+                skipped += 1;
+            } else {
+                value_records.append(&mut vec![value_record.clone()]);
+                last_position = Some(position);
             }
         }
 
-        let limit = limit.unwrap_or(100);
-        let page = page.unwrap_or(1);
-
-        let skip = (page - 1) * limit;
-
-        ids = ids
-            .into_iter()
-            .skip(skip)
-            .take(limit)
-            .collect::<Vec<Uuid>>();
-
-        for id in ids {
-            let value = values.get(&id).ok_or(Error::NotFound)?.clone();
-            result.append(&mut vec![value.into()]);
-            stats.inc_reads();
+        for id in expired_ids {
+            self.try_delete_by_id(id, Some(now), None)?;
         }
 
-        Ok(result)
+        Ok((value_records, next_cursor))
     }
 
     /// Tries to append a value to an existing record in the database using the specified key.
     ///
+    /// With `create`, a missing record is created from an empty array, expiring after `ttl`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -378,83 +667,242 @@ impl Db {
     /// let config = Config::default();
     /// let mut db = Db::new(config);
     ///
-    /// assert_eq!(0, db.stats.read().unwrap().writes);
+    /// assert_eq!(0, db.get_stats().unwrap().writes);
     ///
     /// let key = "test_key".to_string();
     /// let value1 = Value::String("test_value".to_string());
     /// let value1_array = Value::Array(VecDeque::from([value1.clone()]));
-    /// let value_post = ValuePost { key: key.clone(), ttl: None, value: value1_array.clone() };
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value: value1_array.clone() };
     /// let value_response = db.try_create(value_post).unwrap().unwrap();
     ///
     /// assert_eq!(value_response.key, key);
     /// assert_eq!(value_response.value, value1_array);
-    /// assert_eq!(1, db.stats.read().unwrap().writes);
+    /// assert_eq!(1, db.get_stats().unwrap().writes);
     ///
     /// let value2 = Value::Integer(100);
     /// let value2_array = Value::Array(VecDeque::from([value2.clone()]));
-    /// let value_append = ValueAppend { append: value2_array.clone() };
+    /// let value_append = ValueAppend { append: value2_array.clone(), create: false, expected_version: None, ttl: None };
     /// let value_response = db.try_append(&key, value_append.clone()).unwrap().unwrap();
     ///
     /// assert_eq!(value_response.key, key);
     /// assert_eq!(value_response.value, Value::Array(VecDeque::from([value1, value2])));
-    /// assert_eq!(2, db.stats.read().unwrap().writes);
+    /// assert_eq!(2, db.get_stats().unwrap().writes);
     ///
-    /// let value_response = db.try_append("wrong_key", value_append);
+    /// let value_response = db.try_append("wrong_key", value_append.clone());
     ///
     /// assert!(value_response.is_err());
+    ///
+    /// let value_append = ValueAppend { create: true, ttl: Some(100), ..value_append };
+    /// let value_response = db.try_append("new_key", value_append).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_response.value, value2_array);
+    /// assert_eq!(value_response.version, 1);
+    /// assert!(db.try_read_ttl("new_key").unwrap().unwrap().ttl.is_some());
     /// ```
     pub fn try_append(
         &self,
         key: &str,
         value_append: ValueAppend,
     ) -> Result<Option<ValueResponse>> {
-        let mut stats = self.stats.write().map_err(|_| Error::Lock)?;
-        stats.inc_requests();
-
-        let values_key_index = self.values_indexes.key.write().map_err(|_| Error::Lock)?;
-        let id = *values_key_index.get(key).ok_or(Error::NotFound)?;
+        self.stats.track(Operation::Append, || {
+            if let Some((value, delete_at)) = value_append.initial(Utc::now())? {
+                let value = match value_append.clone().apply(value) {
+                    None => return Ok(None),
+                    Some(value) => value,
+                };
+
+                if let Some(result) = self.create_missing(key, &value, delete_at)? {
+                    return Ok(Some(result));
+                }
+            }
 
-        let mut values = self.values.write().map_err(|_| Error::Lock)?;
-        let original_value = values.get(&id).ok_or(Error::NotFound)?.clone();
+            let _checkpoint = self.checkpoint.read().map_err(|_| Error::Lock)?;
+            let id = self.values_indexes.get_id(key).ok_or(Error::NotFound)?;
 
-        let value = match (original_value.value, value_append.append) {
-            (Value::Array(original_value_vec), Value::Array(mut value_append_vec)) => {
-                let mut new_value = original_value_vec;
-                new_value.append(&mut value_append_vec);
+            let mut values = self.values.shard(&id).write().map_err(|_| Error::Lock)?;
+            let original_value = self
+                .get_unexpired(&mut values, id)?
+                .ok_or(Error::NotFound)?;
 
-                Value::Array(new_value)
+            if !original_value.has_version(value_append.expected_version) {
+                return Err(Box::new(Error::VersionMismatch));
             }
-            _ => return Ok(None),
-        };
-
-        let now = Utc::now();
-        let value_record = ValueRecord::new(
-            id,
-            &original_value.key,
-            &value,
-            original_value.created_at,
-            original_value.delete_at,
-            now,
-        );
-        values.insert(id, value_record);
-        let result = values.get(&id).cloned();
 
-        match result {
-            None => Ok(None),
-            Some(result) => {
-                stats.inc_writes();
+            let value = match value_append.apply(original_value.value) {
+                None => return Ok(None),
+                Some(value) => value,
+            };
+
+            let now = Utc::now();
+            let value_record = ValueRecord::new(
+                id,
+                &original_value.key,
+                &value,
+                original_value.created_at,
+                original_value.delete_at,
+                now,
+                original_value.version + 1,
+            );
+            self.wal_append(&WalEntry::Upsert(value_record.clone()))?;
+            values.insert(id, value_record);
+            let result = values.get(&id).cloned();
+
+            match result {
+                None => Ok(None),
+                Some(result) => {
+                    self.stats.inc_writes();
+
+                    self.values_indexes.updated_at.remove(&(
+                        original_value
+                            .updated_at
+                            .timestamp_nanos_opt()
+                            .unwrap_or_default(),
+                        id,
+                    ));
+                    self.values_indexes.updated_at.insert((
+                        result.updated_at.timestamp_nanos_opt().unwrap_or_default(),
+                        id,
+                    ));
+
+                    Ok(Some(result.into()))
+                }
+            }
+        })
+    }
 
-                let mut values_updated_at_index = self
-                    .values_indexes
-                    .updated_at
-                    .write()
-                    .map_err(|_| Error::Lock)?;
-                values_updated_at_index.remove(&original_value.updated_at.timestamp_nanos());
-                values_updated_at_index.insert(result.updated_at.timestamp_nanos(), id);
+    /// Tries to replace a value of an existing record in the database using the specified key,
+    /// but only when the current value equals the expected one.
+    ///
+    /// The response tells whether the value was swapped and holds the value the record ends up with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, value_record::{Value, ValuePost}};
+    ///
+    /// let config = Config::default();
+    /// let mut db = Db::new(config);
+    ///
+    /// let key = "test_key".to_string();
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value: Value::Boolean(false) };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// let value_response = db.try_compare_and_swap(&key, Value::Boolean(false), Value::Boolean(true)).unwrap().unwrap();
+    ///
+    /// assert!(value_response.swapped);
+    /// assert_eq!(value_response.value, Value::Boolean(true));
+    /// assert_eq!(value_response.version, 2);
+    /// assert_eq!(2, db.get_stats().unwrap().writes);
+    ///
+    /// let value_response = db.try_compare_and_swap(&key, Value::Boolean(false), Value::Boolean(true)).unwrap().unwrap();
+    ///
+    /// assert!(!value_response.swapped);
+    /// assert_eq!(value_response.value, Value::Boolean(true));
+    /// assert_eq!(value_response.version, 2);
+    /// assert_eq!(2, db.get_stats().unwrap().writes);
+    /// assert_eq!(2, db.get_stats().unwrap().operations.compare_and_swap);
+    ///
+    /// let value_response = db.try_compare_and_swap("wrong_key", Value::Boolean(true), Value::Boolean(false));
+    ///
+    /// assert!(value_response.is_err());
+    /// ```
+    pub fn try_compare_and_swap(
+        &self,
+        key: &str,
+        expected: Value,
+        new: Value,
+    ) -> Result<Option<ValueCompareAndSwapResponse>> {
+        self.stats.track(Operation::CompareAndSwap, || {
+            let _checkpoint = self.checkpoint.read().map_err(|_| Error::Lock)?;
+            let id = self.values_indexes.get_id(key).ok_or(Error::NotFound)?;
+
+            let mut values = self.values.shard(&id).write().map_err(|_| Error::Lock)?;
+            let original_value = self
+                .get_unexpired(&mut values, id)?
+                .ok_or(Error::NotFound)?;
+
+            if original_value.value != expected {
+                return Ok(Some(ValueCompareAndSwapResponse::new(
+                    original_value,
+                    false,
+                )));
+            }
 
-                Ok(Some(result.into()))
+            let now = Utc::now();
+            let value_record = ValueRecord::new(
+                id,
+                &original_value.key,
+                &new,
+                original_value.created_at,
+                original_value.delete_at,
+                now,
+                original_value.version + 1,
+            );
+            self.wal_append(&WalEntry::Upsert(value_record.clone()))?;
+            values.insert(id, value_record);
+            let result = values.get(&id).cloned();
+
+            match result {
+                None => Ok(None),
+                Some(result) => {
+                    self.stats.inc_writes();
+
+                    self.values_indexes.updated_at.remove(&(
+                        original_value
+                            .updated_at
+                            .timestamp_nanos_opt()
+                            .unwrap_or_default(),
+                        id,
+                    ));
+                    self.values_indexes.updated_at.insert((
+                        result.updated_at.timestamp_nanos_opt().unwrap_or_default(),
+                        id,
+                    ));
+
+                    Ok(Some(ValueCompareAndSwapResponse::new(result, true)))
+                }
             }
-        }
+        })
+    }
+
+    /// Tries to copy a record to a new key. The copy keeps the value and the expiration of the
+    /// original, but is a separate record created now. A record already stored under the new key is
+    /// only replaced with `overwrite`, keeping its creation time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, value_record::{Value, ValuePost}};
+    ///
+    /// let config = Config::default();
+    /// let mut db = Db::new(config);
+    ///
+    /// let value_post = ValuePost { expire_at: None, key: "test_key1".to_string(), ttl: Some(100), ttl_ms: None, value: Value::Integer(10) };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// let value_response = db.try_copy("test_key1", "test_key2", false).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_response.key, "test_key2");
+    /// assert_eq!(value_response.value, Value::Integer(10));
+    /// assert_eq!(value_response.version, 1);
+    /// assert_eq!(Value::Integer(10), db.try_read("test_key1").unwrap().unwrap().value);
+    /// assert!(db.try_read_ttl("test_key2").unwrap().unwrap().ttl.is_some());
+    /// assert_eq!(2, db.values_indexes.delete_at.len());
+    ///
+    /// assert!(db.try_copy("test_key1", "test_key2", false).is_err());
+    /// assert_eq!(1, db.get_stats().unwrap().errors.conflict);
+    ///
+    /// let value_response = db.try_copy("test_key1", "test_key2", true).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_response.version, 2);
+    /// assert_eq!(2, db.values.len().unwrap());
+    ///
+    /// assert!(db.try_copy("wrong_key", "test_key3", false).is_err());
+    /// assert_eq!(4, db.get_stats().unwrap().operations.copy);
+    /// ```
+    pub fn try_copy(&self, from: &str, to: &str, overwrite: bool) -> Result<Option<ValueResponse>> {
+        self.stats
+            .track(Operation::Copy, || self.transfer(from, to, overwrite, true))
     }
 
     /// Tries to create a new record containing a value in the database.
@@ -467,102 +915,91 @@ impl Db {
     /// let config = Config::default();
     /// let mut db = Db::new(config);
     ///
-    /// assert_eq!(0, db.values_indexes.created_at.read().unwrap().len());
-    /// assert_eq!(0, db.values_indexes.delete_at.read().unwrap().len());
-    /// assert_eq!(0, db.values_indexes.key.read().unwrap().len());
-    /// assert_eq!(0, db.values_indexes.updated_at.read().unwrap().len());
-    /// assert_eq!(0, db.stats.read().unwrap().writes);
-    /// assert_eq!(0, db.values.read().unwrap().len());
+    /// assert_eq!(0, db.values_indexes.created_at.len());
+    /// assert_eq!(0, db.values_indexes.delete_at.len());
+    /// assert_eq!(0, db.values_indexes.key.len());
+    /// assert_eq!(0, db.values_indexes.updated_at.len());
+    /// assert_eq!(0, db.get_stats().unwrap().writes);
+    /// assert_eq!(0, db.values.len().unwrap());
     ///
     /// let key = "test_key1".to_string();
     /// let value = Value::String("test_value".to_string());
-    /// let value_post = ValuePost { key: key.clone(), ttl: None, value: value.clone() };
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value: value.clone() };
     /// let value_response = db.try_create(value_post).unwrap().unwrap();
     ///
     /// assert_eq!(value_response.key, key);
     /// assert_eq!(value_response.value, value);
-    /// assert_eq!(1, db.values_indexes.created_at.read().unwrap().len());
-    /// assert_eq!(0, db.values_indexes.delete_at.read().unwrap().len());
-    /// assert_eq!(1, db.values_indexes.key.read().unwrap().len());
-    /// assert_eq!(1, db.values_indexes.updated_at.read().unwrap().len());
-    /// assert_eq!(1, db.stats.read().unwrap().writes);
-    /// assert_eq!(1, db.values.read().unwrap().len());
+    /// assert_eq!(1, db.values_indexes.created_at.len());
+    /// assert_eq!(0, db.values_indexes.delete_at.len());
+    /// assert_eq!(1, db.values_indexes.key.len());
+    /// assert_eq!(1, db.values_indexes.updated_at.len());
+    /// assert_eq!(1, db.get_stats().unwrap().writes);
+    /// assert_eq!(1, db.values.len().unwrap());
     ///
     /// let key = "test_key2".to_string();
     /// let value = Value::Integer(10);
-    /// let value_post = ValuePost { key: key.clone(), ttl: Some(100), value: value.clone() };
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: Some(100), ttl_ms: None, value: value.clone() };
     /// let value_response = db.try_create(value_post.clone()).unwrap().unwrap();
     ///
     /// assert_eq!(value_response.key, key);
     /// assert_eq!(value_response.value, value);
-    /// assert_eq!(2, db.values_indexes.created_at.read().unwrap().len());
-    /// assert_eq!(1, db.values_indexes.delete_at.read().unwrap().len());
-    /// assert_eq!(2, db.values_indexes.key.read().unwrap().len());
-    /// assert_eq!(2, db.values_indexes.updated_at.read().unwrap().len());
-    /// assert_eq!(2, db.stats.read().unwrap().writes);
-    /// assert_eq!(2, db.values.read().unwrap().len());
+    /// assert_eq!(2, db.values_indexes.created_at.len());
+    /// assert_eq!(1, db.values_indexes.delete_at.len());
+    /// assert_eq!(2, db.values_indexes.key.len());
+    /// assert_eq!(2, db.values_indexes.updated_at.len());
+    /// assert_eq!(2, db.get_stats().unwrap().writes);
+    /// assert_eq!(2, db.values.len().unwrap());
     ///
     /// let value_response = db.try_create(value_post);
     ///
     /// assert!(value_response.is_err());
+    /// assert_eq!(3, db.get_stats().unwrap().operations.create);
+    /// assert_eq!(1, db.get_stats().unwrap().errors.conflict);
     /// ```
     pub fn try_create(&self, value_post: ValuePost) -> Result<Option<ValueResponse>> {
-        let mut stats = self.stats.write().map_err(|_| Error::Lock)?;
-        stats.inc_requests();
+        self.stats.track(Operation::Create, || {
+            let delete_at = value_post.delete_at(Utc::now())?;
 
-        let mut values_key_index = self.values_indexes.key.write().map_err(|_| Error::Lock)?;
-        let existing_id = values_key_index.get(&value_post.key);
-
-        if existing_id.is_some() {
-            return Err(Box::new(Error::KeyExists));
-        }
+            self.create(&value_post.key, &value_post.value, delete_at)
+        })
+    }
 
-        let mut values = self.values.write().map_err(|_| Error::Lock)?;
-        let id = Uuid::new_v4();
-        let now = Utc::now();
-        let delete_at = value_post.ttl.map(|ttl| now + Duration::seconds(ttl));
-        let value_record =
-            ValueRecord::new(id, &value_post.key, &value_post.value, now, delete_at, now);
-        values.insert(id, value_record);
-        let result = values.get(&id).cloned();
-
-        match result {
-            None => Ok(None),
-            Some(result) => {
-                stats.inc_writes();
-
-                let mut values_created_at_index = self
-                    .values_indexes
-                    .created_at
-                    .write()
-                    .map_err(|_| Error::Lock)?;
-                values_created_at_index.insert(result.created_at.timestamp_nanos(), id);
-
-                if let Some(delete_at) = delete_at {
-                    let mut values_delete_at_index = self
-                        .values_indexes
-                        .delete_at
-                        .write()
-                        .map_err(|_| Error::Lock)?;
-                    values_delete_at_index.insert(delete_at.timestamp_nanos(), id);
-                }
-
-                values_key_index.insert(value_post.key, id);
-
-                let mut values_updated_at_index = self
-                    .values_indexes
-                    .updated_at
-                    .write()
-                    .map_err(|_| Error::Lock)?;
-                values_updated_at_index.insert(result.updated_at.timestamp_nanos(), id);
-
-                Ok(Some(result.into()))
-            }
-        }
+    /// Tries to create records in the database in a single batch. Keys that already exist are
+    /// reported as conflicts, the other records are created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{batch::BatchStatus, config::Config, db::Db, value_record::{Value, ValuePost}};
+    ///
+    /// let config = Config::default();
+    /// let mut db = Db::new(config);
+    ///
+    /// let value_post = ValuePost { expire_at: None, key: "test_key1".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(10) };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// let values = vec![
+    ///     ValuePost { expire_at: None, key: "test_key1".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(20) },
+    ///     ValuePost { expire_at: None, key: "test_key2".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(30) },
+    /// ];
+    /// let results = db.try_create_many(values).unwrap();
+    ///
+    /// assert_eq!(BatchStatus::Conflict, results[0].status);
+    /// assert_eq!(BatchStatus::Created, results[1].status);
+    /// assert_eq!(Value::Integer(10), db.try_read("test_key1").unwrap().unwrap().value);
+    /// assert_eq!(Value::Integer(30), db.try_read("test_key2").unwrap().unwrap().value);
+    /// assert_eq!(1, db.get_stats().unwrap().operations.batch_set);
+    /// assert_eq!(1, db.get_stats().unwrap().errors.conflict);
+    /// ```
+    pub fn try_create_many(&self, values: Vec<ValuePost>) -> Result<Vec<BatchResult>> {
+        self.stats
+            .track(Operation::BatchSet, || self.set_many(values, false))
     }
 
     /// Tries to decrement a value of an existing record in the database using the specified key.
     ///
+    /// With `create`, a missing record is created from zero, expiring after `ttl`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -572,91 +1009,110 @@ impl Db {
     /// let config = Config::default();
     /// let mut db = Db::new(config);
     ///
-    /// assert_eq!(0, db.stats.read().unwrap().writes);
+    /// assert_eq!(0, db.get_stats().unwrap().writes);
     ///
     /// let key = "test_key".to_string();
     /// let value = Value::Integer(5000);
-    /// let value_post = ValuePost { key: key.clone(), ttl: None, value: value.clone() };
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value: value.clone() };
     /// let value_response = db.try_create(value_post).unwrap().unwrap();
     ///
     /// assert_eq!(value_response.key, key);
     /// assert_eq!(value_response.value, value);
-    /// assert_eq!(1, db.stats.read().unwrap().writes);
+    /// assert_eq!(1, db.get_stats().unwrap().writes);
     ///
-    /// let value_decrement = ValueDecrement { decrement: None };
+    /// let value_decrement = ValueDecrement { create: false, decrement: None, expected_version: None, ttl: None };
     /// let value_response = db.try_decrement(&key, value_decrement).unwrap().unwrap();
     ///
     /// assert_eq!(value_response.key, key);
     /// assert_eq!(value_response.value, Value::Integer(4999));
-    /// assert_eq!(2, db.stats.read().unwrap().writes);
+    /// assert_eq!(2, db.get_stats().unwrap().writes);
     ///
-    /// let value_decrement = ValueDecrement { decrement: Some(10) };
+    /// let value_decrement = ValueDecrement { create: false, decrement: Some(10), expected_version: None, ttl: None };
     /// let value_response = db.try_decrement(&key, value_decrement.clone()).unwrap().unwrap();
     ///
     /// assert_eq!(value_response.key, key);
     /// assert_eq!(value_response.value, Value::Integer(4989));
-    /// assert_eq!(3, db.stats.read().unwrap().writes);
+    /// assert_eq!(3, db.get_stats().unwrap().writes);
     ///
-    /// let value_response = db.try_decrement("wrong_key", value_decrement);
+    /// let value_response = db.try_decrement("wrong_key", value_decrement.clone());
     ///
     /// assert!(value_response.is_err());
+    ///
+    /// let value_decrement = ValueDecrement { create: true, ..value_decrement };
+    /// let value_response = db.try_decrement("new_key", value_decrement).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_response.value, Value::Integer(-10));
+    /// assert_eq!(value_response.version, 1);
     /// ```
     pub fn try_decrement(
         &self,
         key: &str,
         value_decrement: ValueDecrement,
     ) -> Result<Option<ValueResponse>> {
-        let mut stats = self.stats.write().map_err(|_| Error::Lock)?;
-        stats.inc_requests();
-
-        let values_key_index = self.values_indexes.key.write().map_err(|_| Error::Lock)?;
-        let id = *values_key_index.get(key).ok_or(Error::NotFound)?;
-
-        let mut values = self.values.write().map_err(|_| Error::Lock)?;
-        let original_value = values.get(&id).ok_or(Error::NotFound)?.clone();
-
-        let value = match original_value.value {
-            Value::Integer(original_value_integer) => match value_decrement.decrement {
-                None => Value::Integer(original_value_integer.saturating_sub(1)),
-                Some(decrement) => {
-                    if let Some(abs) = decrement.checked_abs() {
-                        Value::Integer(original_value_integer.saturating_sub(abs))
-                    } else {
-                        Value::Integer(original_value_integer)
-                    }
+        self.stats.track(Operation::Decrement, || {
+            if let Some((value, delete_at)) = value_decrement.initial(Utc::now())? {
+                let value = match value_decrement.apply(value) {
+                    None => return Ok(None),
+                    Some(value) => value,
+                };
+
+                if let Some(result) = self.create_missing(key, &value, delete_at)? {
+                    return Ok(Some(result));
                 }
-            },
-            _ => return Ok(None),
-        };
+            }
 
-        let now = Utc::now();
-        let value_record = ValueRecord::new(
-            id,
-            &original_value.key,
-            &value,
-            original_value.created_at,
-            original_value.delete_at,
-            now,
-        );
-        values.insert(id, value_record);
-        let result = values.get(&id).cloned();
+            let _checkpoint = self.checkpoint.read().map_err(|_| Error::Lock)?;
+            let id = self.values_indexes.get_id(key).ok_or(Error::NotFound)?;
 
-        match result {
-            None => Ok(None),
-            Some(result) => {
-                stats.inc_writes();
+            let mut values = self.values.shard(&id).write().map_err(|_| Error::Lock)?;
+            let original_value = self
+                .get_unexpired(&mut values, id)?
+                .ok_or(Error::NotFound)?;
 
-                let mut values_updated_at_index = self
-                    .values_indexes
-                    .updated_at
-                    .write()
-                    .map_err(|_| Error::Lock)?;
-                values_updated_at_index.remove(&original_value.updated_at.timestamp_nanos());
-                values_updated_at_index.insert(result.updated_at.timestamp_nanos(), id);
+            if !original_value.has_version(value_decrement.expected_version) {
+                return Err(Box::new(Error::VersionMismatch));
+            }
 
-                Ok(Some(result.into()))
+            let value = match value_decrement.apply(original_value.value) {
+                None => return Ok(None),
+                Some(value) => value,
+            };
+
+            let now = Utc::now();
+            let value_record = ValueRecord::new(
+                id,
+                &original_value.key,
+                &value,
+                original_value.created_at,
+                original_value.delete_at,
+                now,
+                original_value.version + 1,
+            );
+            self.wal_append(&WalEntry::Upsert(value_record.clone()))?;
+            values.insert(id, value_record);
+            let result = values.get(&id).cloned();
+
+            match result {
+                None => Ok(None),
+                Some(result) => {
+                    self.stats.inc_writes();
+
+                    self.values_indexes.updated_at.remove(&(
+                        original_value
+                            .updated_at
+                            .timestamp_nanos_opt()
+                            .unwrap_or_default(),
+                        id,
+                    ));
+                    self.values_indexes.updated_at.insert((
+                        result.updated_at.timestamp_nanos_opt().unwrap_or_default(),
+                        id,
+                    ));
+
+                    Ok(Some(result.into()))
+                }
             }
-        }
+        })
     }
 
     /// Tries to delete an existing record from the database using the specified key.
@@ -670,85 +1126,253 @@ impl Db {
     /// let config = Config::default();
     /// let mut db = Db::new(config);
     ///
-    /// assert_eq!(0, db.stats.read().unwrap().writes);
+    /// assert_eq!(0, db.get_stats().unwrap().writes);
     ///
     /// let key = "test_key".to_string();
     /// let value = Value::Boolean(false);
-    /// let value_post = ValuePost { key: key.clone(), ttl: None, value: value.clone() };
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value: value.clone() };
     /// let value_response = db.try_create(value_post).unwrap().unwrap();
     ///
     /// assert_eq!(value_response.key, key);
     /// assert_eq!(value_response.value, value);
-    /// assert_eq!(1, db.stats.read().unwrap().writes);
+    /// assert_eq!(1, db.get_stats().unwrap().writes);
     ///
-    /// let value_response = db.try_delete(&key).unwrap().unwrap();
+    /// let value_response = db.try_delete(&key, Some(2));
+    ///
+    /// assert!(value_response.is_err());
+    ///
+    /// let value_response = db.try_delete(&key, Some(1)).unwrap().unwrap();
     ///
     /// assert_eq!(value_response.key, key);
     /// assert_eq!(value_response.value, value);
-    /// assert_eq!(2, db.stats.read().unwrap().writes);
+    /// assert_eq!(2, db.get_stats().unwrap().writes);
     ///
     /// let value_response = db.try_read(&key).unwrap();
     ///
     /// assert!(value_response.is_none());
     ///
-    /// let value_response = db.try_delete("wrong_key");
+    /// let value_response = db.try_delete("wrong_key", None);
     ///
     /// assert!(value_response.is_err());
     /// ```
-    pub fn try_delete(&self, key: &str) -> Result<Option<ValueResponse>> {
-        let values_key_index = self.values_indexes.key.read().map_err(|_| Error::Lock)?;
-        let id = *values_key_index.get(key).ok_or(Error::NotFound)?;
-        drop(values_key_index);
+    pub fn try_delete(
+        &self,
+        key: &str,
+        expected_version: Option<u64>,
+    ) -> Result<Option<ValueResponse>> {
+        self.stats.track(Operation::Delete, || {
+            let id = self.values_indexes.get_id(key).ok_or(Error::NotFound)?;
 
-        self.try_delete_by_id(id)
+            self.try_delete_by_id(id, None, expected_version)
+        })
     }
 
-    fn try_delete_by_id(&self, id: Uuid) -> Result<Option<ValueResponse>> {
-        let mut stats = self.stats.write().map_err(|_| Error::Lock)?;
-        stats.inc_requests();
+    /// Deletes the record with the specified id. When `expired_at` is set, the record is only
+    /// deleted if its time to live passed by that moment, as it may have changed meanwhile.
+    /// Otherwise an expired record is treated as absent.
+    fn try_delete_by_id(
+        &self,
+        id: Uuid,
+        expired_at: Option<DateTime<Utc>>,
+        expected_version: Option<u64>,
+    ) -> Result<Option<ValueResponse>> {
+        let _checkpoint = self.checkpoint.read().map_err(|_| Error::Lock)?;
+        let mut values = self.values.shard(&id).write().map_err(|_| Error::Lock)?;
+
+        let (is_expired, has_version) = match values.get(&id) {
+            None => return Ok(None),
+            Some(value_record) => (
+                value_record.is_expired(expired_at.unwrap_or_else(Utc::now)),
+                value_record.has_version(expected_version),
+            ),
+        };
+
+        match (expired_at, is_expired) {
+            (None, true) => {
+                self.expire(&mut values, id)?;
+
+                return Err(Box::new(Error::NotFound));
+            }
+            (Some(_expired_at), false) => return Ok(None),
+            _ => {}
+        }
+
+        if !has_version {
+            return Err(Box::new(Error::VersionMismatch));
+        }
 
-        let mut values = self.values.write().map_err(|_| Error::Lock)?;
+        self.wal_append(&WalEntry::Delete(id))?;
         let result = values.remove(&id);
 
         match result {
             None => Ok(None),
             Some(result) => {
-                stats.inc_writes();
-
-                let mut values_created_at_index = self
-                    .values_indexes
-                    .created_at
-                    .write()
-                    .map_err(|_| Error::Lock)?;
-                values_created_at_index.remove(&result.created_at.timestamp_nanos());
-
-                if let Some(delete_at) = result.delete_at {
-                    let mut values_delete_at_index = self
-                        .values_indexes
-                        .delete_at
-                        .write()
-                        .map_err(|_| Error::Lock)?;
-                    values_delete_at_index.remove(&delete_at.timestamp_nanos());
-                }
+                self.stats.inc_writes();
 
-                let mut values_key_index =
-                    self.values_indexes.key.write().map_err(|_| Error::Lock)?;
-                values_key_index.remove(&result.key);
+                self.values_indexes.remove(&result);
 
-                let mut values_updated_at_index = self
-                    .values_indexes
-                    .updated_at
-                    .write()
-                    .map_err(|_| Error::Lock)?;
-                values_updated_at_index.remove(&result.updated_at.timestamp_nanos());
+                if expired_at.is_some() {
+                    self.stats.inc_operation(Operation::GcExpired);
+                }
 
                 Ok(Some(result.into()))
             }
         }
     }
 
+    /// Tries to delete a field of an object record in the database using the specified key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, value_record::{Value, ValuePost}};
+    /// use std::collections::BTreeMap;
+    ///
+    /// let config = Config::default();
+    /// let mut db = Db::new(config);
+    ///
+    /// let key = "test_key".to_string();
+    /// let value = Value::Object(BTreeMap::from([("name".to_string(), Value::String("test_name".to_string()))]));
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// let value_field_response = db.try_delete_field(&key, "name", None).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_field_response.value, Value::String("test_name".to_string()));
+    /// assert_eq!(value_field_response.version, 2);
+    /// assert_eq!(Value::Object(BTreeMap::new()), db.try_read(&key).unwrap().unwrap().value);
+    ///
+    /// assert!(db.try_delete_field(&key, "name", None).is_err());
+    /// assert!(db.try_delete_field("wrong_key", "name", None).is_err());
+    /// ```
+    pub fn try_delete_field(
+        &self,
+        key: &str,
+        field: &str,
+        expected_version: Option<u64>,
+    ) -> Result<Option<ValueFieldResponse>> {
+        self.stats.track(Operation::Field, || {
+            self.modify_field(key, field, expected_version, |value| match value {
+                Value::Object(mut fields) => {
+                    let field_value = fields.remove(field).ok_or(Error::NotFound)?;
+
+                    Ok(Some((Value::Object(fields), field_value)))
+                }
+                _ => Ok(None),
+            })
+        })
+    }
+
+    /// Tries to delete records from the database in a single batch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{batch::BatchStatus, config::Config, db::Db, value_record::{Value, ValuePost}};
+    ///
+    /// let config = Config::default();
+    /// let mut db = Db::new(config);
+    ///
+    /// let value_post = ValuePost { expire_at: None, key: "test_key".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(10) };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// let keys = vec!["test_key".to_string(), "wrong_key".to_string()];
+    /// let results = db.try_delete_many(&keys).unwrap();
+    ///
+    /// assert_eq!(BatchStatus::Deleted, results[0].status);
+    /// assert_eq!(Some(Value::Integer(10)), results[0].value.as_ref().map(|value| value.value.clone()));
+    /// assert_eq!(BatchStatus::NotFound, results[1].status);
+    /// assert!(db.try_read("test_key").unwrap().is_none());
+    /// assert_eq!(1, db.get_stats().unwrap().operations.batch_delete);
+    /// ```
+    pub fn try_delete_many(&self, keys: &[String]) -> Result<Vec<BatchResult>> {
+        self.stats.track(Operation::BatchDelete, || {
+            let _checkpoint = self.checkpoint.read().map_err(|_| Error::Lock)?;
+            let mut values = self.lock_keys(keys.iter().map(String::as_str))?;
+            let now = Utc::now();
+
+            let mut staged = HashMap::new();
+            let mut results = vec![];
+
+            for key in keys {
+                let result = match self.get_staged(&values, &staged, key, now) {
+                    None => {
+                        self.stats.inc_error(&Error::NotFound);
+
+                        BatchResult::new(key, BatchStatus::NotFound, None)
+                    }
+                    Some(original_value) => {
+                        staged.insert(key.clone(), None);
+
+                        BatchResult::new(key, BatchStatus::Deleted, Some(original_value))
+                    }
+                };
+                results.append(&mut vec![result]);
+            }
+
+            self.commit(&mut values, staged)?;
+
+            Ok(results)
+        })
+    }
+
+    /// Tries to set the expiration of an existing record in the database using the specified key.
+    ///
+    /// The expiration is either relative to now or absolute, the value and the update time stay intact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, value_record::{Value, ValueExpire, ValuePost}};
+    /// use chrono::{Duration, Utc};
+    ///
+    /// let config = Config::default();
+    /// let mut db = Db::new(config);
+    ///
+    /// let key = "test_key".to_string();
+    /// let value = Value::Integer(10);
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value: value.clone() };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// let value_expire = ValueExpire { expected_version: None, expire_at: None, ttl: Some(100), ttl_ms: None };
+    /// let value_ttl_response = db.try_expire(&key, value_expire).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_ttl_response.key, key);
+    /// assert!(value_ttl_response.ttl.unwrap() > 90);
+    /// assert_eq!(1, db.values_indexes.delete_at.len());
+    /// assert_eq!(2, db.get_stats().unwrap().writes);
+    ///
+    /// let expire_at = Utc::now() + Duration::seconds(1000);
+    /// let value_expire = ValueExpire { expected_version: None, expire_at: Some(expire_at), ttl: None, ttl_ms: None };
+    /// let value_ttl_response = db.try_expire(&key, value_expire.clone()).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_ttl_response.expire_at, Some(expire_at));
+    /// assert_eq!(1, db.values_indexes.delete_at.len());
+    /// assert_eq!(value, db.try_read(&key).unwrap().unwrap().value);
+    ///
+    /// let value_response = db.try_expire("wrong_key", value_expire);
+    ///
+    /// assert!(value_response.is_err());
+    /// ```
+    pub fn try_expire(
+        &self,
+        key: &str,
+        value_expire: ValueExpire,
+    ) -> Result<Option<ValueTtlResponse>> {
+        self.stats.track(Operation::Expire, || {
+            let delete_at = value_expire
+                .delete_at(Utc::now())?
+                .ok_or(Error::ExpiryMissing)?;
+
+            self.set_delete_at(key, Some(delete_at), value_expire.expected_version)
+        })
+    }
+
     /// Tries to increment a value of an existing record in the database using the specified key.
     ///
+    /// With `create`, a missing record is created from zero. The `ttl` only applies to a created
+    /// record, the expiration of an existing one stays intact.
+    ///
     /// # Examples
     ///
     /// ```
@@ -758,91 +1382,254 @@ impl Db {
     /// let config = Config::default();
     /// let mut db = Db::new(config);
     ///
-    /// assert_eq!(0, db.stats.read().unwrap().writes);
+    /// assert_eq!(0, db.get_stats().unwrap().writes);
     ///
     /// let key = "test_key".to_string();
     /// let value = Value::Integer(1000);
-    /// let value_post = ValuePost { key: key.clone(), ttl: None, value: value.clone() };
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value: value.clone() };
     /// let value_response = db.try_create(value_post).unwrap().unwrap();
     ///
     /// assert_eq!(value_response.key, key);
     /// assert_eq!(value_response.value, value);
-    /// assert_eq!(1, db.stats.read().unwrap().writes);
+    /// assert_eq!(1, db.get_stats().unwrap().writes);
     ///
-    /// let value_increment = ValueIncrement { increment: None };
+    /// let value_increment = ValueIncrement { create: false, expected_version: None, increment: None, ttl: None };
     /// let value_response = db.try_increment(&key, value_increment).unwrap().unwrap();
     ///
     /// assert_eq!(value_response.key, key);
     /// assert_eq!(value_response.value, Value::Integer(1001));
-    /// assert_eq!(2, db.stats.read().unwrap().writes);
+    /// assert_eq!(2, db.get_stats().unwrap().writes);
     ///
-    /// let value_increment = ValueIncrement { increment: Some(10) };
+    /// let value_increment = ValueIncrement { create: false, expected_version: None, increment: Some(10), ttl: None };
     /// let value_response = db.try_increment(&key, value_increment.clone()).unwrap().unwrap();
     ///
     /// assert_eq!(value_response.key, key);
     /// assert_eq!(value_response.value, Value::Integer(1011));
-    /// assert_eq!(3, db.stats.read().unwrap().writes);
+    /// assert_eq!(3, db.get_stats().unwrap().writes);
     ///
-    /// let value_response = db.try_increment("wrong_key", value_increment);
+    /// let value_response = db.try_increment("wrong_key", value_increment.clone());
     ///
     /// assert!(value_response.is_err());
+    ///
+    /// let value_increment = ValueIncrement { create: true, ttl: Some(100), ..value_increment };
+    /// let value_response = db.try_increment("new_key", value_increment.clone()).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_response.value, Value::Integer(10));
+    /// assert_eq!(value_response.version, 1);
+    ///
+    /// let value_increment = ValueIncrement { ttl: None, ..value_increment };
+    /// let value_response = db.try_increment("new_key", value_increment).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_response.value, Value::Integer(20));
+    /// assert!(db.try_read_ttl("new_key").unwrap().unwrap().ttl.is_some());
+    ///
+    /// let value_increment = ValueIncrement { create: true, expected_version: Some(1), increment: None, ttl: None };
+    ///
+    /// assert!(db.try_increment("other_key", value_increment).is_err());
     /// ```
     pub fn try_increment(
         &self,
         key: &str,
         value_increment: ValueIncrement,
     ) -> Result<Option<ValueResponse>> {
-        let mut stats = self.stats.write().map_err(|_| Error::Lock)?;
-        stats.inc_requests();
-
-        let values_key_index = self.values_indexes.key.write().map_err(|_| Error::Lock)?;
-        let id = *values_key_index.get(key).ok_or(Error::NotFound)?;
-
-        let mut values = self.values.write().map_err(|_| Error::Lock)?;
-        let original_value = values.get(&id).ok_or(Error::NotFound)?.clone();
-
-        let value = match original_value.value {
-            Value::Integer(original_value_integer) => match value_increment.increment {
-                None => Value::Integer(original_value_integer.saturating_add(1)),
-                Some(increment) => {
-                    if let Some(abs) = increment.checked_abs() {
-                        Value::Integer(original_value_integer.saturating_add(abs))
-                    } else {
-                        Value::Integer(original_value_integer)
-                    }
+        self.stats.track(Operation::Increment, || {
+            if let Some((value, delete_at)) = value_increment.initial(Utc::now())? {
+                let value = match value_increment.apply(value) {
+                    None => return Ok(None),
+                    Some(value) => value,
+                };
+
+                if let Some(result) = self.create_missing(key, &value, delete_at)? {
+                    return Ok(Some(result));
                 }
-            },
-            _ => return Ok(None),
-        };
+            }
 
-        let now = Utc::now();
-        let value_record = ValueRecord::new(
-            id,
-            &original_value.key,
-            &value,
-            original_value.created_at,
-            original_value.delete_at,
-            now,
-        );
-        values.insert(id, value_record);
-        let result = values.get(&id).cloned();
+            let _checkpoint = self.checkpoint.read().map_err(|_| Error::Lock)?;
+            let id = self.values_indexes.get_id(key).ok_or(Error::NotFound)?;
 
-        match result {
-            None => Ok(None),
-            Some(result) => {
-                stats.inc_writes();
+            let mut values = self.values.shard(&id).write().map_err(|_| Error::Lock)?;
+            let original_value = self
+                .get_unexpired(&mut values, id)?
+                .ok_or(Error::NotFound)?;
 
-                let mut values_updated_at_index = self
-                    .values_indexes
-                    .updated_at
-                    .write()
-                    .map_err(|_| Error::Lock)?;
-                values_updated_at_index.remove(&original_value.updated_at.timestamp_nanos());
-                values_updated_at_index.insert(result.updated_at.timestamp_nanos(), id);
+            if !original_value.has_version(value_increment.expected_version) {
+                return Err(Box::new(Error::VersionMismatch));
+            }
 
-                Ok(Some(result.into()))
+            let value = match value_increment.apply(original_value.value) {
+                None => return Ok(None),
+                Some(value) => value,
+            };
+
+            let now = Utc::now();
+            let value_record = ValueRecord::new(
+                id,
+                &original_value.key,
+                &value,
+                original_value.created_at,
+                original_value.delete_at,
+                now,
+                original_value.version + 1,
+            );
+            self.wal_append(&WalEntry::Upsert(value_record.clone()))?;
+            values.insert(id, value_record);
+            let result = values.get(&id).cloned();
+
+            match result {
+                None => Ok(None),
+                Some(result) => {
+                    self.stats.inc_writes();
+
+                    self.values_indexes.updated_at.remove(&(
+                        original_value
+                            .updated_at
+                            .timestamp_nanos_opt()
+                            .unwrap_or_default(),
+                        id,
+                    ));
+                    self.values_indexes.updated_at.insert((
+                        result.updated_at.timestamp_nanos_opt().unwrap_or_default(),
+                        id,
+                    ));
+
+                    Ok(Some(result.into()))
+                }
             }
-        }
+        })
+    }
+
+    /// Tries to increment an integer field of an object record in the database using the specified key.
+    ///
+    /// A missing field is added, starting from zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, value_record::{Value, ValueFieldIncrement, ValuePost}};
+    /// use std::collections::BTreeMap;
+    ///
+    /// let config = Config::default();
+    /// let mut db = Db::new(config);
+    ///
+    /// let key = "test_key".to_string();
+    /// let value = Value::Object(BTreeMap::from([("visits".to_string(), Value::Integer(10)), ("name".to_string(), Value::String("test_name".to_string()))]));
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// let value_field_increment = ValueFieldIncrement { expected_version: None, increment: None };
+    /// let value_field_response = db.try_increment_field(&key, "visits", value_field_increment).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_field_response.value, Value::Integer(11));
+    /// assert_eq!(value_field_response.version, 2);
+    ///
+    /// let value_field_increment = ValueFieldIncrement { expected_version: None, increment: Some(-5) };
+    /// let value_field_response = db.try_increment_field(&key, "likes", value_field_increment.clone()).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_field_response.value, Value::Integer(-5));
+    ///
+    /// let value_field_response = db.try_increment_field(&key, "name", value_field_increment.clone()).unwrap();
+    ///
+    /// assert!(value_field_response.is_none());
+    /// assert!(db.try_increment_field("wrong_key", "visits", value_field_increment).is_err());
+    /// assert_eq!(4, db.get_stats().unwrap().operations.field);
+    /// ```
+    pub fn try_increment_field(
+        &self,
+        key: &str,
+        field: &str,
+        value_field_increment: ValueFieldIncrement,
+    ) -> Result<Option<ValueFieldResponse>> {
+        self.stats.track(Operation::Field, || {
+            self.modify_field(
+                key,
+                field,
+                value_field_increment.expected_version,
+                |value| Ok(value_field_increment.apply(field, value)),
+            )
+        })
+    }
+
+    /// Tries to list the fields of an object record in the database using the specified key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, value_record::{Value, ValuePost}};
+    /// use std::collections::BTreeMap;
+    ///
+    /// let config = Config::default();
+    /// let mut db = Db::new(config);
+    ///
+    /// let key = "test_key".to_string();
+    /// let value = Value::Object(BTreeMap::from([("name".to_string(), Value::String("test_name".to_string())), ("age".to_string(), Value::Integer(30))]));
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// let value_fields_response = db.try_list_fields(&key).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_fields_response.fields, vec!["age".to_string(), "name".to_string()]);
+    /// assert_eq!(value_fields_response.version, 1);
+    ///
+    /// let value_post = ValuePost { expire_at: None, key: "other_key".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(10) };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// assert!(db.try_list_fields("other_key").is_err());
+    /// assert!(db.try_list_fields("wrong_key").unwrap().is_none());
+    /// ```
+    pub fn try_list_fields(&self, key: &str) -> Result<Option<ValueFieldsResponse>> {
+        self.stats.track(Operation::Field, || {
+            let result = match self.read_record(key)? {
+                None => return Ok(None),
+                Some(result) => result,
+            };
+
+            match result.value {
+                Value::Object(fields) => Ok(Some(ValueFieldsResponse {
+                    fields: fields.into_keys().collect(),
+                    key: result.key,
+                    version: result.version,
+                })),
+                _ => Err(Box::new(Error::ValueType)),
+            }
+        })
+    }
+
+    /// Tries to remove the expiration of an existing record in the database using the specified key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, value_record::{Value, ValuePost}};
+    ///
+    /// let config = Config::default();
+    /// let mut db = Db::new(config);
+    ///
+    /// let key = "test_key".to_string();
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: Some(100), ttl_ms: None, value: Value::Integer(10) };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// assert_eq!(1, db.values_indexes.delete_at.len());
+    ///
+    /// let value_ttl_response = db.try_persist(&key, None).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_ttl_response.key, key);
+    /// assert_eq!(value_ttl_response.expire_at, None);
+    /// assert_eq!(value_ttl_response.ttl, None);
+    /// assert_eq!(0, db.values_indexes.delete_at.len());
+    /// assert_eq!(2, db.get_stats().unwrap().writes);
+    ///
+    /// let value_response = db.try_persist("wrong_key", None);
+    ///
+    /// assert!(value_response.is_err());
+    /// ```
+    pub fn try_persist(
+        &self,
+        key: &str,
+        expected_version: Option<u64>,
+    ) -> Result<Option<ValueTtlResponse>> {
+        self.stats.track(Operation::Persist, || {
+            self.set_delete_at(key, None, expected_version)
+        })
     }
 
     /// Tries to pop a value from the back of an existing record in the database using the specified key.
@@ -856,7 +1643,7 @@ impl Db {
     /// let config = Config::default();
     /// let mut db = Db::new(config);
     ///
-    /// assert_eq!(0, db.stats.read().unwrap().writes);
+    /// assert_eq!(0, db.get_stats().unwrap().writes);
     ///
     /// let key = "test_key".to_string();
     /// let value1 = Value::String("test_value1".to_string());
@@ -864,24 +1651,24 @@ impl Db {
     /// let value3 = Value::Integer(100);
     /// let value4 = Value::Integer(1000);
     /// let value_array = Value::Array(VecDeque::from([value1.clone(), value2.clone(), value3.clone(), value4.clone()]));
-    /// let value_post = ValuePost { key: key.clone(), ttl: None, value: value_array.clone() };
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value: value_array.clone() };
     /// let value_response = db.try_create(value_post).unwrap().unwrap();
     ///
     /// assert_eq!(value_response.key, key);
     /// assert_eq!(value_response.value, value_array);
-    /// assert_eq!(1, db.stats.read().unwrap().writes);
+    /// assert_eq!(1, db.get_stats().unwrap().writes);
     ///
-    /// let value_pop_back = ValuePopBack { pop_back: None };
+    /// let value_pop_back = ValuePopBack { expected_version: None, pop_back: None };
     /// let value_response = db.try_pop_back(&key, value_pop_back).unwrap().unwrap();
     ///
     /// assert_eq!(value_response, vec![value4]);
-    /// assert_eq!(2, db.stats.read().unwrap().writes);
+    /// assert_eq!(2, db.get_stats().unwrap().writes);
     ///
-    /// let value_pop_back = ValuePopBack { pop_back: Some(2) };
+    /// let value_pop_back = ValuePopBack { expected_version: None, pop_back: Some(2) };
     /// let value_response = db.try_pop_back(&key, value_pop_back.clone()).unwrap().unwrap();
     ///
     /// assert_eq!(value_response, vec![value3, value2]);
-    /// assert_eq!(3, db.stats.read().unwrap().writes);
+    /// assert_eq!(3, db.get_stats().unwrap().writes);
     ///
     /// let value_response = db.try_pop_back("wrong_key", value_pop_back);
     ///
@@ -892,75 +1679,59 @@ impl Db {
         key: &str,
         value_pop_back: ValuePopBack,
     ) -> Result<Option<Vec<Value>>> {
-        let mut stats = self.stats.write().map_err(|_| Error::Lock)?;
-        stats.inc_requests();
-
-        let values_key_index = self.values_indexes.key.write().map_err(|_| Error::Lock)?;
-        let id = *values_key_index.get(key).ok_or(Error::NotFound)?;
+        self.stats.track(Operation::Pop, || {
+            let _checkpoint = self.checkpoint.read().map_err(|_| Error::Lock)?;
+            let id = self.values_indexes.get_id(key).ok_or(Error::NotFound)?;
 
-        let mut values = self.values.write().map_err(|_| Error::Lock)?;
-        let original_value = values.get(&id).ok_or(Error::NotFound)?.clone();
+            let mut values = self.values.shard(&id).write().map_err(|_| Error::Lock)?;
+            let original_value = self
+                .get_unexpired(&mut values, id)?
+                .ok_or(Error::NotFound)?;
 
-        let mut return_values = vec![];
-        let value = match original_value.value {
-            Value::Array(original_value_vec) => match value_pop_back.pop_back {
-                None => {
-                    let mut new_value = original_value_vec;
-                    let pop_value = new_value.pop_back();
-                    if let Some(pop_value) = pop_value {
-                        return_values.append(&mut vec![pop_value]);
-                    }
-
-                    Value::Array(new_value)
-                }
-                Some(mut pop_back) => {
-                    if pop_back > original_value_vec.len() {
-                        pop_back = original_value_vec.len();
-                    }
-
-                    let mut new_value = original_value_vec;
-
-                    for _i in 1..=pop_back {
-                        let pop_value = new_value.pop_back();
-                        if let Some(pop_value) = pop_value {
-                            return_values.append(&mut vec![pop_value]);
-                        }
-                    }
+            if !original_value.has_version(value_pop_back.expected_version) {
+                return Err(Box::new(Error::VersionMismatch));
+            }
 
-                    Value::Array(new_value)
+            let (value, return_values) = match value_pop_back.apply(original_value.value) {
+                None => return Ok(None),
+                Some(result) => result,
+            };
+
+            let now = Utc::now();
+            let value_record = ValueRecord::new(
+                id,
+                &original_value.key,
+                &value,
+                original_value.created_at,
+                original_value.delete_at,
+                now,
+                original_value.version + 1,
+            );
+            self.wal_append(&WalEntry::Upsert(value_record.clone()))?;
+            values.insert(id, value_record);
+            let result = values.get(&id).cloned();
+
+            match result {
+                None => Ok(None),
+                Some(result) => {
+                    self.stats.inc_writes();
+
+                    self.values_indexes.updated_at.remove(&(
+                        original_value
+                            .updated_at
+                            .timestamp_nanos_opt()
+                            .unwrap_or_default(),
+                        id,
+                    ));
+                    self.values_indexes.updated_at.insert((
+                        result.updated_at.timestamp_nanos_opt().unwrap_or_default(),
+                        id,
+                    ));
+
+                    Ok(Some(return_values))
                 }
-            },
-            _ => return Ok(None),
-        };
-
-        let now = Utc::now();
-        let value_record = ValueRecord::new(
-            id,
-            &original_value.key,
-            &value,
-            original_value.created_at,
-            original_value.delete_at,
-            now,
-        );
-        values.insert(id, value_record);
-        let result = values.get(&id).cloned();
-
-        match result {
-            None => Ok(None),
-            Some(result) => {
-                stats.inc_writes();
-
-                let mut values_updated_at_index = self
-                    .values_indexes
-                    .updated_at
-                    .write()
-                    .map_err(|_| Error::Lock)?;
-                values_updated_at_index.remove(&original_value.updated_at.timestamp_nanos());
-                values_updated_at_index.insert(result.updated_at.timestamp_nanos(), id);
-
-                Ok(Some(return_values))
             }
-        }
+        })
     }
 
     /// Tries to pop a value from the front of an existing record in the database using the specified key.
@@ -974,7 +1745,7 @@ impl Db {
     /// let config = Config::default();
     /// let mut db = Db::new(config);
     ///
-    /// assert_eq!(0, db.stats.read().unwrap().writes);
+    /// assert_eq!(0, db.get_stats().unwrap().writes);
     ///
     /// let key = "test_key".to_string();
     /// let value1 = Value::String("test_value1".to_string());
@@ -982,24 +1753,24 @@ impl Db {
     /// let value3 = Value::Integer(100);
     /// let value4 = Value::Integer(1000);
     /// let value_array = Value::Array(VecDeque::from([value1.clone(), value2.clone(), value3.clone(), value4.clone()]));
-    /// let value_post = ValuePost { key: key.clone(), ttl: None, value: value_array.clone() };
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value: value_array.clone() };
     /// let value_response = db.try_create(value_post).unwrap().unwrap();
     ///
     /// assert_eq!(value_response.key, key);
     /// assert_eq!(value_response.value, value_array);
-    /// assert_eq!(1, db.stats.read().unwrap().writes);
+    /// assert_eq!(1, db.get_stats().unwrap().writes);
     ///
-    /// let value_pop_front = ValuePopFront { pop_front: None };
+    /// let value_pop_front = ValuePopFront { expected_version: None, pop_front: None };
     /// let value_response = db.try_pop_front(&key, value_pop_front).unwrap().unwrap();
     ///
     /// assert_eq!(value_response, vec![value1]);
-    /// assert_eq!(2, db.stats.read().unwrap().writes);
+    /// assert_eq!(2, db.get_stats().unwrap().writes);
     ///
-    /// let value_pop_front = ValuePopFront { pop_front: Some(2) };
+    /// let value_pop_front = ValuePopFront { expected_version: None, pop_front: Some(2) };
     /// let value_response = db.try_pop_front(&key, value_pop_front.clone()).unwrap().unwrap();
     ///
     /// assert_eq!(value_response, vec![value2, value3]);
-    /// assert_eq!(3, db.stats.read().unwrap().writes);
+    /// assert_eq!(3, db.get_stats().unwrap().writes);
     ///
     /// let value_response = db.try_pop_front("wrong_key", value_pop_front);
     ///
@@ -1010,78 +1781,65 @@ impl Db {
         key: &str,
         value_pop_front: ValuePopFront,
     ) -> Result<Option<Vec<Value>>> {
-        let mut stats = self.stats.write().map_err(|_| Error::Lock)?;
-        stats.inc_requests();
+        self.stats.track(Operation::Pop, || {
+            let _checkpoint = self.checkpoint.read().map_err(|_| Error::Lock)?;
+            let id = self.values_indexes.get_id(key).ok_or(Error::NotFound)?;
 
-        let values_key_index = self.values_indexes.key.write().map_err(|_| Error::Lock)?;
-        let id = *values_key_index.get(key).ok_or(Error::NotFound)?;
+            let mut values = self.values.shard(&id).write().map_err(|_| Error::Lock)?;
+            let original_value = self
+                .get_unexpired(&mut values, id)?
+                .ok_or(Error::NotFound)?;
 
-        let mut values = self.values.write().map_err(|_| Error::Lock)?;
-        let original_value = values.get(&id).ok_or(Error::NotFound)?.clone();
+            if !original_value.has_version(value_pop_front.expected_version) {
+                return Err(Box::new(Error::VersionMismatch));
+            }
 
-        let mut return_values = vec![];
-        let value = match original_value.value {
-            Value::Array(original_value_vec) => match value_pop_front.pop_front {
-                None => {
-                    let mut new_value = original_value_vec;
-                    let pop_value = new_value.pop_front();
-                    if let Some(pop_value) = pop_value {
-                        return_values.append(&mut vec![pop_value]);
-                    }
-
-                    Value::Array(new_value)
-                }
-                Some(mut pop_front) => {
-                    if pop_front > original_value_vec.len() {
-                        pop_front = original_value_vec.len();
-                    }
-                    let mut new_value = original_value_vec;
-
-                    for _i in 1..=pop_front {
-                        let pop_value = new_value.pop_front();
-                        if let Some(pop_value) = pop_value {
-                            return_values.append(&mut vec![pop_value]);
-                        }
-                    }
-
-                    Value::Array(new_value)
+            let (value, return_values) = match value_pop_front.apply(original_value.value) {
+                None => return Ok(None),
+                Some(result) => result,
+            };
+
+            let now = Utc::now();
+            let value_record = ValueRecord::new(
+                id,
+                &original_value.key,
+                &value,
+                original_value.created_at,
+                original_value.delete_at,
+                now,
+                original_value.version + 1,
+            );
+            self.wal_append(&WalEntry::Upsert(value_record.clone()))?;
+            values.insert(id, value_record);
+            let result = values.get(&id).cloned();
+
+            match result {
+                None => Ok(None),
+                Some(result) => {
+                    self.stats.inc_writes();
+
+                    self.values_indexes.updated_at.remove(&(
+                        original_value
+                            .updated_at
+                            .timestamp_nanos_opt()
+                            .unwrap_or_default(),
+                        id,
+                    ));
+                    self.values_indexes.updated_at.insert((
+                        result.updated_at.timestamp_nanos_opt().unwrap_or_default(),
+                        id,
+                    ));
+
+                    Ok(Some(return_values))
                 }
-            },
-            _ => return Ok(None),
-        };
-
-        let now = Utc::now();
-        let value_record = ValueRecord::new(
-            id,
-            &original_value.key,
-            &value,
-            original_value.created_at,
-            original_value.delete_at,
-            now,
-        );
-        values.insert(id, value_record);
-        let result = values.get(&id).cloned();
-
-        match result {
-            None => Ok(None),
-            Some(result) => {
-                stats.inc_writes();
-
-                let mut values_updated_at_index = self
-                    .values_indexes
-                    .updated_at
-                    .write()
-                    .map_err(|_| Error::Lock)?;
-                values_updated_at_index.remove(&original_value.updated_at.timestamp_nanos());
-                values_updated_at_index.insert(result.updated_at.timestamp_nanos(), id);
-
-                Ok(Some(return_values))
             }
-        }
+        })
     }
 
     /// Tries to prepend a value to an existing record in the database using the specified key.
     ///
+    /// With `create`, a missing record is created from an empty array, expiring after `ttl`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -1091,86 +1849,106 @@ impl Db {
     /// let config = Config::default();
     /// let mut db = Db::new(config);
     ///
-    /// assert_eq!(0, db.stats.read().unwrap().writes);
+    /// assert_eq!(0, db.get_stats().unwrap().writes);
     ///
     /// let key = "test_key".to_string();
     /// let value1 = Value::String("test_value".to_string());
     /// let value1_array = Value::Array(VecDeque::from([value1.clone()]));
-    /// let value_post = ValuePost { key: key.clone(), ttl: None, value: value1_array.clone() };
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value: value1_array.clone() };
     /// let value_response = db.try_create(value_post).unwrap().unwrap();
     ///
     /// assert_eq!(value_response.key, key);
     /// assert_eq!(value_response.value, value1_array);
-    /// assert_eq!(1, db.stats.read().unwrap().writes);
+    /// assert_eq!(1, db.get_stats().unwrap().writes);
     ///
     /// let value2 = Value::Integer(100);
     /// let value2_array = Value::Array(VecDeque::from([value2.clone()]));
-    /// let value_prepend = ValuePrepend { prepend: value2_array.clone() };
+    /// let value_prepend = ValuePrepend { create: false, expected_version: None, prepend: value2_array.clone(), ttl: None };
     /// let value_response = db.try_prepend(&key, value_prepend.clone()).unwrap().unwrap();
     ///
     /// assert_eq!(value_response.key, key);
     /// assert_eq!(value_response.value, Value::Array(VecDeque::from([value2, value1])));
-    /// assert_eq!(2, db.stats.read().unwrap().writes);
+    /// assert_eq!(2, db.get_stats().unwrap().writes);
     ///
-    /// let value_response = db.try_prepend("wrong_key", value_prepend);
+    /// let value_response = db.try_prepend("wrong_key", value_prepend.clone());
     ///
     /// assert!(value_response.is_err());
+    ///
+    /// let value_prepend = ValuePrepend { create: true, ..value_prepend };
+    /// let value_response = db.try_prepend("new_key", value_prepend).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_response.value, value2_array);
+    /// assert_eq!(value_response.version, 1);
     /// ```
     pub fn try_prepend(
         &self,
         key: &str,
         value_prepend: ValuePrepend,
     ) -> Result<Option<ValueResponse>> {
-        let mut stats = self.stats.write().map_err(|_| Error::Lock)?;
-        stats.inc_requests();
-
-        let values_key_index = self.values_indexes.key.write().map_err(|_| Error::Lock)?;
-        let id = *values_key_index.get(key).ok_or(Error::NotFound)?;
-
-        let mut values = self.values.write().map_err(|_| Error::Lock)?;
-        let original_value = values.get(&id).ok_or(Error::NotFound)?.clone();
-
-        let value = match (original_value.value, value_prepend.prepend) {
-            (Value::Array(original_value_vec), Value::Array(value_prepend_vec)) => {
-                let mut new_value = original_value_vec;
-
-                for value_prepend_item in value_prepend_vec {
-                    new_value.push_front(value_prepend_item);
+        self.stats.track(Operation::Prepend, || {
+            if let Some((value, delete_at)) = value_prepend.initial(Utc::now())? {
+                let value = match value_prepend.clone().apply(value) {
+                    None => return Ok(None),
+                    Some(value) => value,
+                };
+
+                if let Some(result) = self.create_missing(key, &value, delete_at)? {
+                    return Ok(Some(result));
                 }
-
-                Value::Array(new_value)
             }
-            _ => return Ok(None),
-        };
 
-        let now = Utc::now();
-        let value_record = ValueRecord::new(
-            id,
-            &original_value.key,
-            &value,
-            original_value.created_at,
-            original_value.delete_at,
-            now,
-        );
-        values.insert(id, value_record);
-        let result = values.get(&id).cloned();
+            let _checkpoint = self.checkpoint.read().map_err(|_| Error::Lock)?;
+            let id = self.values_indexes.get_id(key).ok_or(Error::NotFound)?;
 
-        match result {
-            None => Ok(None),
-            Some(result) => {
-                stats.inc_writes();
+            let mut values = self.values.shard(&id).write().map_err(|_| Error::Lock)?;
+            let original_value = self
+                .get_unexpired(&mut values, id)?
+                .ok_or(Error::NotFound)?;
 
-                let mut values_updated_at_index = self
-                    .values_indexes
-                    .updated_at
-                    .write()
-                    .map_err(|_| Error::Lock)?;
-                values_updated_at_index.remove(&original_value.updated_at.timestamp_nanos());
-                values_updated_at_index.insert(result.updated_at.timestamp_nanos(), id);
+            if !original_value.has_version(value_prepend.expected_version) {
+                return Err(Box::new(Error::VersionMismatch));
+            }
 
-                Ok(Some(result.into()))
+            let value = match value_prepend.apply(original_value.value) {
+                None => return Ok(None),
+                Some(value) => value,
+            };
+
+            let now = Utc::now();
+            let value_record = ValueRecord::new(
+                id,
+                &original_value.key,
+                &value,
+                original_value.created_at,
+                original_value.delete_at,
+                now,
+                original_value.version + 1,
+            );
+            self.wal_append(&WalEntry::Upsert(value_record.clone()))?;
+            values.insert(id, value_record);
+            let result = values.get(&id).cloned();
+
+            match result {
+                None => Ok(None),
+                Some(result) => {
+                    self.stats.inc_writes();
+
+                    self.values_indexes.updated_at.remove(&(
+                        original_value
+                            .updated_at
+                            .timestamp_nanos_opt()
+                            .unwrap_or_default(),
+                        id,
+                    ));
+                    self.values_indexes.updated_at.insert((
+                        result.updated_at.timestamp_nanos_opt().unwrap_or_default(),
+                        id,
+                    ));
+
+                    Ok(Some(result.into()))
+                }
             }
-        }
+        })
     }
 
     /// Tries to read a record from the database using the specified key.
@@ -1183,45 +1961,436 @@ impl Db {
     /// let config = Config::default();
     /// let mut db = Db::new(config);
     ///
-    /// assert_eq!(0, db.stats.read().unwrap().reads);
+    /// assert_eq!(0, db.get_stats().unwrap().reads);
     ///
     /// let key = "test_key".to_string();
     /// let value = Value::Integer(10);
-    /// let value_post = ValuePost { key: key.clone(), ttl: None, value: value.clone() };
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value: value.clone() };
     /// db.try_create(value_post);
     /// let value_response = db.try_read(&key).unwrap().unwrap();
     ///
     /// assert_eq!(value_response.key, key);
     /// assert_eq!(value_response.value, value);
-    /// assert_eq!(1, db.stats.read().unwrap().reads);
+    /// assert_eq!(1, db.get_stats().unwrap().reads);
+    ///
+    /// let value_response = db.try_read("wrong_key").unwrap();
+    ///
+    /// assert!(value_response.is_none());
+    /// assert_eq!(2, db.get_stats().unwrap().operations.read);
+    /// assert_eq!(1, db.get_stats().unwrap().errors.not_found);
+    /// ```
+    ///
+    /// A record whose time to live has passed is treated as absent and removed on access,
+    /// without waiting for the garbage collector.
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, value_index::ValueIndex, value_record::{Value, ValuePost, ValueRecord}, value_shards::ValueShards};
+    /// use chrono::{Duration, Utc};
+    /// use std::collections::HashMap;
+    /// use uuid::Uuid;
+    ///
+    /// let mut db = Db::new(Config::default());
+    /// let now = Utc::now();
+    /// let id = Uuid::new_v4();
+    /// let value_record = ValueRecord::new(id, "test_key", &Value::Integer(10), now, Some(now - Duration::seconds(1)), now, 1);
+    /// let values = HashMap::from([(id, value_record)]);
+    /// db.values_indexes = ValueIndex::from_values(&values);
+    /// db.values = ValueShards::from_values(values);
+    ///
+    /// assert!(db.try_read("test_key").unwrap().is_none());
+    /// assert_eq!(0, db.values.len().unwrap());
+    /// assert_eq!(0, db.values_indexes.key.len());
+    /// assert_eq!(0, db.values_indexes.delete_at.len());
+    ///
+    /// let value_post = ValuePost { expire_at: None, key: "test_key".to_string(), ttl: Some(0), ttl_ms: None, value: Value::Integer(20) };
+    /// db.try_create(value_post.clone()).unwrap();
+    ///
+    /// assert!(db.try_read("test_key").unwrap().is_none());
+    /// assert!(db.try_create(value_post).is_ok());
     /// ```
     pub fn try_read(&self, key: &str) -> Result<Option<ValueResponse>> {
-        let mut stats = self.stats.write().map_err(|_| Error::Lock)?;
-        stats.inc_requests();
+        self.stats.track(Operation::Read, || {
+            let id = self.values_indexes.get_id(key);
 
-        let values_key_index = self.values_indexes.key.read().map_err(|_| Error::Lock)?;
-        let id = values_key_index.get(key);
+            match id {
+                None => {
+                    self.stats.inc_error(&Error::NotFound);
 
-        match id {
-            None => Ok(None),
-            Some(id) => {
-                let values = self.values.read().map_err(|_| Error::Lock)?;
-                let result = values.get(id).cloned();
+                    Ok(None)
+                }
+                Some(id) => {
+                    let now = Utc::now();
+                    let result = match self.values.get(&id)? {
+                        Some(result) if result.is_expired(now) => {
+                            self.try_delete_by_id(id, Some(now), None)?;
+
+                            None
+                        }
+                        result => result,
+                    };
+
+                    match result {
+                        None => {
+                            self.stats.inc_error(&Error::NotFound);
 
-                match result {
-                    None => Ok(None),
-                    Some(result) => {
-                        stats.inc_reads();
+                            Ok(None)
+                        }
+                        Some(result) => {
+                            self.stats.inc_reads();
 
-                        Ok(Some(result.into()))
+                            Ok(Some(result.into()))
+                        }
                     }
                 }
             }
-        }
+        })
+    }
+
+    /// Tries to read a field of an object record in the database using the specified key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, value_record::{Value, ValuePost}};
+    /// use std::collections::BTreeMap;
+    ///
+    /// let config = Config::default();
+    /// let mut db = Db::new(config);
+    ///
+    /// let key = "test_key".to_string();
+    /// let value = Value::Object(BTreeMap::from([("name".to_string(), Value::String("test_name".to_string()))]));
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// let value_field_response = db.try_read_field(&key, "name").unwrap().unwrap();
+    ///
+    /// assert_eq!(value_field_response.field, "name");
+    /// assert_eq!(value_field_response.value, Value::String("test_name".to_string()));
+    ///
+    /// assert!(db.try_read_field(&key, "wrong_field").unwrap().is_none());
+    /// assert!(db.try_read_field("wrong_key", "name").unwrap().is_none());
+    /// assert_eq!(2, db.get_stats().unwrap().errors.not_found);
+    /// ```
+    pub fn try_read_field(&self, key: &str, field: &str) -> Result<Option<ValueFieldResponse>> {
+        self.stats.track(Operation::Field, || {
+            let result = match self.read_record(key)? {
+                None => return Ok(None),
+                Some(result) => result,
+            };
+
+            let field_value = match result.value {
+                Value::Object(mut fields) => fields.remove(field),
+                _ => return Err(Box::new(Error::ValueType)),
+            };
+
+            match field_value {
+                None => {
+                    self.stats.inc_error(&Error::NotFound);
+
+                    Ok(None)
+                }
+                Some(field_value) => Ok(Some(ValueFieldResponse {
+                    field: field.to_string(),
+                    key: result.key,
+                    value: field_value,
+                    version: result.version,
+                })),
+            }
+        })
+    }
+
+    /// Tries to read records from the database in a single batch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{batch::BatchStatus, config::Config, db::Db, value_record::{Value, ValuePost}};
+    ///
+    /// let config = Config::default();
+    /// let mut db = Db::new(config);
+    ///
+    /// let value_post = ValuePost { expire_at: None, key: "test_key".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(10) };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// let value_post = ValuePost { expire_at: None, key: "expired_key".to_string(), ttl: Some(0), ttl_ms: None, value: Value::Integer(20) };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// let keys = vec!["test_key".to_string(), "wrong_key".to_string(), "expired_key".to_string()];
+    /// let results = db.try_read_many(&keys).unwrap();
+    ///
+    /// assert_eq!(3, results.len());
+    /// assert_eq!(BatchStatus::Found, results[0].status);
+    /// assert_eq!(Some(Value::Integer(10)), results[0].value.as_ref().map(|value| value.value.clone()));
+    /// assert_eq!(BatchStatus::NotFound, results[1].status);
+    /// assert!(results[1].value.is_none());
+    /// assert_eq!(BatchStatus::NotFound, results[2].status);
+    /// assert_eq!(0, db.values_indexes.delete_at.len());
+    /// assert_eq!(1, db.get_stats().unwrap().reads);
+    /// assert_eq!(1, db.get_stats().unwrap().operations.batch_read);
+    /// assert_eq!(1, db.get_stats().unwrap().operations.gc_expired);
+    /// assert_eq!(2, db.get_stats().unwrap().errors.not_found);
+    /// ```
+    pub fn try_read_many(&self, keys: &[String]) -> Result<Vec<BatchResult>> {
+        self.stats.track(Operation::BatchRead, || {
+            let ids = keys
+                .iter()
+                .map(|key| self.values_indexes.get_id(key))
+                .collect::<Vec<Option<Uuid>>>();
+            let values = self
+                .values
+                .get_many(&ids.iter().flatten().copied().collect::<Vec<Uuid>>())?;
+            let now = Utc::now();
+
+            let mut results = vec![];
+            for (key, id) in keys.iter().zip(ids) {
+                let value_record = match id.and_then(|id| values.get(&id)) {
+                    Some(value_record) if value_record.is_expired(now) => {
+                        self.try_delete_by_id(value_record.id, Some(now), None)?;
+
+                        None
+                    }
+                    value_record => value_record,
+                };
+
+                let result = match value_record {
+                    None => {
+                        self.stats.inc_error(&Error::NotFound);
+
+                        BatchResult::new(key, BatchStatus::NotFound, None)
+                    }
+                    Some(value_record) => {
+                        self.stats.inc_reads();
+
+                        BatchResult::new(key, BatchStatus::Found, Some(value_record.clone()))
+                    }
+                };
+                results.append(&mut vec![result]);
+            }
+
+            Ok(results)
+        })
+    }
+
+    /// Tries to read the expiration of a record from the database using the specified key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, value_record::{Value, ValuePost}};
+    ///
+    /// let config = Config::default();
+    /// let mut db = Db::new(config);
+    ///
+    /// let key = "test_key".to_string();
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: Some(100), ttl_ms: None, value: Value::Integer(10) };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// let value_ttl_response = db.try_read_ttl(&key).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_ttl_response.key, key);
+    /// assert!(value_ttl_response.expire_at.is_some());
+    /// assert!(value_ttl_response.ttl.unwrap() > 90);
+    ///
+    /// let value_post = ValuePost { expire_at: None, key: "other_key".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(10) };
+    /// db.try_create(value_post).unwrap();
+    /// let value_ttl_response = db.try_read_ttl("other_key").unwrap().unwrap();
+    ///
+    /// assert_eq!(value_ttl_response.ttl, None);
+    ///
+    /// let value_ttl_response = db.try_read_ttl("wrong_key").unwrap();
+    ///
+    /// assert!(value_ttl_response.is_none());
+    /// assert_eq!(1, db.get_stats().unwrap().errors.not_found);
+    /// ```
+    pub fn try_read_ttl(&self, key: &str) -> Result<Option<ValueTtlResponse>> {
+        self.stats.track(Operation::Ttl, || {
+            let now = Utc::now();
+            let result = match self.values_indexes.get_id(key) {
+                None => None,
+                Some(id) => match self.values.get(&id)? {
+                    Some(result) if result.is_expired(now) => {
+                        self.try_delete_by_id(id, Some(now), None)?;
+
+                        None
+                    }
+                    result => result,
+                },
+            };
+
+            match result {
+                None => {
+                    self.stats.inc_error(&Error::NotFound);
+
+                    Ok(None)
+                }
+                Some(result) => {
+                    self.stats.inc_reads();
+
+                    Ok(Some(ValueTtlResponse::new(&result, now)))
+                }
+            }
+        })
+    }
+
+    /// Tries to rename a record. The record keeps its value, creation time and expiration, while
+    /// the old key stops existing at the same moment. A record already stored under the new key is
+    /// only replaced with `overwrite`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, value_record::{Value, ValuePost}};
+    ///
+    /// let config = Config::default();
+    /// let mut db = Db::new(config);
+    ///
+    /// let value_post = ValuePost { expire_at: None, key: "test_key1".to_string(), ttl: Some(100), ttl_ms: None, value: Value::Integer(10) };
+    /// db.try_create(value_post).unwrap();
+    /// let value_post = ValuePost { expire_at: None, key: "test_key3".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(30) };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// let value_response = db.try_rename("test_key1", "test_key2", false).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_response.key, "test_key2");
+    /// assert_eq!(value_response.value, Value::Integer(10));
+    /// assert_eq!(value_response.version, 2);
+    /// assert!(db.try_read("test_key1").unwrap().is_none());
+    /// assert!(db.try_read_ttl("test_key2").unwrap().unwrap().ttl.is_some());
+    /// assert_eq!(1, db.values_indexes.delete_at.len());
+    ///
+    /// assert!(db.try_rename("test_key2", "test_key3", false).is_err());
+    /// assert_eq!(Value::Integer(30), db.try_read("test_key3").unwrap().unwrap().value);
+    ///
+    /// let value_response = db.try_rename("test_key2", "test_key3", true).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_response.value, Value::Integer(10));
+    /// assert_eq!(1, db.values.len().unwrap());
+    /// assert_eq!(1, db.values_indexes.key.len());
+    ///
+    /// assert!(db.try_rename("wrong_key", "test_key4", false).is_err());
+    /// assert_eq!(4, db.get_stats().unwrap().operations.rename);
+    /// ```
+    pub fn try_rename(
+        &self,
+        from: &str,
+        to: &str,
+        overwrite: bool,
+    ) -> Result<Option<ValueResponse>> {
+        self.stats.track(Operation::Rename, || {
+            self.transfer(from, to, overwrite, false)
+        })
+    }
+
+    /// Tries to apply a list of operations atomically.
+    ///
+    /// Writers and readers of the records involved wait until the transaction ends. Operations see
+    /// the changes of the preceding ones and nothing is changed when any of them fails. The error
+    /// tells which one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, transaction::{TransactionOperation, TransactionResult}, value_record::{Value, ValueDecrement, ValueIncrement, ValuePost}};
+    ///
+    /// let config = Config::default();
+    /// let mut db = Db::new(config);
+    ///
+    /// let value_post = ValuePost { expire_at: None, key: "test_key1".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(100) };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// let operations = vec![
+    ///     TransactionOperation::Decrement { key: "test_key1".to_string(), value: ValueDecrement { create: false, decrement: Some(10), expected_version: None, ttl: None } },
+    ///     TransactionOperation::Create { value: ValuePost { expire_at: None, key: "test_key2".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(0) } },
+    ///     TransactionOperation::Increment { key: "test_key2".to_string(), value: ValueIncrement { create: false, expected_version: None, increment: Some(10), ttl: None } },
+    /// ];
+    /// let results = db.try_transaction(operations).unwrap();
+    ///
+    /// assert_eq!(3, results.len());
+    /// assert!(matches!(&results[2], TransactionResult::Increment(value_response) if value_response.value == Value::Integer(10)));
+    /// assert_eq!(Value::Integer(90), db.try_read("test_key1").unwrap().unwrap().value);
+    /// assert_eq!(Value::Integer(10), db.try_read("test_key2").unwrap().unwrap().value);
+    ///
+    /// let operations = vec![
+    ///     TransactionOperation::Delete { expected_version: None, key: "test_key1".to_string() },
+    ///     TransactionOperation::Increment { key: "wrong_key".to_string(), value: ValueIncrement { create: false, expected_version: None, increment: None, ttl: None } },
+    /// ];
+    /// let result = db.try_transaction(operations);
+    ///
+    /// assert!(result.is_err());
+    /// assert!(db.try_read("test_key1").unwrap().is_some());
+    /// assert_eq!(2, db.get_stats().unwrap().operations.transaction);
+    /// assert_eq!(1, db.get_stats().unwrap().errors.not_found);
+    ///
+    /// let operations = vec![
+    ///     TransactionOperation::Increment { key: "test_key3".to_string(), value: ValueIncrement { create: true, expected_version: None, increment: None, ttl: None } },
+    ///     TransactionOperation::Increment { key: "test_key3".to_string(), value: ValueIncrement { create: true, expected_version: None, increment: None, ttl: None } },
+    /// ];
+    /// let results = db.try_transaction(operations).unwrap();
+    ///
+    /// assert!(matches!(&results[0], TransactionResult::Increment(value_response) if value_response.version == 1));
+    /// assert_eq!(Value::Integer(2), db.try_read("test_key3").unwrap().unwrap().value);
+    /// ```
+    ///
+    /// Changes of a transaction are written to the write-ahead log at once.
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, storage::MemoryStorage, transaction::TransactionOperation, value_record::{Value, ValuePost}};
+    /// use std::sync::Arc;
+    ///
+    /// let storage = Arc::new(MemoryStorage::default());
+    /// let mut db = Db::with_storage(Config::default(), storage.clone());
+    /// db.restore().unwrap();
+    ///
+    /// let value_post = ValuePost { expire_at: None, key: "test_key1".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(100) };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// let operations = vec![
+    ///     TransactionOperation::Delete { expected_version: None, key: "test_key1".to_string() },
+    ///     TransactionOperation::Create { value: ValuePost { expire_at: None, key: "test_key1".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(200) } },
+    ///     TransactionOperation::Create { value: ValuePost { expire_at: None, key: "test_key2".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(300) } },
+    /// ];
+    /// db.try_transaction(operations).unwrap();
+    ///
+    /// let mut db = Db::with_storage(Config::default(), storage);
+    /// db.restore().unwrap();
+    ///
+    /// assert_eq!(2, db.values.len().unwrap());
+    /// assert_eq!(Value::Integer(200), db.try_read("test_key1").unwrap().unwrap().value);
+    /// assert_eq!(Value::Integer(300), db.try_read("test_key2").unwrap().unwrap().value);
+    /// ```
+    pub fn try_transaction(
+        &self,
+        operations: Vec<TransactionOperation>,
+    ) -> Result<Vec<TransactionResult>> {
+        self.stats.track(Operation::Transaction, || {
+            let _checkpoint = self.checkpoint.read().map_err(|_| Error::Lock)?;
+            let mut values = self.lock_keys(operations.iter().map(TransactionOperation::key))?;
+            let now = Utc::now();
+
+            // Records changed by the operations, by key. Deleted records are staged as `None`.
+            let mut staged = HashMap::new();
+            let mut results = vec![];
+
+            for (index, operation) in operations.into_iter().enumerate() {
+                let result = self
+                    .stage_operation(&values, &mut staged, operation, now)
+                    .map_err(|e| match e.downcast::<Error>() {
+                        Ok(error) => Error::Transaction(index, error).into(),
+                        Err(e) => e,
+                    })?;
+                results.append(&mut vec![result]);
+            }
+
+            self.commit(&mut values, staged)?;
+
+            Ok(results)
+        })
     }
 
     /// Tries to update a record in the database using the specified key.
     ///
+    /// With `upsert`, a missing record is created instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -1230,80 +2399,906 @@ impl Db {
     /// let config = Config::default();
     /// let mut db = Db::new(config);
     ///
-    /// assert_eq!(0, db.stats.read().unwrap().writes);
+    /// assert_eq!(0, db.get_stats().unwrap().writes);
     ///
     /// let key = "test_key".to_string();
     /// let value = Value::String("test_value".to_string());
-    /// let value_post = ValuePost { key: key.clone(), ttl: None, value: value.clone() };
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value: value.clone() };
     /// let value_response = db.try_create(value_post).unwrap().unwrap();
     ///
     /// assert_eq!(value_response.key, key);
     /// assert_eq!(value_response.value, value);
-    /// assert_eq!(1, db.stats.read().unwrap().writes);
+    /// assert_eq!(1, db.get_stats().unwrap().writes);
     ///
     /// let value = Value::Integer(100);
-    /// let value_put = ValuePut { ttl: None, value: value.clone() };
+    /// let value_put = ValuePut { expected_version: None, expire_at: None, keep_ttl: false, ttl: None, ttl_ms: None, upsert: false, value: value.clone() };
     /// let value_response = db.try_update(&key, value_put.clone()).unwrap().unwrap();
     ///
     /// assert_eq!(value_response.key, key);
     /// assert_eq!(value_response.value, value);
-    /// assert_eq!(2, db.stats.read().unwrap().writes);
+    /// assert_eq!(value_response.version, 2);
+    /// assert_eq!(2, db.get_stats().unwrap().writes);
     ///
     /// let value_response = db.try_update("wrong_key", value_put);
     ///
     /// assert!(value_response.is_err());
+    ///
+    /// let value_put = ValuePut { expected_version: None, expire_at: None, keep_ttl: false, ttl: None, ttl_ms: Some(100000), upsert: false, value: value.clone() };
+    /// db.try_update(&key, value_put).unwrap();
+    /// let ttl_ms = db.try_read_ttl(&key).unwrap().unwrap().ttl_ms.unwrap();
+    ///
+    /// assert!(ttl_ms > 90000 && ttl_ms <= 100000);
+    ///
+    /// let value_put = ValuePut { expected_version: None, expire_at: None, keep_ttl: true, ttl: None, ttl_ms: None, upsert: false, value: value.clone() };
+    /// db.try_update(&key, value_put).unwrap();
+    ///
+    /// assert!(db.try_read_ttl(&key).unwrap().unwrap().ttl_ms.is_some());
+    /// assert_eq!(1, db.values_indexes.delete_at.len());
+    ///
+    /// let value_put = ValuePut { expected_version: None, expire_at: None, keep_ttl: false, ttl: None, ttl_ms: None, upsert: false, value };
+    /// db.try_update(&key, value_put).unwrap();
+    ///
+    /// assert!(db.try_read_ttl(&key).unwrap().unwrap().ttl_ms.is_none());
+    /// assert_eq!(0, db.values_indexes.delete_at.len());
+    ///
+    /// let value_put = ValuePut { expected_version: Some(4), expire_at: None, keep_ttl: false, ttl: None, ttl_ms: None, upsert: false, value: Value::Integer(10) };
+    /// let value_response = db.try_update(&key, value_put.clone());
+    ///
+    /// assert!(value_response.is_err());
+    /// assert_eq!(1, db.get_stats().unwrap().errors.conflict);
+    ///
+    /// let value_put = ValuePut { expected_version: Some(5), ..value_put };
+    /// let value_response = db.try_update(&key, value_put).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_response.value, Value::Integer(10));
+    /// assert_eq!(value_response.version, 6);
+    ///
+    /// let value_put = ValuePut { expected_version: None, expire_at: None, keep_ttl: false, ttl: None, ttl_ms: None, upsert: true, value: Value::Integer(20) };
+    /// let value_response = db.try_update("new_key", value_put).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_response.value, Value::Integer(20));
+    /// assert_eq!(value_response.version, 1);
+    /// assert_eq!(2, db.values.len().unwrap());
     /// ```
     pub fn try_update(&self, key: &str, value_put: ValuePut) -> Result<Option<ValueResponse>> {
-        let mut stats = self.stats.write().map_err(|_| Error::Lock)?;
-        stats.inc_requests();
+        self.stats.track(Operation::Update, || {
+            if let Some((value, delete_at)) = value_put.initial(Utc::now())? {
+                if let Some(result) = self.create_missing(key, &value, delete_at)? {
+                    return Ok(Some(result));
+                }
+            }
+
+            let _checkpoint = self.checkpoint.read().map_err(|_| Error::Lock)?;
+            let id = self.values_indexes.get_id(key).ok_or(Error::NotFound)?;
+
+            let mut values = self.values.shard(&id).write().map_err(|_| Error::Lock)?;
+            let original_value = self
+                .get_unexpired(&mut values, id)?
+                .ok_or(Error::NotFound)?;
+
+            if !original_value.has_version(value_put.expected_version) {
+                return Err(Box::new(Error::VersionMismatch));
+            }
 
-        let values_key_index = self.values_indexes.key.read().map_err(|_| Error::Lock)?;
-        let id = *values_key_index.get(key).ok_or(Error::NotFound)?;
+            let now = Utc::now();
+            let delete_at = value_put.delete_at(now, original_value.delete_at)?;
+            let value_record = ValueRecord::new(
+                id,
+                &original_value.key,
+                &value_put.value,
+                original_value.created_at,
+                delete_at,
+                now,
+                original_value.version + 1,
+            );
+            self.wal_append(&WalEntry::Upsert(value_record.clone()))?;
+            values.insert(id, value_record);
+            let result = values.get(&id).cloned();
+
+            match result {
+                None => Ok(None),
+                Some(result) => {
+                    self.stats.inc_writes();
+
+                    if let Some(original_value_delete_at) = original_value.delete_at {
+                        self.values_indexes.delete_at.remove(&(
+                            original_value_delete_at
+                                .timestamp_nanos_opt()
+                                .unwrap_or_default(),
+                            id,
+                        ));
+                    }
+                    if let Some(delete_at) = delete_at {
+                        self.values_indexes
+                            .delete_at
+                            .insert((delete_at.timestamp_nanos_opt().unwrap_or_default(), id));
+                    }
 
-        let mut values = self.values.write().map_err(|_| Error::Lock)?;
-        let original_value = values.get(&id).ok_or(Error::NotFound)?.clone();
+                    self.values_indexes.updated_at.remove(&(
+                        original_value
+                            .updated_at
+                            .timestamp_nanos_opt()
+                            .unwrap_or_default(),
+                        id,
+                    ));
+                    self.values_indexes.updated_at.insert((
+                        result.updated_at.timestamp_nanos_opt().unwrap_or_default(),
+                        id,
+                    ));
+
+                    Ok(Some(result.into()))
+                }
+            }
+        })
+    }
+
+    /// Tries to set a field of an object record in the database using the specified key.
+    ///
+    /// A missing field is added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, db::Db, value_record::{Value, ValueFieldPut, ValuePost}};
+    /// use std::collections::BTreeMap;
+    ///
+    /// let config = Config::default();
+    /// let mut db = Db::new(config);
+    ///
+    /// let key = "test_key".to_string();
+    /// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value: Value::Object(BTreeMap::new()) };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// let address = Value::Object(BTreeMap::from([("city".to_string(), Value::String("test_city".to_string()))]));
+    /// let value_field_put = ValueFieldPut { expected_version: None, value: address.clone() };
+    /// let value_field_response = db.try_update_field(&key, "address", value_field_put).unwrap().unwrap();
+    ///
+    /// assert_eq!(value_field_response.value, address);
+    /// assert_eq!(value_field_response.version, 2);
+    /// assert_eq!(Value::Object(BTreeMap::from([("address".to_string(), address)])), db.try_read(&key).unwrap().unwrap().value);
+    ///
+    /// let value_field_put = ValueFieldPut { expected_version: Some(1), value: Value::Integer(10) };
+    ///
+    /// assert!(db.try_update_field(&key, "address", value_field_put).is_err());
+    /// ```
+    pub fn try_update_field(
+        &self,
+        key: &str,
+        field: &str,
+        value_field_put: ValueFieldPut,
+    ) -> Result<Option<ValueFieldResponse>> {
+        self.stats.track(Operation::Field, || {
+            self.modify_field(key, field, value_field_put.expected_version, |value| {
+                Ok(value_field_put
+                    .apply(field, value)
+                    .map(|value| (value, value_field_put.value.clone())))
+            })
+        })
+    }
+
+    /// Applies the changes staged by key, writing them to the write-ahead log as a single entry.
+    /// Deleted records are staged as `None`.
+    fn commit(
+        &self,
+        values: &mut ValueShardsWriteGuard,
+        staged: HashMap<String, Option<ValueRecord>>,
+    ) -> Result<()> {
+        let mut changes = vec![];
+        let mut wal_entries = vec![];
+        for (key, value_record) in staged {
+            let original_value = self
+                .values_indexes
+                .get_id(&key)
+                .and_then(|id| values.get(&id).cloned());
+
+            if let Some(original_value) = &original_value {
+                if value_record.as_ref().map(|value_record| value_record.id)
+                    != Some(original_value.id)
+                {
+                    wal_entries.append(&mut vec![WalEntry::Delete(original_value.id)]);
+                }
+            }
+            if let Some(value_record) = &value_record {
+                wal_entries.append(&mut vec![WalEntry::Upsert(value_record.clone())]);
+            }
+
+            changes.append(&mut vec![(original_value, value_record)]);
+        }
+
+        if !wal_entries.is_empty() {
+            self.wal_append(&WalEntry::Transaction(wal_entries))?;
+        }
+
+        // Records are written before others are deleted, so a renamed key shows up under its
+        // new name before the old one goes away, and the key index never misses a kept key.
+        let (writes, deletes): (Vec<_>, Vec<_>) = changes
+            .into_iter()
+            .partition(|(_original_value, value_record)| value_record.is_some());
+
+        for (original_value, value_record) in writes.into_iter().chain(deletes) {
+            match (original_value, value_record) {
+                (None, None) => {}
+                (None, Some(value_record)) => {
+                    self.values_indexes.insert(&value_record);
+                    values.insert(value_record.id, value_record)?;
+                    self.stats.inc_writes();
+                }
+                (Some(original_value), None) => {
+                    values.remove(&original_value.id)?;
+                    self.values_indexes.remove(&original_value);
+                    self.stats.inc_writes();
+                }
+                (Some(original_value), Some(value_record)) => {
+                    self.values_indexes.replace(&original_value, &value_record);
+                    if original_value.id != value_record.id {
+                        values.remove(&original_value.id)?;
+                    }
+                    values.insert(value_record.id, value_record)?;
+                    self.stats.inc_writes();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Tries to create or replace records in the database in a single batch. Replaced records keep
+    /// their creation time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{batch::BatchStatus, config::Config, db::Db, value_record::{Value, ValuePost}};
+    ///
+    /// let config = Config::default();
+    /// let mut db = Db::new(config);
+    ///
+    /// let value_post = ValuePost { expire_at: None, key: "test_key1".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(10) };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// let values = vec![
+    ///     ValuePost { expire_at: None, key: "test_key1".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(20) },
+    ///     ValuePost { expire_at: None, key: "test_key2".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(30) },
+    /// ];
+    /// let results = db.try_upsert_many(values).unwrap();
+    ///
+    /// assert_eq!(BatchStatus::Updated, results[0].status);
+    /// assert_eq!(Some(2), results[0].value.as_ref().map(|value| value.version));
+    /// assert_eq!(BatchStatus::Created, results[1].status);
+    /// assert_eq!(Value::Integer(20), db.try_read("test_key1").unwrap().unwrap().value);
+    /// assert_eq!(Value::Integer(30), db.try_read("test_key2").unwrap().unwrap().value);
+    /// assert_eq!(2, db.values.len().unwrap());
+    /// ```
+    pub fn try_upsert_many(&self, values: Vec<ValuePost>) -> Result<Vec<BatchResult>> {
+        self.stats
+            .track(Operation::BatchSet, || self.set_many(values, true))
+    }
+
+    /// Creates a new record. The key is reserved in the index before the record is stored.
+    fn create(
+        &self,
+        key: &str,
+        value: &Value,
+        delete_at: Option<DateTime<Utc>>,
+    ) -> Result<Option<ValueResponse>> {
+        // An expired record doesn't hold on to its key.
+        if let Some(existing_id) = self.values_indexes.get_id(key) {
+            self.try_delete_by_id(existing_id, Some(Utc::now()), None)?;
+        }
+
+        let _checkpoint = self.checkpoint.read().map_err(|_| Error::Lock)?;
+        let id = new_id(key);
+        let mut values = self.values.shard(&id).write().map_err(|_| Error::Lock)?;
+
+        // The key is reserved first, so concurrent creates of the same key can't both succeed.
+        let key_entry = self.values_indexes.key.get_or_insert(key.to_string(), id);
+
+        if *key_entry.value() != id {
+            return Err(Box::new(Error::KeyExists));
+        }
+
+        let now = Utc::now();
+        let value_record = ValueRecord::new(id, key, value, now, delete_at, now, 1);
+        if let Err(e) = self.wal_append(&WalEntry::Upsert(value_record.clone())) {
+            key_entry.remove();
+
+            return Err(e);
+        }
+        values.insert(id, value_record);
+        let result = values.get(&id).cloned();
+
+        match result {
+            None => Ok(None),
+            Some(result) => {
+                self.stats.inc_writes();
+
+                self.values_indexes.created_at.insert((
+                    result.created_at.timestamp_nanos_opt().unwrap_or_default(),
+                    id,
+                ));
+
+                if let Some(delete_at) = delete_at {
+                    self.values_indexes
+                        .delete_at
+                        .insert((delete_at.timestamp_nanos_opt().unwrap_or_default(), id));
+                }
+
+                self.values_indexes.updated_at.insert((
+                    result.updated_at.timestamp_nanos_opt().unwrap_or_default(),
+                    id,
+                ));
+
+                Ok(Some(result.into()))
+            }
+        }
+    }
+
+    /// Creates a record when no record with the key exists. Returns `None` when one exists,
+    /// including when it was created concurrently, so the caller proceeds with it instead.
+    fn create_missing(
+        &self,
+        key: &str,
+        value: &Value,
+        delete_at: Option<DateTime<Utc>>,
+    ) -> Result<Option<ValueResponse>> {
+        match self.create(key, value, delete_at) {
+            Err(e) if matches!(e.downcast_ref::<Error>(), Some(Error::KeyExists)) => Ok(None),
+            result => result,
+        }
+    }
+
+    /// Removes an expired record. The lock of its shard must be held.
+    fn expire(&self, values: &mut HashMap<Uuid, ValueRecord>, id: Uuid) -> Result<()> {
+        self.wal_append(&WalEntry::Delete(id))?;
+
+        if let Some(value_record) = values.remove(&id) {
+            self.stats.inc_writes();
+            self.stats.inc_operation(Operation::GcExpired);
+            self.values_indexes.remove(&value_record);
+        }
+
+        Ok(())
+    }
+
+    /// Returns a copy of the record with the specified key as staged, or as stored when it wasn't
+    /// changed yet, unless its time to live has passed. All shards must be locked.
+    fn get_staged(
+        &self,
+        values: &ValueShardsWriteGuard,
+        staged: &HashMap<String, Option<ValueRecord>>,
+        key: &str,
+        now: DateTime<Utc>,
+    ) -> Option<ValueRecord> {
+        match staged.get(key) {
+            Some(value_record) => value_record.clone(),
+            None => self
+                .values_indexes
+                .get_id(key)
+                .and_then(|id| values.get(&id))
+                .filter(|value_record| !value_record.is_expired(now))
+                .cloned(),
+        }
+    }
+
+    /// Takes write locks on the shards of the records with the specified keys and on the shards
+    /// records with the keys would be created in, leaving the other shards to concurrent writers.
+    /// Locking is retried when a key moves to another record before its shard is locked.
+    fn lock_keys<'a>(
+        &self,
+        keys: impl Iterator<Item = &'a str> + Clone,
+    ) -> Result<ValueShardsWriteGuard<'_>> {
+        let mut shard_indexes = BTreeSet::new();
+
+        loop {
+            for key in keys.clone() {
+                shard_indexes.insert(key_shard_index(key));
+                if let Some(id) = self.values_indexes.get_id(key) {
+                    shard_indexes.insert(shard_index(&id));
+                }
+            }
+
+            let values = self.values.write_many(&shard_indexes)?;
+
+            if keys.clone().all(|key| {
+                self.values_indexes
+                    .get_id(key)
+                    .map_or(true, |id| values.is_locked(&id))
+            }) {
+                return Ok(values);
+            }
+        }
+    }
+
+    /// Returns a copy of the record unless its time to live has passed, in which case it is expired.
+    /// The lock of its shard must be held.
+    fn get_unexpired(
+        &self,
+        values: &mut HashMap<Uuid, ValueRecord>,
+        id: Uuid,
+    ) -> Result<Option<ValueRecord>> {
+        let value_record = match values.get(&id) {
+            None => return Ok(None),
+            Some(value_record) => value_record,
+        };
+
+        if value_record.is_expired(Utc::now()) {
+            self.expire(values, id)?;
+
+            return Ok(None);
+        }
+
+        Ok(Some(value_record.clone()))
+    }
+
+    /// Changes a field of an object record. The change returns the new value of the record and the
+    /// value of the field, or `None` when the value type doesn't support it.
+    fn modify_field(
+        &self,
+        key: &str,
+        field: &str,
+        expected_version: Option<u64>,
+        change: impl FnOnce(Value) -> Result<Option<(Value, Value)>>,
+    ) -> Result<Option<ValueFieldResponse>> {
+        let _checkpoint = self.checkpoint.read().map_err(|_| Error::Lock)?;
+        let id = self.values_indexes.get_id(key).ok_or(Error::NotFound)?;
+
+        let mut values = self.values.shard(&id).write().map_err(|_| Error::Lock)?;
+        let original_value = self
+            .get_unexpired(&mut values, id)?
+            .ok_or(Error::NotFound)?;
+
+        if !original_value.has_version(expected_version) {
+            return Err(Box::new(Error::VersionMismatch));
+        }
+
+        let (value, field_value) = match change(original_value.value)? {
+            None => return Ok(None),
+            Some(result) => result,
+        };
 
         let now = Utc::now();
-        let delete_at = value_put.ttl.map(|ttl| now + Duration::seconds(ttl));
         let value_record = ValueRecord::new(
             id,
             &original_value.key,
-            &value_put.value,
+            &value,
             original_value.created_at,
-            delete_at,
+            original_value.delete_at,
             now,
+            original_value.version + 1,
+        );
+        self.wal_append(&WalEntry::Upsert(value_record.clone()))?;
+        values.insert(id, value_record);
+        let result = values.get(&id).cloned();
+
+        match result {
+            None => Ok(None),
+            Some(result) => {
+                self.stats.inc_writes();
+
+                self.values_indexes.updated_at.remove(&(
+                    original_value
+                        .updated_at
+                        .timestamp_nanos_opt()
+                        .unwrap_or_default(),
+                    id,
+                ));
+                self.values_indexes.updated_at.insert((
+                    result.updated_at.timestamp_nanos_opt().unwrap_or_default(),
+                    id,
+                ));
+
+                Ok(Some(ValueFieldResponse {
+                    field: field.to_string(),
+                    key: result.key,
+                    value: field_value,
+                    version: result.version,
+                }))
+            }
+        }
+    }
+
+    /// Returns a copy of the record with the specified key unless its time to live has passed,
+    /// counting the read. A missing record is counted as not found.
+    fn read_record(&self, key: &str) -> Result<Option<ValueRecord>> {
+        let now = Utc::now();
+        let result = match self.values_indexes.get_id(key) {
+            None => None,
+            Some(id) => match self.values.get(&id)? {
+                Some(result) if result.is_expired(now) => {
+                    self.try_delete_by_id(id, Some(now), None)?;
+
+                    None
+                }
+                result => result,
+            },
+        };
+
+        match result {
+            None => self.stats.inc_error(&Error::NotFound),
+            Some(_) => {
+                self.stats.inc_reads();
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Replaces the expiration of an existing record, keeping the delete_at index in sync.
+    fn set_delete_at(
+        &self,
+        key: &str,
+        delete_at: Option<DateTime<Utc>>,
+        expected_version: Option<u64>,
+    ) -> Result<Option<ValueTtlResponse>> {
+        let _checkpoint = self.checkpoint.read().map_err(|_| Error::Lock)?;
+        let id = self.values_indexes.get_id(key).ok_or(Error::NotFound)?;
+
+        let mut values = self.values.shard(&id).write().map_err(|_| Error::Lock)?;
+        let original_value = self
+            .get_unexpired(&mut values, id)?
+            .ok_or(Error::NotFound)?;
+
+        if !original_value.has_version(expected_version) {
+            return Err(Box::new(Error::VersionMismatch));
+        }
+
+        let value_record = ValueRecord::new(
+            id,
+            &original_value.key,
+            &original_value.value,
+            original_value.created_at,
+            delete_at,
+            original_value.updated_at,
+            original_value.version + 1,
         );
+        self.wal_append(&WalEntry::Upsert(value_record.clone()))?;
         values.insert(id, value_record);
         let result = values.get(&id).cloned();
 
         match result {
             None => Ok(None),
             Some(result) => {
-                stats.inc_writes();
+                self.stats.inc_writes();
 
-                let mut values_delete_at_index = self
-                    .values_indexes
-                    .delete_at
-                    .write()
-                    .map_err(|_| Error::Lock)?;
                 if let Some(original_value_delete_at) = original_value.delete_at {
-                    values_delete_at_index.remove(&original_value_delete_at.timestamp_nanos());
+                    self.values_indexes.delete_at.remove(&(
+                        original_value_delete_at
+                            .timestamp_nanos_opt()
+                            .unwrap_or_default(),
+                        id,
+                    ));
                 }
                 if let Some(delete_at) = delete_at {
-                    values_delete_at_index.insert(delete_at.timestamp_nanos(), id);
+                    self.values_indexes
+                        .delete_at
+                        .insert((delete_at.timestamp_nanos_opt().unwrap_or_default(), id));
                 }
 
-                let mut values_updated_at_index = self
-                    .values_indexes
-                    .updated_at
-                    .write()
-                    .map_err(|_| Error::Lock)?;
-                values_updated_at_index.remove(&original_value.updated_at.timestamp_nanos());
-                values_updated_at_index.insert(result.updated_at.timestamp_nanos(), id);
+                Ok(Some(ValueTtlResponse::new(&result, Utc::now())))
+            }
+        }
+    }
+
+    /// Creates the records of a batch, replacing existing ones when `upsert` is set.
+    fn set_many(&self, values_post: Vec<ValuePost>, upsert: bool) -> Result<Vec<BatchResult>> {
+        let _checkpoint = self.checkpoint.read().map_err(|_| Error::Lock)?;
+        let mut values =
+            self.lock_keys(values_post.iter().map(|value_post| value_post.key.as_str()))?;
+        let now = Utc::now();
 
-                Ok(Some(result.into()))
+        let mut staged = HashMap::new();
+        let mut results = vec![];
+
+        for value_post in values_post {
+            let key = value_post.key.clone();
+            let delete_at = value_post.delete_at(now)?;
+
+            let result = match self.get_staged(&values, &staged, &key, now) {
+                Some(_original_value) if !upsert => {
+                    self.stats.inc_error(&Error::KeyExists);
+
+                    BatchResult::new(&key, BatchStatus::Conflict, None)
+                }
+                Some(original_value) => {
+                    let value_record = ValueRecord::new(
+                        original_value.id,
+                        &key,
+                        &value_post.value,
+                        original_value.created_at,
+                        delete_at,
+                        now,
+                        original_value.version + 1,
+                    );
+                    staged.insert(key.clone(), Some(value_record.clone()));
+
+                    BatchResult::new(&key, BatchStatus::Updated, Some(value_record))
+                }
+                None => {
+                    let value_record = ValueRecord::new(
+                        new_id(&key),
+                        &key,
+                        &value_post.value,
+                        now,
+                        delete_at,
+                        now,
+                        1,
+                    );
+                    staged.insert(key.clone(), Some(value_record.clone()));
+
+                    BatchResult::new(&key, BatchStatus::Created, Some(value_record))
+                }
+            };
+            results.append(&mut vec![result]);
+        }
+
+        self.commit(&mut values, staged)?;
+
+        Ok(results)
+    }
+
+    /// Computes the change made by an operation of a transaction on top of the already staged ones.
+    fn stage_operation(
+        &self,
+        values: &ValueShardsWriteGuard,
+        staged: &mut HashMap<String, Option<ValueRecord>>,
+        operation: TransactionOperation,
+        now: DateTime<Utc>,
+    ) -> Result<TransactionResult> {
+        let key = operation.key().to_string();
+        let expected_version = match &operation {
+            TransactionOperation::Append { value, .. } => value.expected_version,
+            TransactionOperation::Create { .. } => None,
+            TransactionOperation::Decrement { value, .. } => value.expected_version,
+            TransactionOperation::Delete {
+                expected_version, ..
+            }
+            | TransactionOperation::Persist {
+                expected_version, ..
+            } => *expected_version,
+            TransactionOperation::Expire { value, .. } => value.expected_version,
+            TransactionOperation::Increment { value, .. } => value.expected_version,
+            TransactionOperation::PopBack { value, .. } => value.expected_version,
+            TransactionOperation::PopFront { value, .. } => value.expected_version,
+            TransactionOperation::Prepend { value, .. } => value.expected_version,
+            TransactionOperation::Update { value, .. } => value.expected_version,
+        };
+
+        let original_value = self.get_staged(values, staged, &key, now);
+
+        if let Some(original_value) = &original_value {
+            if !original_value.has_version(expected_version) {
+                return Err(Box::new(Error::VersionMismatch));
             }
         }
+
+        // Operations creating a missing record start from a record that was never stored,
+        // so the created one gets the first version.
+        let original_value = match original_value {
+            Some(original_value) => Some(original_value),
+            None => {
+                let initial = match &operation {
+                    TransactionOperation::Append { value, .. } => value.initial(now)?,
+                    TransactionOperation::Decrement { value, .. } => value.initial(now)?,
+                    TransactionOperation::Increment { value, .. } => value.initial(now)?,
+                    TransactionOperation::Prepend { value, .. } => value.initial(now)?,
+                    TransactionOperation::Update { value, .. } => value.initial(now)?,
+                    _ => None,
+                };
+
+                initial.map(|(value, delete_at)| {
+                    ValueRecord::new(new_id(&key), &key, &value, now, delete_at, now, 0)
+                })
+            }
+        };
+
+        let with_value =
+            |original_value: &ValueRecord, value: Option<Value>| -> Result<ValueRecord> {
+                let value = value.ok_or(Error::ValueType)?;
+
+                Ok(ValueRecord::new(
+                    original_value.id,
+                    &original_value.key,
+                    &value,
+                    original_value.created_at,
+                    original_value.delete_at,
+                    now,
+                    original_value.version + 1,
+                ))
+            };
+        let with_delete_at = |original_value: &ValueRecord, delete_at: Option<DateTime<Utc>>| {
+            ValueRecord::new(
+                original_value.id,
+                &original_value.key,
+                &original_value.value,
+                original_value.created_at,
+                delete_at,
+                original_value.updated_at,
+                original_value.version + 1,
+            )
+        };
+
+        let (value_record, result) = match (operation, original_value) {
+            (TransactionOperation::Create { value }, None) => {
+                let delete_at = value.delete_at(now)?;
+                let value_record =
+                    ValueRecord::new(new_id(&key), &key, &value.value, now, delete_at, now, 1);
+
+                (
+                    Some(value_record.clone()),
+                    TransactionResult::Create(value_record.into()),
+                )
+            }
+            (TransactionOperation::Create { .. }, Some(_original_value)) => {
+                return Err(Box::new(Error::KeyExists));
+            }
+            (_operation, None) => return Err(Box::new(Error::NotFound)),
+            (TransactionOperation::Append { value, .. }, Some(original_value)) => {
+                let value_record =
+                    with_value(&original_value, value.apply(original_value.value.clone()))?;
+
+                (
+                    Some(value_record.clone()),
+                    TransactionResult::Append(value_record.into()),
+                )
+            }
+            (TransactionOperation::Decrement { value, .. }, Some(original_value)) => {
+                let value_record =
+                    with_value(&original_value, value.apply(original_value.value.clone()))?;
+
+                (
+                    Some(value_record.clone()),
+                    TransactionResult::Decrement(value_record.into()),
+                )
+            }
+            (TransactionOperation::Delete { .. }, Some(original_value)) => {
+                (None, TransactionResult::Delete(original_value.into()))
+            }
+            (TransactionOperation::Expire { value, .. }, Some(original_value)) => {
+                let delete_at = value.delete_at(now)?.ok_or(Error::ExpiryMissing)?;
+                let value_record = with_delete_at(&original_value, Some(delete_at));
+                let result = ValueTtlResponse::new(&value_record, now);
+
+                (Some(value_record), TransactionResult::Expire(result))
+            }
+            (TransactionOperation::Increment { value, .. }, Some(original_value)) => {
+                let value_record =
+                    with_value(&original_value, value.apply(original_value.value.clone()))?;
+
+                (
+                    Some(value_record.clone()),
+                    TransactionResult::Increment(value_record.into()),
+                )
+            }
+            (TransactionOperation::Persist { .. }, Some(original_value)) => {
+                let value_record = with_delete_at(&original_value, None);
+                let result = ValueTtlResponse::new(&value_record, now);
+
+                (Some(value_record), TransactionResult::Persist(result))
+            }
+            (TransactionOperation::PopBack { value, .. }, Some(original_value)) => {
+                let (value, return_values) = value
+                    .apply(original_value.value.clone())
+                    .ok_or(Error::ValueType)?;
+
+                (
+                    Some(with_value(&original_value, Some(value))?),
+                    TransactionResult::PopBack(return_values),
+                )
+            }
+            (TransactionOperation::PopFront { value, .. }, Some(original_value)) => {
+                let (value, return_values) = value
+                    .apply(original_value.value.clone())
+                    .ok_or(Error::ValueType)?;
+
+                (
+                    Some(with_value(&original_value, Some(value))?),
+                    TransactionResult::PopFront(return_values),
+                )
+            }
+            (TransactionOperation::Prepend { value, .. }, Some(original_value)) => {
+                let value_record =
+                    with_value(&original_value, value.apply(original_value.value.clone()))?;
+
+                (
+                    Some(value_record.clone()),
+                    TransactionResult::Prepend(value_record.into()),
+                )
+            }
+            (TransactionOperation::Update { value, .. }, Some(original_value)) => {
+                let delete_at = value.delete_at(now, original_value.delete_at)?;
+                let value_record = ValueRecord::new(
+                    original_value.id,
+                    &original_value.key,
+                    &value.value,
+                    original_value.created_at,
+                    delete_at,
+                    now,
+                    original_value.version + 1,
+                );
+
+                (
+                    Some(value_record.clone()),
+                    TransactionResult::Update(value_record.into()),
+                )
+            }
+        };
+
+        staged.insert(key, value_record);
+
+        Ok(result)
+    }
+
+    /// Stores the record with the `from` key under the `to` key, keeping the original one when
+    /// copying. Both keys change in a single write-ahead log entry.
+    fn transfer(
+        &self,
+        from: &str,
+        to: &str,
+        overwrite: bool,
+        copy: bool,
+    ) -> Result<Option<ValueResponse>> {
+        let _checkpoint = self.checkpoint.read().map_err(|_| Error::Lock)?;
+        let mut values = self.lock_keys([from, to].into_iter())?;
+        let now = Utc::now();
+
+        let mut staged = HashMap::new();
+
+        let original_value = self
+            .get_staged(&values, &staged, from, now)
+            .ok_or(Error::NotFound)?;
+        let replaced_value = self.get_staged(&values, &staged, to, now);
+
+        if replaced_value.is_some() && !overwrite {
+            return Err(Box::new(Error::KeyExists));
+        }
+
+        // A renamed record gets a new id, as the old one is removed along with the old key.
+        let value_record = match (copy, replaced_value) {
+            (true, Some(replaced_value)) => ValueRecord::new(
+                replaced_value.id,
+                to,
+                &original_value.value,
+                replaced_value.created_at,
+                original_value.delete_at,
+                now,
+                replaced_value.version + 1,
+            ),
+            (true, None) => ValueRecord::new(
+                new_id(to),
+                to,
+                &original_value.value,
+                now,
+                original_value.delete_at,
+                now,
+                1,
+            ),
+            (false, _) => ValueRecord::new(
+                new_id(to),
+                to,
+                &original_value.value,
+                original_value.created_at,
+                original_value.delete_at,
+                now,
+                original_value.version + 1,
+            ),
+        };
+
+        if !copy {
+            staged.insert(from.to_string(), None);
+        }
+        staged.insert(to.to_string(), Some(value_record.clone()));
+
+        self.commit(&mut values, staged)?;
+
+        Ok(Some(value_record.into()))
+    }
+
+    fn wal_append(&self, wal_entry: &WalEntry) -> Result<()> {
+        if let Some(wal) = &self.wal {
+            wal.append(wal_entry)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -1335,6 +3330,35 @@ impl From<Direction> for String {
     }
 }
 
+/// Filter of the records returned by [`Db::list`].
+#[derive(Clone, Debug, Default)]
+pub struct ListFilter {
+    /// End of the time range of the sort timestamp, exclusive.
+    pub ends_at: Option<DateTime<Utc>>,
+    /// Glob pattern the keys have to match.
+    pub pattern: Option<String>,
+    /// Prefix the keys have to start with.
+    pub prefix: Option<String>,
+    /// Start of the time range of the sort timestamp, inclusive.
+    pub starts_at: Option<DateTime<Utc>>,
+}
+
+impl ListFilter {
+    /// Checks whether the key passes the filter.
+    pub fn matches(&self, key: &str) -> bool {
+        if let Some(prefix) = &self.prefix {
+            if !key.starts_with(prefix.as_str()) {
+                return false;
+            }
+        }
+
+        match &self.pattern {
+            None => true,
+            Some(pattern) => glob::matches(pattern, key),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Sort {
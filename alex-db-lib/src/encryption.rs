@@ -0,0 +1,114 @@
+use crate::{error::Error, Result};
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng, Payload},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use std::{fmt, str::FromStr};
+
+pub const ENCRYPTION_KEY_LEN: usize = 32;
+
+const NONCE_LEN: usize = 12;
+
+/// Key used for ChaCha20-Poly1305 authenticated encryption of the data at rest.
+///
+/// The key is parsed from 64 hex characters and never shows up in debug output.
+///
+/// # Examples
+///
+/// ```
+/// use alex_db_lib::encryption::EncryptionKey;
+///
+/// let key = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f".parse::<EncryptionKey>().unwrap();
+/// let other_key = EncryptionKey::generate();
+///
+/// let encrypted = key.encrypt(b"test_value", b"header").unwrap();
+///
+/// assert_eq!(b"test_value".to_vec(), key.decrypt(&encrypted, b"header").unwrap());
+/// assert!(key.decrypt(&encrypted, b"other_header").is_err());
+/// assert!(other_key.decrypt(&encrypted, b"header").is_err());
+/// assert_eq!("EncryptionKey(<redacted>)", format!("{key:?}"));
+/// assert!("0001".parse::<EncryptionKey>().is_err());
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct EncryptionKey([u8; ENCRYPTION_KEY_LEN]);
+
+impl EncryptionKey {
+    /// Generates a new random key.
+    pub fn generate() -> Self {
+        let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+
+        Self(key.into())
+    }
+
+    /// Encrypts the data. The result is the random nonce followed by the ciphertext.
+    pub fn encrypt(&self, plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(&self.0));
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: plaintext,
+                    aad,
+                },
+            )
+            .map_err(|_| Error::Encryption)?;
+
+        let mut encrypted = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+        encrypted.extend_from_slice(&nonce);
+        encrypted.extend_from_slice(&ciphertext);
+
+        Ok(encrypted)
+    }
+
+    /// Decrypts the data produced by [`EncryptionKey::encrypt`], verifying that it was not tampered with.
+    pub fn decrypt(&self, encrypted: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        if encrypted.len() < NONCE_LEN {
+            return Err(Box::new(Error::Decryption));
+        }
+
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(&self.0));
+        let (nonce, ciphertext) = encrypted.split_at(NONCE_LEN);
+        let plaintext = cipher
+            .decrypt(
+                Nonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad,
+                },
+            )
+            .map_err(|_| Error::Decryption)?;
+
+        Ok(plaintext)
+    }
+}
+
+impl fmt::Debug for EncryptionKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "EncryptionKey(<redacted>)")
+    }
+}
+
+impl FromStr for EncryptionKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut key = [0; ENCRYPTION_KEY_LEN];
+        hex::decode_to_slice(s.trim(), &mut key).map_err(|_| Error::EncryptionKeyParse)?;
+
+        Ok(Self(key))
+    }
+}
+
+/// Decrypts the data with the first of the specified keys that fits.
+///
+/// Keys are tried in order, so the current key should go first followed by the previous ones.
+pub fn decrypt_with_any(keys: &[EncryptionKey], encrypted: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+    for key in keys {
+        if let Ok(plaintext) = key.decrypt(encrypted, aad) {
+            return Ok(plaintext);
+        }
+    }
+
+    Err(Box::new(Error::Decryption))
+}
@@ -2,10 +2,23 @@ use std::fmt;
 
 #[derive(Debug)]
 pub enum Error {
+    CursorParse,
+    Decryption,
+    Encryption,
+    EncryptionKeyParse,
+    ExpiryMissing,
+    ExpiryRange,
     KeyExists,
+    KeyspaceDefault,
+    KeyspaceName,
     Lock,
     NotFound,
+    SnapshotCorrupted,
+    SnapshotVersion,
+    Transaction(usize, Box<Error>),
     ValueParse,
+    ValueType,
+    VersionMismatch,
 }
 
 impl std::error::Error for Error {}
@@ -14,10 +27,31 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use Error::*;
         match self {
+            CursorParse => write!(f, "Problem with parsing cursor."),
+            Decryption => write!(
+                f,
+                "Unable to decrypt data. The encryption key is missing or wrong."
+            ),
+            Encryption => write!(f, "Problem with encrypting data."),
+            EncryptionKeyParse => write!(
+                f,
+                "Encryption key must be 32 bytes encoded as 64 hex characters."
+            ),
+            ExpiryMissing => write!(f, "Expiration time or time to live is required."),
+            ExpiryRange => write!(f, "Expiration time is out of range."),
             KeyExists => write!(f, "Key already exists."),
+            KeyspaceDefault => write!(f, "Default keyspace can't be dropped."),
+            KeyspaceName => write!(f, "Keyspace name is invalid."),
             Lock => write!(f, "Problem with the lock."),
             NotFound => write!(f, "Not found."),
+            SnapshotCorrupted => write!(f, "Snapshot file is corrupted."),
+            SnapshotVersion => write!(f, "Unsupported snapshot format version."),
+            Transaction(index, error) => {
+                write!(f, "Transaction aborted at operation {index}. {error}")
+            }
             ValueParse => write!(f, "Problem with parsing value."),
+            ValueType => write!(f, "Operation is not supported by the value type."),
+            VersionMismatch => write!(f, "Record version does not match the expected version."),
         }
     }
 }
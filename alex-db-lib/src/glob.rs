@@ -0,0 +1,116 @@
+/// Checks whether the text matches a glob pattern.
+///
+/// `*` matches any sequence of characters, `?` matches a single character and `[...]` matches
+/// one of the enclosed characters or ranges, negated by a leading `!` or `^`. A backslash
+/// matches the following character literally.
+///
+/// # Examples
+///
+/// ```
+/// use alex_db_lib::glob::matches;
+///
+/// assert!(matches("user:*:session", "user:42:session"));
+/// assert!(matches("user:??", "user:42"));
+/// assert!(matches("user:[0-4]*", "user:42"));
+/// assert!(matches("user:[!5-9]*", "user:42"));
+/// assert!(matches("user\\*", "user*"));
+/// assert!(!matches("user:*:session", "user:42:token"));
+/// assert!(!matches("user:?", "user:42"));
+/// assert!(!matches("user\\*", "user:42"));
+/// ```
+pub fn matches(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<char>>();
+    let text = text.chars().collect::<Vec<char>>();
+
+    let mut pattern_index = 0;
+    let mut text_index = 0;
+    // Positions right after the last `*` and of the text it currently stands for, to backtrack to.
+    let mut backtrack = None;
+
+    while text_index < text.len() {
+        if pattern_index < pattern.len() && pattern[pattern_index] == '*' {
+            pattern_index += 1;
+            backtrack = Some((pattern_index, text_index));
+
+            continue;
+        }
+
+        if let Some(next_pattern_index) = match_char(&pattern, pattern_index, text[text_index]) {
+            pattern_index = next_pattern_index;
+            text_index += 1;
+
+            continue;
+        }
+
+        match backtrack {
+            None => return false,
+            Some((star_pattern_index, star_text_index)) => {
+                pattern_index = star_pattern_index;
+                text_index = star_text_index + 1;
+                backtrack = Some((star_pattern_index, text_index));
+            }
+        }
+    }
+
+    pattern[pattern_index..].iter().all(|c| *c == '*')
+}
+
+/// Matches a single character against the pattern element at the specified index.
+/// Returns the index of the next pattern element on success.
+fn match_char(pattern: &[char], index: usize, c: char) -> Option<usize> {
+    match pattern.get(index)? {
+        '?' => Some(index + 1),
+        '\\' => match pattern.get(index + 1) {
+            Some(escaped) if *escaped == c => Some(index + 2),
+            Some(_escaped) => None,
+            None if c == '\\' => Some(index + 1),
+            None => None,
+        },
+        '[' => match_class(pattern, index, c),
+        p if *p == c => Some(index + 1),
+        _ => None,
+    }
+}
+
+/// Matches a single character against the `[...]` class starting at the specified index.
+/// An unterminated class is matched as a literal `[`.
+fn match_class(pattern: &[char], index: usize, c: char) -> Option<usize> {
+    let mut i = index + 1;
+    let negated = matches!(pattern.get(i), Some('!') | Some('^'));
+    if negated {
+        i += 1;
+    }
+
+    let mut matched = false;
+    let mut first = true;
+
+    loop {
+        let start = match pattern.get(i) {
+            None => return if c == '[' { Some(index + 1) } else { None },
+            Some(']') if !first => break,
+            Some(start) => *start,
+        };
+        first = false;
+
+        match (pattern.get(i + 1), pattern.get(i + 2)) {
+            (Some('-'), Some(end)) if *end != ']' => {
+                if start <= c && c <= *end {
+                    matched = true;
+                }
+                i += 3;
+            }
+            _ => {
+                if start == c {
+                    matched = true;
+                }
+                i += 1;
+            }
+        }
+    }
+
+    if matched != negated {
+        Some(i + 1)
+    } else {
+        None
+    }
+}
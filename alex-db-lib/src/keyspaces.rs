@@ -0,0 +1,311 @@
+use crate::{config::Config, db::Db, error::Error, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::Path,
+    sync::{Arc, RwLock},
+};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// Name of the keyspace kept directly in the data directory.
+pub const DEFAULT_KEYSPACE: &str = "default";
+/// Directory of the data directory holding a subdirectory per named keyspace.
+pub const KEYSPACES_DIR: &str = "keyspaces";
+
+lazy_static! {
+    static ref VALID_KEYSPACE_NAME: Regex = Regex::new(r"^[a-zA-Z0-9_-]{1,64}$").unwrap();
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Validate)]
+pub struct KeyspacePost {
+    #[validate(regex = "VALID_KEYSPACE_NAME")]
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct KeyspaceResponse {
+    pub name: String,
+}
+
+/// Named keyspaces, each being a separate database with its own values, indexes and stats.
+///
+/// The default keyspace is stored directly in the data directory, so data saved before
+/// keyspaces existed stays in it. Other keyspaces are stored in their own subdirectories.
+#[derive(Debug)]
+pub struct Keyspaces {
+    config: Config,
+    default: Arc<Db>,
+    keyspaces: RwLock<BTreeMap<String, Arc<Db>>>,
+}
+
+impl Keyspaces {
+    /// Creates keyspaces holding only the default one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, keyspaces::{Keyspaces, DEFAULT_KEYSPACE}};
+    ///
+    /// let config = Config::default();
+    /// let keyspaces = Keyspaces::new(config);
+    ///
+    /// assert_eq!(vec![DEFAULT_KEYSPACE.to_string()], keyspaces.list().unwrap());
+    /// ```
+    pub fn new(config: Config) -> Self {
+        Self {
+            default: Arc::new(Db::new(config.clone())),
+            config,
+            keyspaces: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    /// Returns the database of the default keyspace.
+    pub fn default_keyspace(&self) -> Arc<Db> {
+        self.default.clone()
+    }
+
+    /// Saves all keyspaces regardless of the save thresholds.
+    pub fn force_save(&self) -> Result<()> {
+        for db in self.all()? {
+            db.force_save()?;
+        }
+
+        Ok(())
+    }
+
+    /// Deletes the records whose time to live has passed in all keyspaces.
+    pub fn gc(&self) -> Result<()> {
+        for db in self.all()? {
+            db.gc()?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the database of the keyspace with the specified name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, keyspaces::{Keyspaces, DEFAULT_KEYSPACE}};
+    ///
+    /// let config = Config::default();
+    /// let keyspaces = Keyspaces::new(config);
+    ///
+    /// assert!(keyspaces.get(DEFAULT_KEYSPACE).unwrap().is_some());
+    /// assert!(keyspaces.get("staging").unwrap().is_none());
+    /// ```
+    pub fn get(&self, name: &str) -> Result<Option<Arc<Db>>> {
+        if name == DEFAULT_KEYSPACE {
+            return Ok(Some(self.default_keyspace()));
+        }
+
+        let keyspaces = self.keyspaces.read().map_err(|_| Error::Lock)?;
+
+        Ok(keyspaces.get(name).cloned())
+    }
+
+    /// Returns the names of all keyspaces, the default one first.
+    pub fn list(&self) -> Result<Vec<String>> {
+        let keyspaces = self.keyspaces.read().map_err(|_| Error::Lock)?;
+
+        let mut names = vec![DEFAULT_KEYSPACE.to_string()];
+        names.append(&mut keyspaces.keys().cloned().collect());
+
+        Ok(names)
+    }
+
+    /// Restores the default keyspace and the named keyspaces found in the data directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, keyspaces::Keyspaces, value_record::{Value, ValuePost}};
+    ///
+    /// let data_dir = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+    /// std::fs::create_dir_all(&data_dir).unwrap();
+    /// let config = Config {
+    ///     data_dir: Some(data_dir.to_str().unwrap().to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let mut keyspaces = Keyspaces::new(config.clone());
+    /// keyspaces.restore().unwrap();
+    ///
+    /// let db = keyspaces.try_create("staging").unwrap().unwrap();
+    /// let value_post = ValuePost { expire_at: None, key: "test_key".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(10) };
+    /// db.try_create(value_post).unwrap();
+    /// keyspaces.force_save().unwrap();
+    /// drop(db);
+    /// drop(keyspaces);
+    ///
+    /// let mut keyspaces = Keyspaces::new(config);
+    /// keyspaces.restore().unwrap();
+    /// let db = keyspaces.get("staging").unwrap().unwrap();
+    ///
+    /// assert_eq!(Value::Integer(10), db.try_read("test_key").unwrap().unwrap().value);
+    /// assert!(keyspaces.default_keyspace().try_read("test_key").unwrap().is_none());
+    /// # std::fs::remove_dir_all(data_dir).unwrap();
+    /// ```
+    pub fn restore(&mut self) -> Result<()> {
+        let mut default = Db::new(self.config.clone());
+        default.restore()?;
+        self.default = Arc::new(default);
+
+        let mut keyspaces = BTreeMap::new();
+
+        if let Some(data_dir) = &self.config.data_dir {
+            let keyspaces_dir = Path::new(data_dir).join(KEYSPACES_DIR);
+
+            if keyspaces_dir.exists() {
+                for entry in fs::read_dir(keyspaces_dir)? {
+                    let entry = entry?;
+                    let name = entry.file_name().to_string_lossy().to_string();
+
+                    if !entry.file_type()?.is_dir() || !VALID_KEYSPACE_NAME.is_match(&name) {
+                        continue;
+                    }
+
+                    let mut db = Db::new(self.keyspace_config(&name));
+                    db.restore()?;
+                    keyspaces.insert(name, Arc::new(db));
+                }
+            }
+        }
+
+        self.keyspaces = RwLock::new(keyspaces);
+
+        Ok(())
+    }
+
+    /// Saves all keyspaces when their save thresholds are reached.
+    pub fn save(&self) -> Result<()> {
+        for db in self.all()? {
+            db.save()?;
+        }
+
+        Ok(())
+    }
+
+    /// Syncs the write-ahead logs of all keyspaces once the interval of their sync policy has
+    /// passed.
+    pub fn sync_wal(&self) -> Result<()> {
+        for db in self.all()? {
+            db.sync_wal()?;
+        }
+
+        Ok(())
+    }
+
+    /// Tries to create an empty keyspace with the specified name. Returns `None` when a keyspace
+    /// with the name already exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, keyspaces::{Keyspaces, DEFAULT_KEYSPACE}, value_record::{Value, ValuePost}};
+    ///
+    /// let config = Config::default();
+    /// let keyspaces = Keyspaces::new(config);
+    ///
+    /// let db = keyspaces.try_create("staging").unwrap().unwrap();
+    /// let value_post = ValuePost { expire_at: None, key: "test_key".to_string(), ttl: None, ttl_ms: None, value: Value::Integer(10) };
+    /// db.try_create(value_post).unwrap();
+    ///
+    /// assert!(keyspaces.default_keyspace().try_read("test_key").unwrap().is_none());
+    /// assert_eq!(vec![DEFAULT_KEYSPACE.to_string(), "staging".to_string()], keyspaces.list().unwrap());
+    /// assert!(keyspaces.try_create("staging").unwrap().is_none());
+    /// assert!(keyspaces.try_create(DEFAULT_KEYSPACE).unwrap().is_none());
+    /// assert!(keyspaces.try_create("../staging").is_err());
+    /// ```
+    pub fn try_create(&self, name: &str) -> Result<Option<Arc<Db>>> {
+        if !VALID_KEYSPACE_NAME.is_match(name) {
+            return Err(Box::new(Error::KeyspaceName));
+        }
+
+        let mut keyspaces = self.keyspaces.write().map_err(|_| Error::Lock)?;
+
+        if name == DEFAULT_KEYSPACE || keyspaces.contains_key(name) {
+            return Ok(None);
+        }
+
+        let config = self.keyspace_config(name);
+        if let Some(data_dir) = &config.data_dir {
+            fs::create_dir_all(data_dir)?;
+        }
+
+        let mut db = Db::new(config);
+        db.restore()?;
+        let db = Arc::new(db);
+        keyspaces.insert(name.to_string(), db.clone());
+
+        Ok(Some(db))
+    }
+
+    /// Tries to drop the keyspace with the specified name along with its data.
+    /// The default keyspace can't be dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{config::Config, keyspaces::{Keyspaces, DEFAULT_KEYSPACE}};
+    ///
+    /// let config = Config::default();
+    /// let keyspaces = Keyspaces::new(config);
+    /// keyspaces.try_create("staging").unwrap();
+    ///
+    /// assert!(keyspaces.try_drop("staging").unwrap().is_some());
+    /// assert!(keyspaces.get("staging").unwrap().is_none());
+    /// assert!(keyspaces.try_drop("staging").unwrap().is_none());
+    /// assert!(keyspaces.try_drop(DEFAULT_KEYSPACE).is_err());
+    /// ```
+    pub fn try_drop(&self, name: &str) -> Result<Option<Arc<Db>>> {
+        if name == DEFAULT_KEYSPACE {
+            return Err(Box::new(Error::KeyspaceDefault));
+        }
+
+        let mut keyspaces = self.keyspaces.write().map_err(|_| Error::Lock)?;
+
+        let db = match keyspaces.remove(name) {
+            None => return Ok(None),
+            Some(db) => db,
+        };
+
+        if let Some(data_dir) = &db.config.data_dir {
+            if Path::new(data_dir).exists() {
+                fs::remove_dir_all(data_dir)?;
+            }
+        }
+
+        Ok(Some(db))
+    }
+
+    /// Returns the databases of all keyspaces, the default one first.
+    fn all(&self) -> Result<Vec<Arc<Db>>> {
+        let keyspaces = self.keyspaces.read().map_err(|_| Error::Lock)?;
+
+        let mut dbs = vec![self.default_keyspace()];
+        dbs.append(&mut keyspaces.values().cloned().collect());
+
+        Ok(dbs)
+    }
+
+    /// Returns the configuration of a named keyspace, storing it in its own subdirectory.
+    fn keyspace_config(&self, name: &str) -> Config {
+        let data_dir = self.config.data_dir.as_ref().map(|data_dir| {
+            Path::new(data_dir)
+                .join(KEYSPACES_DIR)
+                .join(name)
+                .to_string_lossy()
+                .to_string()
+        });
+
+        Config {
+            data_dir,
+            ..self.config.clone()
+        }
+    }
+}
@@ -4,9 +4,19 @@ use std::error::Error;
 
 type Result<T> = std::result::Result<T, Box<dyn Error + Send + Sync>>;
 
+pub mod batch;
 pub mod config;
+pub mod cursor;
 pub mod db;
+pub mod encryption;
 pub mod error;
+pub mod glob;
+pub mod keyspaces;
+pub mod snapshot;
 pub mod stat_record;
+pub mod storage;
+pub mod transaction;
 pub mod value_index;
 pub mod value_record;
+pub mod value_shards;
+pub mod wal;
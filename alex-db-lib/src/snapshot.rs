@@ -0,0 +1,590 @@
+use crate::{
+    encryption::{decrypt_with_any, EncryptionKey},
+    error::Error,
+    value_index::ValueIndex,
+    value_record::{Value, ValueRecord},
+    Result,
+};
+use bincode::Options;
+use chrono::{DateTime, TimeZone, Utc};
+use crossbeam_skiplist::{SkipMap, SkipSet};
+use lz4_flex::{compress_prepend_size, decompress_size_prepended};
+use serde::{
+    ser::{SerializeMap, SerializeSeq},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, VecDeque},
+    fmt,
+    str::FromStr,
+};
+use uuid::Uuid;
+
+pub const SNAPSHOT_FORMAT_VERSION: u16 = 3;
+
+const SNAPSHOT_FORMAT_VERSION_JSON: u16 = 1;
+const SNAPSHOT_FORMAT_VERSION_UNVERSIONED: u16 = 2;
+const SNAPSHOT_ENCRYPTED: u8 = 1;
+const SNAPSHOT_HEADER_LEN: usize = 24;
+const SNAPSHOT_NOT_ENCRYPTED: u8 = 0;
+const SNAPSHOT_MAGIC: &[u8; 8] = b"ALEXSNAP";
+const ZSTD_COMPRESSION_LEVEL: i32 = 3;
+
+/// Compression codec applied to the encoded snapshot.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SnapshotCompression {
+    Lz4,
+    None,
+    Zstd,
+}
+
+impl SnapshotCompression {
+    fn compress(&self, bytes: &[u8]) -> Result<Vec<u8>> {
+        let compressed = match self {
+            SnapshotCompression::Lz4 => compress_prepend_size(bytes),
+            SnapshotCompression::None => bytes.to_vec(),
+            SnapshotCompression::Zstd => zstd::encode_all(bytes, ZSTD_COMPRESSION_LEVEL)?,
+        };
+
+        Ok(compressed)
+    }
+
+    fn decompress(&self, bytes: &[u8]) -> Result<Vec<u8>> {
+        let decompressed = match self {
+            SnapshotCompression::Lz4 => decompress_size_prepended(bytes)?,
+            SnapshotCompression::None => bytes.to_vec(),
+            SnapshotCompression::Zstd => zstd::decode_all(bytes)?,
+        };
+
+        Ok(decompressed)
+    }
+
+    fn from_u8(codec: u8) -> Result<Self> {
+        match codec {
+            0 => Ok(SnapshotCompression::None),
+            1 => Ok(SnapshotCompression::Lz4),
+            2 => Ok(SnapshotCompression::Zstd),
+            _ => Err(Box::new(Error::SnapshotCorrupted)),
+        }
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            SnapshotCompression::None => 0,
+            SnapshotCompression::Lz4 => 1,
+            SnapshotCompression::Zstd => 2,
+        }
+    }
+}
+
+impl Default for SnapshotCompression {
+    fn default() -> Self {
+        SnapshotCompression::Lz4
+    }
+}
+
+impl fmt::Display for SnapshotCompression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotCompression::Lz4 => write!(f, "lz4"),
+            SnapshotCompression::None => write!(f, "none"),
+            SnapshotCompression::Zstd => write!(f, "zstd"),
+        }
+    }
+}
+
+impl FromStr for SnapshotCompression {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "lz4" => Ok(SnapshotCompression::Lz4),
+            "none" => Ok(SnapshotCompression::None),
+            "zstd" => Ok(SnapshotCompression::Zstd),
+            _ => Err(Error::ValueParse),
+        }
+    }
+}
+
+/// Full state of the database as stored in the snapshot file.
+///
+/// Deserialization reads the JSON format of earlier versions, where timestamp indexes were maps.
+#[derive(Debug, Default, Deserialize)]
+pub struct Snapshot {
+    pub api_keys: Vec<Uuid>,
+    #[serde(default, deserialize_with = "deserialize_timestamp_index_map")]
+    pub created_at_index: Option<BTreeSet<(i64, Uuid)>>,
+    #[serde(default, deserialize_with = "deserialize_timestamp_index_map")]
+    pub delete_at_index: Option<BTreeSet<(i64, Uuid)>>,
+    pub key_index: Option<BTreeMap<String, Uuid>>,
+    #[serde(default, deserialize_with = "deserialize_timestamp_index_map")]
+    pub updated_at_index: Option<BTreeSet<(i64, Uuid)>>,
+    pub values: HashMap<Uuid, ValueRecord>,
+}
+
+/// Borrowed counterpart of [`Snapshot`] used for saving without cloning the database.
+///
+/// Indexes are derived data and are only persisted when requested.
+/// Values are borrowed shard by shard.
+#[derive(Debug)]
+pub struct SnapshotRef<'a> {
+    pub api_keys: &'a Vec<Uuid>,
+    pub values: &'a [&'a HashMap<Uuid, ValueRecord>],
+    pub values_indexes: Option<&'a ValueIndex>,
+}
+
+// The binary payload mirrors the public structures with a tagged value enum and
+// timestamps stored as seconds and nanoseconds, which bincode encodes compactly.
+// Variant order of `BinaryValue` and `BinaryValueRef` must stay in sync, and new variants
+// are added last so that earlier snapshots keep decoding.
+// Records of the previous binary format have no version and are read as `BinaryUnversionedValueRecord`.
+
+#[derive(Deserialize)]
+struct BinarySnapshot<R> {
+    api_keys: Vec<Uuid>,
+    created_at_index: Option<BTreeSet<(i64, Uuid)>>,
+    delete_at_index: Option<BTreeSet<(i64, Uuid)>>,
+    key_index: Option<BTreeMap<String, Uuid>>,
+    updated_at_index: Option<BTreeSet<(i64, Uuid)>>,
+    values: Vec<R>,
+}
+
+#[derive(Serialize)]
+struct BinarySnapshotRef<'a> {
+    api_keys: &'a Vec<Uuid>,
+    created_at_index: Option<BinaryTimestampIndexRef<'a>>,
+    delete_at_index: Option<BinaryTimestampIndexRef<'a>>,
+    key_index: Option<BinaryKeyIndexRef<'a>>,
+    updated_at_index: Option<BinaryTimestampIndexRef<'a>>,
+    values: BinaryValueRecordsRef<'a>,
+}
+
+// Skip lists are written the same way bincode writes `BTreeSet` and `BTreeMap`.
+
+struct BinaryKeyIndexRef<'a>(&'a SkipMap<String, Uuid>);
+
+struct BinaryTimestampIndexRef<'a>(&'a SkipSet<(i64, Uuid)>);
+
+#[derive(Deserialize)]
+enum BinaryValue {
+    Array(VecDeque<BinaryValue>),
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Object(BTreeMap<String, BinaryValue>),
+}
+
+#[derive(Serialize)]
+enum BinaryValueRef<'a> {
+    Array(BinaryValuesRef<'a>),
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    String(&'a str),
+    Object(BinaryObjectRef<'a>),
+}
+
+struct BinaryObjectRef<'a>(&'a BTreeMap<String, Value>);
+
+struct BinaryValuesRef<'a>(&'a VecDeque<Value>);
+
+#[derive(Deserialize)]
+struct BinaryUnversionedValueRecord {
+    id: Uuid,
+    key: String,
+    value: BinaryValue,
+    created_at: (i64, u32),
+    delete_at: Option<(i64, u32)>,
+    updated_at: (i64, u32),
+}
+
+#[derive(Deserialize)]
+struct BinaryValueRecord {
+    id: Uuid,
+    key: String,
+    value: BinaryValue,
+    created_at: (i64, u32),
+    delete_at: Option<(i64, u32)>,
+    updated_at: (i64, u32),
+    version: u64,
+}
+
+#[derive(Serialize)]
+struct BinaryValueRecordRef<'a> {
+    id: &'a Uuid,
+    key: &'a str,
+    value: BinaryValueRef<'a>,
+    created_at: (i64, u32),
+    delete_at: Option<(i64, u32)>,
+    updated_at: (i64, u32),
+    version: u64,
+}
+
+struct BinaryValueRecordsRef<'a>(&'a [&'a HashMap<Uuid, ValueRecord>]);
+
+impl<R> BinarySnapshot<R> {
+    fn map_values<T>(self, f: impl FnMut(R) -> T) -> BinarySnapshot<T> {
+        BinarySnapshot {
+            api_keys: self.api_keys,
+            created_at_index: self.created_at_index,
+            delete_at_index: self.delete_at_index,
+            key_index: self.key_index,
+            updated_at_index: self.updated_at_index,
+            values: self.values.into_iter().map(f).collect(),
+        }
+    }
+}
+
+impl From<BinaryUnversionedValueRecord> for BinaryValueRecord {
+    fn from(binary_value_record: BinaryUnversionedValueRecord) -> Self {
+        Self {
+            id: binary_value_record.id,
+            key: binary_value_record.key,
+            value: binary_value_record.value,
+            created_at: binary_value_record.created_at,
+            delete_at: binary_value_record.delete_at,
+            updated_at: binary_value_record.updated_at,
+            version: 1,
+        }
+    }
+}
+
+impl From<BinaryValue> for Value {
+    fn from(binary_value: BinaryValue) -> Self {
+        match binary_value {
+            BinaryValue::Array(values) => {
+                Value::Array(values.into_iter().map(Value::from).collect())
+            }
+            BinaryValue::Boolean(value) => Value::Boolean(value),
+            BinaryValue::Integer(value) => Value::Integer(value),
+            BinaryValue::Float(value) => Value::Float(value),
+            BinaryValue::String(value) => Value::String(value),
+            BinaryValue::Object(values) => Value::Object(
+                values
+                    .into_iter()
+                    .map(|(field, value)| (field, Value::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl<'a> From<&'a Value> for BinaryValueRef<'a> {
+    fn from(value: &'a Value) -> Self {
+        match value {
+            Value::Array(values) => BinaryValueRef::Array(BinaryValuesRef(values)),
+            Value::Boolean(value) => BinaryValueRef::Boolean(*value),
+            Value::Integer(value) => BinaryValueRef::Integer(*value),
+            Value::Float(value) => BinaryValueRef::Float(*value),
+            Value::Object(values) => BinaryValueRef::Object(BinaryObjectRef(values)),
+            Value::String(value) => BinaryValueRef::String(value),
+        }
+    }
+}
+
+impl Serialize for BinaryObjectRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (field, value) in self.0 {
+            map.serialize_entry(field, &BinaryValueRef::from(value))?;
+        }
+        map.end()
+    }
+}
+
+impl Serialize for BinaryValuesRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for value in self.0 {
+            seq.serialize_element(&BinaryValueRef::from(value))?;
+        }
+        seq.end()
+    }
+}
+
+impl TryFrom<BinaryValueRecord> for ValueRecord {
+    type Error = Error;
+
+    fn try_from(binary_value_record: BinaryValueRecord) -> std::result::Result<Self, Self::Error> {
+        let delete_at = match binary_value_record.delete_at {
+            None => None,
+            Some(delete_at) => Some(from_timestamp(delete_at)?),
+        };
+
+        let value_record = ValueRecord {
+            id: binary_value_record.id,
+            key: binary_value_record.key,
+            value: Value::from(binary_value_record.value),
+            created_at: from_timestamp(binary_value_record.created_at)?,
+            delete_at,
+            updated_at: from_timestamp(binary_value_record.updated_at)?,
+            version: binary_value_record.version,
+        };
+
+        Ok(value_record)
+    }
+}
+
+impl<'a> From<&'a ValueRecord> for BinaryValueRecordRef<'a> {
+    fn from(value_record: &'a ValueRecord) -> Self {
+        Self {
+            id: &value_record.id,
+            key: &value_record.key,
+            value: BinaryValueRef::from(&value_record.value),
+            created_at: to_timestamp(&value_record.created_at),
+            delete_at: value_record.delete_at.as_ref().map(to_timestamp),
+            updated_at: to_timestamp(&value_record.updated_at),
+            version: value_record.version,
+        }
+    }
+}
+
+impl Serialize for BinaryKeyIndexRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for entry in self.0.iter() {
+            map.serialize_entry(entry.key(), entry.value())?;
+        }
+        map.end()
+    }
+}
+
+impl Serialize for BinaryTimestampIndexRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for entry in self.0.iter() {
+            seq.serialize_element(entry.value())?;
+        }
+        seq.end()
+    }
+}
+
+impl Serialize for BinaryValueRecordsRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let len = self.0.iter().map(|values| values.len()).sum();
+        let mut seq = serializer.serialize_seq(Some(len))?;
+        for values in self.0 {
+            for value_record in values.values() {
+                seq.serialize_element(&BinaryValueRecordRef::from(value_record))?;
+            }
+        }
+        seq.end()
+    }
+}
+
+impl Snapshot {
+    /// Decodes a snapshot, verifying its header and checksum.
+    ///
+    /// Encrypted snapshots are decrypted with the first of the specified keys that fits.
+    /// Snapshots written in the JSON format of earlier versions are decoded as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{encryption::EncryptionKey, snapshot::{Snapshot, SnapshotCompression, SnapshotRef}, value_index::ValueIndex, value_record::{Value, ValueRecord}};
+    /// use chrono::Utc;
+    /// use std::collections::{BTreeMap, HashMap, VecDeque};
+    /// use uuid::Uuid;
+    ///
+    /// let now = Utc::now();
+    /// let id = Uuid::new_v4();
+    /// let object = Value::Object(BTreeMap::from([("test_field".to_string(), Value::Boolean(true))]));
+    /// let value = Value::Array(VecDeque::from([Value::Integer(10), Value::String("test".to_string()), object]));
+    /// let value_record = ValueRecord::new(id, "test_key", &value, now, None, now, 3);
+    /// let values = HashMap::from([(id, value_record)]);
+    /// let values_indexes = ValueIndex::from_values(&values);
+    /// let snapshot_ref = SnapshotRef {
+    ///     api_keys: &vec![Uuid::new_v4()],
+    ///     values: &[&values],
+    ///     values_indexes: None,
+    /// };
+    ///
+    /// for compression in [SnapshotCompression::Lz4, SnapshotCompression::None, SnapshotCompression::Zstd] {
+    ///     let encoded = snapshot_ref.encode(compression, None).unwrap();
+    ///     let snapshot = Snapshot::decode(&encoded, &[]).unwrap();
+    ///
+    ///     assert_eq!(1, snapshot.api_keys.len());
+    ///     assert!(snapshot.created_at_index.is_none());
+    ///     assert!(snapshot.key_index.is_none());
+    ///     assert_eq!(value, snapshot.values[&id].value);
+    ///     assert_eq!(now, snapshot.values[&id].created_at);
+    ///     assert_eq!(3, snapshot.values[&id].version);
+    ///
+    ///     let truncated = &encoded[..encoded.len() - 1];
+    ///
+    ///     assert!(Snapshot::decode(truncated, &[]).is_err());
+    /// }
+    ///
+    /// let key = EncryptionKey::generate();
+    /// let previous_key = EncryptionKey::generate();
+    /// let encoded = snapshot_ref.encode(SnapshotCompression::Lz4, Some(&previous_key)).unwrap();
+    ///
+    /// assert!(!encoded.windows(8).any(|window| window == b"test_key"));
+    /// assert!(Snapshot::decode(&encoded, &[]).is_err());
+    /// assert!(Snapshot::decode(&encoded, &[key.clone()]).is_err());
+    /// assert_eq!(value, Snapshot::decode(&encoded, &[key, previous_key]).unwrap().values[&id].value);
+    ///
+    /// let snapshot_ref = SnapshotRef { values_indexes: Some(&values_indexes), ..snapshot_ref };
+    /// let snapshot = Snapshot::decode(&snapshot_ref.encode(SnapshotCompression::None, None).unwrap(), &[]).unwrap();
+    ///
+    /// assert_eq!(Some(&id), snapshot.key_index.unwrap().get("test_key"));
+    /// assert_eq!(1, snapshot.updated_at_index.unwrap().len());
+    /// ```
+    ///
+    /// ```
+    /// use alex_db_lib::{snapshot::Snapshot, value_record::Value};
+    ///
+    /// let json = br#"{"api_keys":[],"values":{"67e55044-10b1-426f-9247-bb680e5fe0c8":{"id":"67e55044-10b1-426f-9247-bb680e5fe0c8","key":"test_key","value":10,"created_at":"2023-02-14T00:00:00Z","delete_at":null,"updated_at":"2023-02-14T00:00:00Z"}}}"#;
+    /// let payload = lz4_flex::compress_prepend_size(json);
+    /// let mut encoded = b"ALEXSNAP".to_vec();
+    /// encoded.extend_from_slice(&1u16.to_le_bytes());
+    /// encoded.extend_from_slice(&0u16.to_le_bytes());
+    /// encoded.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+    /// encoded.extend_from_slice(&crc32fast::hash(&payload).to_le_bytes());
+    /// encoded.extend_from_slice(&payload);
+    /// let snapshot = Snapshot::decode(&encoded, &[]).unwrap();
+    ///
+    /// assert_eq!(Value::Integer(10), snapshot.values.values().next().unwrap().value);
+    /// assert_eq!(1, snapshot.values.values().next().unwrap().version);
+    /// ```
+    pub fn decode(encoded: &[u8], decryption_keys: &[EncryptionKey]) -> Result<Self> {
+        if encoded.len() < SNAPSHOT_HEADER_LEN || &encoded[0..8] != SNAPSHOT_MAGIC {
+            return Err(Box::new(Error::SnapshotCorrupted));
+        }
+
+        let version = u16::from_le_bytes(encoded[8..10].try_into()?);
+        if version != SNAPSHOT_FORMAT_VERSION
+            && version != SNAPSHOT_FORMAT_VERSION_JSON
+            && version != SNAPSHOT_FORMAT_VERSION_UNVERSIONED
+        {
+            return Err(Box::new(Error::SnapshotVersion));
+        }
+
+        let len = u64::from_le_bytes(encoded[12..20].try_into()?);
+        let checksum = u32::from_le_bytes(encoded[20..24].try_into()?);
+        let payload = &encoded[SNAPSHOT_HEADER_LEN..];
+
+        if payload.len() as u64 != len || crc32fast::hash(payload) != checksum {
+            return Err(Box::new(Error::SnapshotCorrupted));
+        }
+
+        if version == SNAPSHOT_FORMAT_VERSION_JSON {
+            let uncompressed = decompress_size_prepended(payload)?;
+            let snapshot = serde_json::from_slice(&uncompressed)?;
+
+            return Ok(snapshot);
+        }
+
+        let compression = SnapshotCompression::from_u8(encoded[10])?;
+        let uncompressed = match encoded[11] {
+            SNAPSHOT_NOT_ENCRYPTED => compression.decompress(payload)?,
+            SNAPSHOT_ENCRYPTED => {
+                let decrypted = decrypt_with_any(decryption_keys, payload, &encoded[0..12])?;
+                compression.decompress(&decrypted)?
+            }
+            _ => return Err(Box::new(Error::SnapshotCorrupted)),
+        };
+        let options = bincode::DefaultOptions::new().reject_trailing_bytes();
+        let binary_snapshot: BinarySnapshot<BinaryValueRecord> =
+            if version == SNAPSHOT_FORMAT_VERSION_UNVERSIONED {
+                options
+                    .deserialize::<BinarySnapshot<BinaryUnversionedValueRecord>>(&uncompressed)?
+                    .map_values(BinaryValueRecord::from)
+            } else {
+                options.deserialize(&uncompressed)?
+            };
+
+        let mut values = HashMap::with_capacity(binary_snapshot.values.len());
+        for binary_value_record in binary_snapshot.values {
+            let value_record = ValueRecord::try_from(binary_value_record)?;
+            values.insert(value_record.id, value_record);
+        }
+
+        let snapshot = Self {
+            api_keys: binary_snapshot.api_keys,
+            created_at_index: binary_snapshot.created_at_index,
+            delete_at_index: binary_snapshot.delete_at_index,
+            key_index: binary_snapshot.key_index,
+            updated_at_index: binary_snapshot.updated_at_index,
+            values,
+        };
+
+        Ok(snapshot)
+    }
+}
+
+impl SnapshotRef<'_> {
+    /// Encodes the snapshot with a header carrying the format version, the compression codec and a checksum.
+    ///
+    /// When the encryption key is specified, the compressed payload is encrypted and authenticated
+    /// together with the header.
+    pub fn encode(
+        &self,
+        compression: SnapshotCompression,
+        encryption_key: Option<&EncryptionKey>,
+    ) -> Result<Vec<u8>> {
+        let binary_snapshot_ref = BinarySnapshotRef {
+            api_keys: self.api_keys,
+            created_at_index: self
+                .values_indexes
+                .map(|values_indexes| BinaryTimestampIndexRef(&values_indexes.created_at)),
+            delete_at_index: self
+                .values_indexes
+                .map(|values_indexes| BinaryTimestampIndexRef(&values_indexes.delete_at)),
+            key_index: self
+                .values_indexes
+                .map(|values_indexes| BinaryKeyIndexRef(&values_indexes.key)),
+            updated_at_index: self
+                .values_indexes
+                .map(|values_indexes| BinaryTimestampIndexRef(&values_indexes.updated_at)),
+            values: BinaryValueRecordsRef(self.values),
+        };
+        let serialized = bincode::DefaultOptions::new().serialize(&binary_snapshot_ref)?;
+        let compressed = compression.compress(&serialized)?;
+
+        let mut encoded = Vec::with_capacity(SNAPSHOT_HEADER_LEN + compressed.len());
+        encoded.extend_from_slice(SNAPSHOT_MAGIC);
+        encoded.extend_from_slice(&SNAPSHOT_FORMAT_VERSION.to_le_bytes());
+        encoded.push(compression.to_u8());
+
+        let payload = match encryption_key {
+            None => {
+                encoded.push(SNAPSHOT_NOT_ENCRYPTED);
+                compressed
+            }
+            Some(encryption_key) => {
+                encoded.push(SNAPSHOT_ENCRYPTED);
+                encryption_key.encrypt(&compressed, &encoded)?
+            }
+        };
+
+        encoded.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+        encoded.extend_from_slice(&crc32fast::hash(&payload).to_le_bytes());
+        encoded.extend_from_slice(&payload);
+
+        Ok(encoded)
+    }
+}
+
+fn deserialize_timestamp_index_map<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<BTreeSet<(i64, Uuid)>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let index = Option::<BTreeMap<i64, Uuid>>::deserialize(deserializer)?;
+
+    Ok(index.map(|index| index.into_iter().collect()))
+}
+
+fn from_timestamp((secs, nsecs): (i64, u32)) -> std::result::Result<DateTime<Utc>, Error> {
+    Utc.timestamp_opt(secs, nsecs)
+        .single()
+        .ok_or(Error::SnapshotCorrupted)
+}
+
+fn to_timestamp(date_time: &DateTime<Utc>) -> (i64, u32) {
+    (date_time.timestamp(), date_time.timestamp_subsec_nanos())
+}
@@ -1,9 +1,23 @@
+use crate::{error::Error, Result};
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Mutex,
+};
 use utoipa::ToSchema;
 
+/// Point-in-time copy of the statistics.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
 pub struct StatRecord {
+    #[serde(default)]
+    pub errors: ErrorStatRecord,
+    #[serde(default)]
+    pub gc_runs: u128,
+    #[serde(default)]
+    pub last_save: Option<SaveStatRecord>,
+    #[serde(default)]
+    pub operations: OperationStatRecord,
     pub reads: u128,
     pub requests: u128,
     pub saved_at: Option<DateTime<Utc>>,
@@ -11,11 +25,143 @@ pub struct StatRecord {
     pub writes: u128,
 }
 
-impl StatRecord {
-    pub fn can_save(&self, save_triggered_after_ms: i64, save_triggered_by_threshold: u16) -> bool {
+/// Number of failed operations by cause.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+pub struct ErrorStatRecord {
+    pub conflict: u128,
+    pub lock: u128,
+    pub not_found: u128,
+}
+
+/// Number of performed operations by kind.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+pub struct OperationStatRecord {
+    pub append: u128,
+    pub batch_delete: u128,
+    pub batch_read: u128,
+    pub batch_set: u128,
+    pub compare_and_swap: u128,
+    pub copy: u128,
+    pub create: u128,
+    pub decrement: u128,
+    pub delete: u128,
+    pub expire: u128,
+    pub field: u128,
+    pub gc_expired: u128,
+    pub increment: u128,
+    pub list: u128,
+    pub persist: u128,
+    pub pop: u128,
+    pub prepend: u128,
+    pub read: u128,
+    pub rename: u128,
+    pub transaction: u128,
+    pub ttl: u128,
+    pub update: u128,
+}
+
+/// Outcome of the most recent save attempt.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct SaveStatRecord {
+    pub duration_us: u128,
+    pub finished_at: DateTime<Utc>,
+    pub succeeded: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Operation {
+    Append,
+    BatchDelete,
+    BatchRead,
+    BatchSet,
+    CompareAndSwap,
+    Copy,
+    Create,
+    Decrement,
+    Delete,
+    Expire,
+    Field,
+    GcExpired,
+    Increment,
+    List,
+    Persist,
+    Pop,
+    Prepend,
+    Read,
+    Rename,
+    Transaction,
+    Ttl,
+    Update,
+}
+
+/// Statistics of the database kept in atomic counters, so updating them doesn't serialize requests.
+///
+/// # Examples
+///
+/// ```
+/// use alex_db_lib::{error::Error, stat_record::{Operation, Stats}};
+///
+/// let stats = Stats::default();
+/// let result = stats.track(Operation::Read, || Ok(10));
+///
+/// assert_eq!(10, result.unwrap());
+///
+/// let result: Result<(), _> = stats.track(Operation::Update, || Err(Error::NotFound.into()));
+///
+/// assert!(result.is_err());
+///
+/// let stat_record = stats.to_stat_record().unwrap();
+///
+/// assert_eq!(2, stat_record.requests);
+/// assert_eq!(1, stat_record.operations.read);
+/// assert_eq!(1, stat_record.operations.update);
+/// assert_eq!(1, stat_record.errors.not_found);
+/// ```
+#[derive(Debug, Default)]
+pub struct Stats {
+    conflict_errors: AtomicU64,
+    lock_errors: AtomicU64,
+    not_found_errors: AtomicU64,
+    gc_runs: AtomicU64,
+    last_save: Mutex<Option<SaveStatRecord>>,
+    append_operations: AtomicU64,
+    batch_delete_operations: AtomicU64,
+    batch_read_operations: AtomicU64,
+    batch_set_operations: AtomicU64,
+    compare_and_swap_operations: AtomicU64,
+    copy_operations: AtomicU64,
+    create_operations: AtomicU64,
+    decrement_operations: AtomicU64,
+    delete_operations: AtomicU64,
+    expire_operations: AtomicU64,
+    field_operations: AtomicU64,
+    gc_expired_operations: AtomicU64,
+    increment_operations: AtomicU64,
+    list_operations: AtomicU64,
+    persist_operations: AtomicU64,
+    pop_operations: AtomicU64,
+    prepend_operations: AtomicU64,
+    read_operations: AtomicU64,
+    rename_operations: AtomicU64,
+    transaction_operations: AtomicU64,
+    ttl_operations: AtomicU64,
+    update_operations: AtomicU64,
+    reads: AtomicU64,
+    requests: AtomicU64,
+    saved_at: Mutex<Option<DateTime<Utc>>>,
+    saved_writes: AtomicU64,
+    writes: AtomicU64,
+}
+
+impl Stats {
+    pub fn can_save(
+        &self,
+        save_triggered_after_ms: i64,
+        save_triggered_by_threshold: u16,
+    ) -> Result<bool> {
         let mut can_save = false;
 
-        match self.saved_at {
+        match *self.saved_at.lock().map_err(|_| Error::Lock)? {
             None => can_save = true,
             Some(saved_at) => {
                 if saved_at + Duration::milliseconds(save_triggered_after_ms) < Utc::now() {
@@ -24,35 +170,149 @@ impl StatRecord {
             }
         }
 
-        if self.writes >= self.saved_writes + u128::from(save_triggered_by_threshold) {
+        let writes = self.writes.load(Ordering::Relaxed);
+        let saved_writes = self.saved_writes.load(Ordering::Relaxed);
+        if writes >= saved_writes + u64::from(save_triggered_by_threshold) {
             can_save = true;
         }
 
-        can_save
+        Ok(can_save)
     }
 
-    pub fn inc_reads(&mut self) -> u128 {
-        self.reads += 1;
+    /// Counts an error by its cause. Errors without a counter are ignored.
+    pub fn inc_error(&self, error: &Error) {
+        let counter = match error {
+            Error::KeyExists | Error::ValueType | Error::VersionMismatch => &self.conflict_errors,
+            Error::Lock => &self.lock_errors,
+            Error::NotFound => &self.not_found_errors,
+            Error::Transaction(_index, error) => return self.inc_error(error),
+            _ => return,
+        };
 
-        self.reads
+        counter.fetch_add(1, Ordering::Relaxed);
     }
 
-    pub fn inc_requests(&mut self) -> u128 {
-        self.requests += 1;
+    pub fn inc_gc_runs(&self) -> u64 {
+        self.gc_runs.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    pub fn inc_operation(&self, operation: Operation) {
+        let counter = match operation {
+            Operation::Append => &self.append_operations,
+            Operation::BatchDelete => &self.batch_delete_operations,
+            Operation::BatchRead => &self.batch_read_operations,
+            Operation::BatchSet => &self.batch_set_operations,
+            Operation::CompareAndSwap => &self.compare_and_swap_operations,
+            Operation::Copy => &self.copy_operations,
+            Operation::Create => &self.create_operations,
+            Operation::Decrement => &self.decrement_operations,
+            Operation::Delete => &self.delete_operations,
+            Operation::Expire => &self.expire_operations,
+            Operation::Field => &self.field_operations,
+            Operation::GcExpired => &self.gc_expired_operations,
+            Operation::Increment => &self.increment_operations,
+            Operation::List => &self.list_operations,
+            Operation::Persist => &self.persist_operations,
+            Operation::Pop => &self.pop_operations,
+            Operation::Prepend => &self.prepend_operations,
+            Operation::Read => &self.read_operations,
+            Operation::Rename => &self.rename_operations,
+            Operation::Transaction => &self.transaction_operations,
+            Operation::Ttl => &self.ttl_operations,
+            Operation::Update => &self.update_operations,
+        };
+
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn inc_reads(&self) -> u64 {
+        self.reads.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    pub fn inc_requests(&self) -> u64 {
+        self.requests.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    pub fn inc_writes(&self) -> u64 {
+        self.writes.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    pub fn to_stat_record(&self) -> Result<StatRecord> {
+        let load = |counter: &AtomicU64| u128::from(counter.load(Ordering::Relaxed));
+
+        let stat_record = StatRecord {
+            errors: ErrorStatRecord {
+                conflict: load(&self.conflict_errors),
+                lock: load(&self.lock_errors),
+                not_found: load(&self.not_found_errors),
+            },
+            gc_runs: load(&self.gc_runs),
+            last_save: self.last_save.lock().map_err(|_| Error::Lock)?.clone(),
+            operations: OperationStatRecord {
+                append: load(&self.append_operations),
+                batch_delete: load(&self.batch_delete_operations),
+                batch_read: load(&self.batch_read_operations),
+                batch_set: load(&self.batch_set_operations),
+                compare_and_swap: load(&self.compare_and_swap_operations),
+                copy: load(&self.copy_operations),
+                create: load(&self.create_operations),
+                decrement: load(&self.decrement_operations),
+                delete: load(&self.delete_operations),
+                expire: load(&self.expire_operations),
+                field: load(&self.field_operations),
+                gc_expired: load(&self.gc_expired_operations),
+                increment: load(&self.increment_operations),
+                list: load(&self.list_operations),
+                persist: load(&self.persist_operations),
+                pop: load(&self.pop_operations),
+                prepend: load(&self.prepend_operations),
+                read: load(&self.read_operations),
+                rename: load(&self.rename_operations),
+                transaction: load(&self.transaction_operations),
+                ttl: load(&self.ttl_operations),
+                update: load(&self.update_operations),
+            },
+            reads: load(&self.reads),
+            requests: load(&self.requests),
+            saved_at: *self.saved_at.lock().map_err(|_| Error::Lock)?,
+            saved_writes: load(&self.saved_writes),
+            writes: load(&self.writes),
+        };
+
+        Ok(stat_record)
+    }
+
+    /// Counts a request of the specified operation and the error it ends with, if any.
+    pub fn track<T>(&self, operation: Operation, f: impl FnOnce() -> Result<T>) -> Result<T> {
+        self.inc_requests();
+        self.inc_operation(operation);
+
+        let result = f();
+
+        if let Err(e) = &result {
+            if let Some(error) = e.downcast_ref::<Error>() {
+                self.inc_error(error);
+            }
+        }
 
-        self.requests
+        result
     }
 
-    pub fn inc_writes(&mut self) -> u128 {
-        self.writes += 1;
+    pub fn update_last_save(&self, duration_us: u128, succeeded: bool) -> Result<()> {
+        *self.last_save.lock().map_err(|_| Error::Lock)? = Some(SaveStatRecord {
+            duration_us,
+            finished_at: Utc::now(),
+            succeeded,
+        });
 
-        self.writes
+        Ok(())
     }
 
-    pub fn update_saved_writes(&mut self) -> u128 {
-        self.saved_at = Some(Utc::now());
-        self.saved_writes = self.writes;
+    pub fn update_saved_writes(&self) -> Result<u64> {
+        let saved_writes = self.writes.load(Ordering::Relaxed);
+        *self.saved_at.lock().map_err(|_| Error::Lock)? = Some(Utc::now());
+        self.saved_writes.store(saved_writes, Ordering::Relaxed);
 
-        self.saved_writes
+        Ok(saved_writes)
     }
 }
@@ -0,0 +1,298 @@
+use crate::{error::Error, snapshot::Snapshot, Result};
+use lz4_flex::decompress_size_prepended;
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::Path,
+    sync::Mutex,
+};
+use uuid::Uuid;
+
+pub const API_KEYS_FILE: &str = "api_keys.sec";
+pub const SNAPSHOT_FILE: &str = "snapshot.db";
+pub const VALUES_CREATED_AT_INDEX_FILE: &str = "values_created_at.idx";
+pub const VALUES_DATABASE_FILE: &str = "values.db";
+pub const VALUES_DELETE_AT_INDEX_FILE: &str = "values_delete_at.idx";
+pub const VALUES_KEY_INDEX_FILE: &str = "values_key.idx";
+pub const VALUES_UPDATED_AT_INDEX_FILE: &str = "values_updated_at.idx";
+pub const WAL_FILE: &str = "values.wal";
+
+/// Place where the database keeps its snapshot and write-ahead log.
+pub trait StorageBackend: Debug + Send + Sync {
+    /// Returns the encoded snapshot, or `None` when nothing has been saved yet.
+    fn load_snapshot(&self) -> Result<Option<Vec<u8>>>;
+
+    /// Returns the state saved in a format that predates the snapshot file, if there is one.
+    fn load_legacy_snapshot(&self) -> Result<Option<Snapshot>> {
+        Ok(None)
+    }
+
+    /// Replaces the encoded snapshot. A failure must leave the previous snapshot intact.
+    fn save_snapshot(&self, encoded: &[u8]) -> Result<()>;
+
+    /// Returns the whole content of the write-ahead log.
+    fn load_wal(&self) -> Result<Vec<u8>>;
+
+    /// Appends bytes to the write-ahead log, flushing them to durable storage when `sync` is set.
+    fn append_wal(&self, bytes: &[u8], sync: bool) -> Result<()>;
+
+    /// Flushes the appended bytes of the write-ahead log to durable storage.
+    fn sync_wal(&self) -> Result<()> {
+        self.append_wal(&[], true)
+    }
+
+    /// Cuts the write-ahead log down to the specified length.
+    fn truncate_wal(&self, len: u64) -> Result<()>;
+}
+
+/// Stores the database in files under a data directory.
+#[derive(Debug)]
+pub struct DirectoryStorage {
+    data_dir: String,
+    wal_file: Mutex<Option<File>>,
+}
+
+impl DirectoryStorage {
+    pub fn new(data_dir: &str) -> Self {
+        Self {
+            data_dir: data_dir.to_string(),
+            wal_file: Mutex::new(None),
+        }
+    }
+
+    fn file_path(&self, file: &str) -> String {
+        format!("{}/{file}", self.data_dir)
+    }
+
+    fn load_legacy_file<T>(&self, file: &str) -> Result<Option<T>>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        let file_path = self.file_path(file);
+        if !Path::new(&file_path).exists() {
+            return Ok(None);
+        }
+
+        let compressed = fs::read(file_path)?;
+        let uncompressed = decompress_size_prepended(&compressed)?;
+        let deserialized = serde_json::from_slice(&uncompressed)?;
+
+        Ok(Some(deserialized))
+    }
+}
+
+impl StorageBackend for DirectoryStorage {
+    fn load_snapshot(&self) -> Result<Option<Vec<u8>>> {
+        let snapshot_file_path = self.file_path(SNAPSHOT_FILE);
+        let tmp_snapshot_file_path = format!("{snapshot_file_path}.tmp");
+        if Path::new(&tmp_snapshot_file_path).exists() {
+            fs::remove_file(tmp_snapshot_file_path)?;
+        }
+
+        if !Path::new(&snapshot_file_path).exists() {
+            return Ok(None);
+        }
+
+        let encoded = fs::read(snapshot_file_path)?;
+
+        Ok(Some(encoded))
+    }
+
+    fn load_legacy_snapshot(&self) -> Result<Option<Snapshot>> {
+        let values = self.load_legacy_file(VALUES_DATABASE_FILE)?;
+
+        match values {
+            None => Ok(None),
+            Some(values) => {
+                let snapshot = Snapshot {
+                    api_keys: self.load_legacy_file(API_KEYS_FILE)?.unwrap_or_default(),
+                    created_at_index: self
+                        .load_legacy_file::<BTreeMap<i64, Uuid>>(VALUES_CREATED_AT_INDEX_FILE)?
+                        .map(|index| index.into_iter().collect()),
+                    delete_at_index: self
+                        .load_legacy_file::<BTreeMap<i64, Uuid>>(VALUES_DELETE_AT_INDEX_FILE)?
+                        .map(|index| index.into_iter().collect()),
+                    key_index: self.load_legacy_file(VALUES_KEY_INDEX_FILE)?,
+                    updated_at_index: self
+                        .load_legacy_file::<BTreeMap<i64, Uuid>>(VALUES_UPDATED_AT_INDEX_FILE)?
+                        .map(|index| index.into_iter().collect()),
+                    values,
+                };
+
+                Ok(Some(snapshot))
+            }
+        }
+    }
+
+    fn save_snapshot(&self, encoded: &[u8]) -> Result<()> {
+        let snapshot_file_path = self.file_path(SNAPSHOT_FILE);
+        let tmp_snapshot_file_path = format!("{snapshot_file_path}.tmp");
+
+        let mut file = File::create(&tmp_snapshot_file_path)?;
+        file.write_all(encoded)?;
+        file.sync_all()?;
+        drop(file);
+
+        fs::rename(&tmp_snapshot_file_path, &snapshot_file_path)?;
+        File::open(&self.data_dir)?.sync_all()?;
+
+        for file in [
+            API_KEYS_FILE,
+            VALUES_CREATED_AT_INDEX_FILE,
+            VALUES_DATABASE_FILE,
+            VALUES_DELETE_AT_INDEX_FILE,
+            VALUES_KEY_INDEX_FILE,
+            VALUES_UPDATED_AT_INDEX_FILE,
+        ] {
+            let file_path = self.file_path(file);
+            if Path::new(&file_path).exists() {
+                fs::remove_file(file_path)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn load_wal(&self) -> Result<Vec<u8>> {
+        let wal_file_path = self.file_path(WAL_FILE);
+        if !Path::new(&wal_file_path).exists() {
+            return Ok(vec![]);
+        }
+
+        let bytes = fs::read(wal_file_path)?;
+
+        Ok(bytes)
+    }
+
+    fn append_wal(&self, bytes: &[u8], sync: bool) -> Result<()> {
+        let mut wal_file = self.wal_file.lock().map_err(|_| Error::Lock)?;
+
+        if wal_file.is_none() {
+            let file = OpenOptions::new()
+                .append(true)
+                .create(true)
+                .open(self.file_path(WAL_FILE))?;
+            *wal_file = Some(file);
+        }
+
+        if let Some(file) = wal_file.as_mut() {
+            file.write_all(bytes)?;
+
+            if sync {
+                file.sync_data()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn sync_wal(&self) -> Result<()> {
+        let wal_file = self.wal_file.lock().map_err(|_| Error::Lock)?;
+
+        if let Some(file) = wal_file.as_ref() {
+            file.sync_data()?;
+        }
+
+        Ok(())
+    }
+
+    fn truncate_wal(&self, len: u64) -> Result<()> {
+        let _wal_file = self.wal_file.lock().map_err(|_| Error::Lock)?;
+
+        let wal_file_path = self.file_path(WAL_FILE);
+        if Path::new(&wal_file_path).exists() {
+            let file = OpenOptions::new().write(true).open(wal_file_path)?;
+            file.set_len(len)?;
+            file.sync_all()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Keeps the database in memory. Useful for tests and for embedding with custom persistence.
+///
+/// # Examples
+///
+/// ```
+/// use alex_db_lib::{config::Config, db::Db, storage::MemoryStorage, value_record::{Value, ValuePost}};
+/// use std::sync::Arc;
+///
+/// let storage = Arc::new(MemoryStorage::default());
+/// let mut db = Db::with_storage(Config::default(), storage.clone());
+/// db.restore().unwrap();
+///
+/// let key = "test_key".to_string();
+/// let value = Value::Integer(10);
+/// let value_post = ValuePost { expire_at: None, key: key.clone(), ttl: None, ttl_ms: None, value: value.clone() };
+/// db.try_create(value_post).unwrap();
+///
+/// assert!(storage.snapshot().unwrap().is_none());
+/// assert!(!storage.wal().unwrap().is_empty());
+///
+/// db.save().unwrap();
+///
+/// assert!(storage.snapshot().unwrap().is_some());
+/// assert!(storage.wal().unwrap().is_empty());
+///
+/// let mut db = Db::with_storage(Config::default(), storage);
+/// db.restore().unwrap();
+/// let value_response = db.try_read(&key).unwrap().unwrap();
+///
+/// assert_eq!(value_response.value, value);
+/// ```
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    snapshot: Mutex<Option<Vec<u8>>>,
+    wal: Mutex<Vec<u8>>,
+}
+
+impl MemoryStorage {
+    /// Returns a copy of the encoded snapshot.
+    pub fn snapshot(&self) -> Result<Option<Vec<u8>>> {
+        let snapshot = self.snapshot.lock().map_err(|_| Error::Lock)?.clone();
+
+        Ok(snapshot)
+    }
+
+    /// Returns a copy of the write-ahead log.
+    pub fn wal(&self) -> Result<Vec<u8>> {
+        let wal = self.wal.lock().map_err(|_| Error::Lock)?.clone();
+
+        Ok(wal)
+    }
+}
+
+impl StorageBackend for MemoryStorage {
+    fn load_snapshot(&self) -> Result<Option<Vec<u8>>> {
+        self.snapshot()
+    }
+
+    fn save_snapshot(&self, encoded: &[u8]) -> Result<()> {
+        let mut snapshot = self.snapshot.lock().map_err(|_| Error::Lock)?;
+        *snapshot = Some(encoded.to_vec());
+
+        Ok(())
+    }
+
+    fn load_wal(&self) -> Result<Vec<u8>> {
+        self.wal()
+    }
+
+    fn append_wal(&self, bytes: &[u8], _sync: bool) -> Result<()> {
+        let mut wal = self.wal.lock().map_err(|_| Error::Lock)?;
+        wal.extend_from_slice(bytes);
+
+        Ok(())
+    }
+
+    fn truncate_wal(&self, len: u64) -> Result<()> {
+        let mut wal = self.wal.lock().map_err(|_| Error::Lock)?;
+        wal.truncate(usize::try_from(len)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use crate::value_record::{
+    Value, ValueAppend, ValueDecrement, ValueExpire, ValueIncrement, ValuePopBack, ValuePopFront,
+    ValuePost, ValuePrepend, ValuePut, ValueResponse, ValueTtlResponse, VALID_KEY,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+/// Operation applied as a part of a transaction.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionOperation {
+    Append {
+        key: String,
+        value: ValueAppend,
+    },
+    Create {
+        value: ValuePost,
+    },
+    Decrement {
+        key: String,
+        value: ValueDecrement,
+    },
+    Delete {
+        expected_version: Option<u64>,
+        key: String,
+    },
+    Expire {
+        key: String,
+        value: ValueExpire,
+    },
+    Increment {
+        key: String,
+        value: ValueIncrement,
+    },
+    Persist {
+        expected_version: Option<u64>,
+        key: String,
+    },
+    PopBack {
+        key: String,
+        value: ValuePopBack,
+    },
+    PopFront {
+        key: String,
+        value: ValuePopFront,
+    },
+    Prepend {
+        key: String,
+        value: ValuePrepend,
+    },
+    Update {
+        key: String,
+        value: ValuePut,
+    },
+}
+
+impl TransactionOperation {
+    /// Returns the key of the record the operation changes.
+    pub fn key(&self) -> &str {
+        match self {
+            TransactionOperation::Create { value } => &value.key,
+            TransactionOperation::Append { key, .. }
+            | TransactionOperation::Decrement { key, .. }
+            | TransactionOperation::Delete { key, .. }
+            | TransactionOperation::Expire { key, .. }
+            | TransactionOperation::Increment { key, .. }
+            | TransactionOperation::Persist { key, .. }
+            | TransactionOperation::PopBack { key, .. }
+            | TransactionOperation::PopFront { key, .. }
+            | TransactionOperation::Prepend { key, .. }
+            | TransactionOperation::Update { key, .. } => key,
+        }
+    }
+}
+
+impl Validate for TransactionOperation {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let result = match self {
+            TransactionOperation::Create { value } => return value.validate(),
+            TransactionOperation::Append { value, .. } => value.validate(),
+            TransactionOperation::Decrement { value, .. } => value.validate(),
+            TransactionOperation::Delete { .. } => Ok(()),
+            TransactionOperation::Expire { value, .. } => value.validate(),
+            TransactionOperation::Increment { value, .. } => value.validate(),
+            TransactionOperation::Persist { .. } => Ok(()),
+            TransactionOperation::PopBack { value, .. } => value.validate(),
+            TransactionOperation::PopFront { value, .. } => value.validate(),
+            TransactionOperation::Prepend { value, .. } => value.validate(),
+            TransactionOperation::Update { value, .. } => value.validate(),
+        };
+
+        if VALID_KEY.is_match(self.key()) {
+            return result;
+        }
+
+        let mut errors = result.err().unwrap_or_else(ValidationErrors::new);
+        errors.add("key", ValidationError::new("regex"));
+
+        Err(errors)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Validate)]
+pub struct TransactionPost {
+    #[validate]
+    pub operations: Vec<TransactionOperation>,
+}
+
+/// Result of a single operation of a transaction.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionResult {
+    Append(ValueResponse),
+    Create(ValueResponse),
+    Decrement(ValueResponse),
+    Delete(ValueResponse),
+    Expire(ValueTtlResponse),
+    Increment(ValueResponse),
+    Persist(ValueTtlResponse),
+    PopBack(Vec<Value>),
+    PopFront(Vec<Value>),
+    Prepend(ValueResponse),
+    Update(ValueResponse),
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct TransactionResponse {
+    pub results: Vec<TransactionResult>,
+}
@@ -1,24 +1,321 @@
-use serde::{Deserialize, Serialize};
-use std::{collections::BTreeMap, sync::RwLock};
+use crate::{
+    cursor::Cursor,
+    db::{Direction, ListFilter, Sort},
+    error::Error,
+    value_record::ValueRecord,
+    Result,
+};
+use chrono::{DateTime, Utc};
+use crossbeam_skiplist::{map, set::Entry, SkipMap, SkipSet};
+use std::{
+    collections::HashMap,
+    ops::Bound::{self, Excluded, Included, Unbounded},
+};
 use uuid::Uuid;
 
-#[derive(Debug, Deserialize, Serialize)]
+/// Indexes of the values.
+///
+/// Timestamp indexes hold `(timestamp, id)` pairs, so records sharing a timestamp
+/// get separate entries ordered by timestamp first. All indexes are lock-free skip lists,
+/// entries of a record are only changed while holding the lock of its value shard.
+#[derive(Debug)]
 pub struct ValueIndex {
-    pub created_at: RwLock<BTreeMap<i64, Uuid>>,
-    pub delete_at: RwLock<BTreeMap<i64, Uuid>>,
-    pub key: RwLock<BTreeMap<String, Uuid>>,
-    pub updated_at: RwLock<BTreeMap<i64, Uuid>>,
+    pub created_at: SkipSet<(i64, Uuid)>,
+    pub delete_at: SkipSet<(i64, Uuid)>,
+    pub key: SkipMap<String, Uuid>,
+    pub updated_at: SkipSet<(i64, Uuid)>,
 }
 
 impl ValueIndex {
     pub fn new() -> Self {
         Self {
-            created_at: RwLock::new(BTreeMap::new()),
-            delete_at: RwLock::new(BTreeMap::new()),
-            key: RwLock::new(BTreeMap::new()),
-            updated_at: RwLock::new(BTreeMap::new()),
+            created_at: SkipSet::new(),
+            delete_at: SkipSet::new(),
+            key: SkipMap::new(),
+            updated_at: SkipSet::new(),
         }
     }
+
+    /// Builds indexes for the specified values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{value_index::ValueIndex, value_record::{Value, ValueRecord}};
+    /// use chrono::{Duration, Utc};
+    /// use std::collections::HashMap;
+    /// use uuid::Uuid;
+    ///
+    /// let now = Utc::now();
+    /// let id = Uuid::new_v4();
+    /// let other_id = Uuid::new_v4();
+    /// let value_record = ValueRecord::new(id, "test_key", &Value::Integer(10), now, Some(now + Duration::seconds(10)), now, 1);
+    /// let other_value_record = ValueRecord::new(other_id, "other_test_key", &Value::Integer(20), now, Some(now + Duration::seconds(10)), now, 1);
+    /// let values = HashMap::from([(id, value_record), (other_id, other_value_record)]);
+    /// let value_index = ValueIndex::from_values(&values);
+    ///
+    /// assert_eq!(2, value_index.created_at.len());
+    /// assert_eq!(2, value_index.delete_at.len());
+    /// assert_eq!(Some(id), value_index.get_id("test_key"));
+    /// assert_eq!(2, value_index.updated_at.len());
+    /// assert!(value_index.is_consistent_with(&values));
+    /// assert!(!ValueIndex::default().is_consistent_with(&values));
+    /// ```
+    pub fn from_values(values: &HashMap<Uuid, ValueRecord>) -> Self {
+        let value_index = Self::new();
+
+        for value_record in values.values() {
+            value_index.insert(value_record);
+        }
+
+        value_index
+    }
+
+    /// Returns the id of the record with the specified key.
+    pub fn get_id(&self, key: &str) -> Option<Uuid> {
+        self.key.get(key).map(|entry| *entry.value())
+    }
+
+    /// Returns the positions and ids of the records in the order of the sort and direction,
+    /// starting right after the cursor. Keys are limited to the prefix of the filter, timestamps
+    /// of the sort to its time range, which is ignored when sorting by key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{cursor::Cursor, db::{Direction, ListFilter, Sort}, value_index::ValueIndex, value_record::{Value, ValueRecord}};
+    /// use chrono::{Duration, Utc};
+    /// use uuid::Uuid;
+    ///
+    /// let now = Utc::now();
+    /// let value_index = ValueIndex::default();
+    /// let ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+    /// value_index.insert(&ValueRecord::new(ids[0], "user:1", &Value::Integer(10), now - Duration::hours(2), None, now, 1));
+    /// value_index.insert(&ValueRecord::new(ids[1], "user:2", &Value::Integer(20), now - Duration::hours(1), None, now, 1));
+    /// value_index.insert(&ValueRecord::new(ids[2], "users", &Value::Integer(30), now, None, now, 1));
+    ///
+    /// let filter = ListFilter { prefix: Some("user:".to_string()), ..Default::default() };
+    /// let positions = value_index.positions(None, &Direction::Desc, &filter, &Sort::Key).unwrap();
+    ///
+    /// assert_eq!(vec![(Cursor::Key("user:2".to_string()), ids[1]), (Cursor::Key("user:1".to_string()), ids[0])], positions.collect::<Vec<(Cursor, Uuid)>>());
+    ///
+    /// let cursor = Cursor::Key("user:1".to_string());
+    /// let positions = value_index.positions(Some(&cursor), &Direction::Asc, &ListFilter::default(), &Sort::Key).unwrap();
+    ///
+    /// assert_eq!(vec![ids[1], ids[2]], positions.map(|(_cursor, id)| id).collect::<Vec<Uuid>>());
+    /// assert!(value_index.positions(Some(&cursor), &Direction::Asc, &ListFilter::default(), &Sort::CreatedAt).is_err());
+    ///
+    /// let filter = ListFilter { ends_at: Some(now), starts_at: Some(now - Duration::hours(1)), ..Default::default() };
+    /// let positions = value_index.positions(None, &Direction::Asc, &filter, &Sort::CreatedAt).unwrap();
+    ///
+    /// assert_eq!(vec![ids[1]], positions.map(|(_cursor, id)| id).collect::<Vec<Uuid>>());
+    /// ```
+    pub fn positions<'a>(
+        &'a self,
+        cursor: Option<&Cursor>,
+        direction: &Direction,
+        filter: &ListFilter,
+        sort: &Sort,
+    ) -> Result<Box<dyn Iterator<Item = (Cursor, Uuid)> + 'a>> {
+        let index = match sort {
+            Sort::CreatedAt => &self.created_at,
+            Sort::DeleteAt => &self.delete_at,
+            Sort::Key => {
+                let after = match cursor {
+                    None => None,
+                    Some(Cursor::Key(key)) => Some(key.clone()),
+                    Some(_cursor) => return Err(Box::new(Error::CursorParse)),
+                };
+
+                return Ok(self.key_positions(after, direction, filter.prefix.as_deref()));
+            }
+            Sort::UpdatedAt => &self.updated_at,
+        };
+
+        let after = match cursor {
+            None => None,
+            Some(Cursor::Timestamp(timestamp, id)) => Some((*timestamp, *id)),
+            Some(_cursor) => return Err(Box::new(Error::CursorParse)),
+        };
+
+        // Entries of a timestamp come after the nil id, so the range covers all of them.
+        let mut start = match filter.starts_at {
+            None => Unbounded,
+            Some(starts_at) => Included((timestamp(&starts_at), Uuid::nil())),
+        };
+        let mut end = match filter.ends_at {
+            None => Unbounded,
+            Some(ends_at) => Excluded((timestamp(&ends_at), Uuid::nil())),
+        };
+        narrow(&mut start, &mut end, direction, after);
+
+        let positions = |entry: Entry<(i64, Uuid)>| {
+            let (timestamp, id) = *entry.value();
+
+            (Cursor::Timestamp(timestamp, id), id)
+        };
+        let range = index.range((start, end));
+
+        Ok(match direction {
+            Direction::Asc => Box::new(range.map(positions)),
+            Direction::Desc => Box::new(range.rev().map(positions)),
+        })
+    }
+
+    fn key_positions<'a>(
+        &'a self,
+        after: Option<String>,
+        direction: &Direction,
+        prefix: Option<&str>,
+    ) -> Box<dyn Iterator<Item = (Cursor, Uuid)> + 'a> {
+        // Keys starting with the prefix are contiguous in the index, so they are looked up as a range.
+        let mut start = match prefix {
+            None => Unbounded,
+            Some(prefix) => Included(prefix.to_string()),
+        };
+        let mut end = match prefix.and_then(prefix_end) {
+            None => Unbounded,
+            Some(prefix_end) => Excluded(prefix_end),
+        };
+        narrow(&mut start, &mut end, direction, after);
+
+        let positions =
+            |entry: map::Entry<String, Uuid>| (Cursor::Key(entry.key().clone()), *entry.value());
+        let range = self.key.range((start, end));
+
+        match direction {
+            Direction::Asc => Box::new(range.map(positions)),
+            Direction::Desc => Box::new(range.rev().map(positions)),
+        }
+    }
+
+    /// Adds the entries of a record to all indexes.
+    pub fn insert(&self, value_record: &ValueRecord) {
+        let id = value_record.id;
+
+        self.created_at
+            .insert((timestamp(&value_record.created_at), id));
+        if let Some(delete_at) = value_record.delete_at {
+            self.delete_at.insert((timestamp(&delete_at), id));
+        }
+        self.key.insert(value_record.key.clone(), id);
+        self.updated_at
+            .insert((timestamp(&value_record.updated_at), id));
+    }
+
+    /// Removes the entries of a record from all indexes.
+    ///
+    /// The key entry is only removed when it still points at the record.
+    pub fn remove(&self, value_record: &ValueRecord) {
+        let id = value_record.id;
+
+        self.created_at
+            .remove(&(timestamp(&value_record.created_at), id));
+        if let Some(delete_at) = value_record.delete_at {
+            self.delete_at.remove(&(timestamp(&delete_at), id));
+        }
+        if let Some(entry) = self.key.get(&value_record.key) {
+            if *entry.value() == id {
+                entry.remove();
+            }
+        }
+        self.updated_at
+            .remove(&(timestamp(&value_record.updated_at), id));
+    }
+
+    /// Replaces the entries of a record with the entries of its new version.
+    ///
+    /// New entries are added before the old ones are removed, so the key never goes missing
+    /// from the index in between. The key entry is left alone when neither the key nor the id change.
+    pub fn replace(&self, original_value: &ValueRecord, value_record: &ValueRecord) {
+        let (original_id, id) = (original_value.id, value_record.id);
+
+        replace_timestamp(
+            &self.created_at,
+            (timestamp(&original_value.created_at), original_id),
+            (timestamp(&value_record.created_at), id),
+        );
+        match (original_value.delete_at, value_record.delete_at) {
+            (None, None) => {}
+            (None, Some(delete_at)) => {
+                self.delete_at.insert((timestamp(&delete_at), id));
+            }
+            (Some(original_delete_at), None) => {
+                self.delete_at
+                    .remove(&(timestamp(&original_delete_at), original_id));
+            }
+            (Some(original_delete_at), Some(delete_at)) => replace_timestamp(
+                &self.delete_at,
+                (timestamp(&original_delete_at), original_id),
+                (timestamp(&delete_at), id),
+            ),
+        }
+        if original_value.key != value_record.key || original_id != id {
+            self.key.insert(value_record.key.clone(), id);
+            if let Some(entry) = self.key.get(&original_value.key) {
+                if *entry.value() == original_id {
+                    entry.remove();
+                }
+            }
+        }
+        replace_timestamp(
+            &self.updated_at,
+            (timestamp(&original_value.updated_at), original_id),
+            (timestamp(&value_record.updated_at), id),
+        );
+    }
+
+    /// Checks that every index entry points at an existing record with a matching field.
+    pub fn is_consistent_with(&self, values: &HashMap<Uuid, ValueRecord>) -> bool {
+        let values_with_delete_at = values
+            .values()
+            .filter(|value_record| value_record.delete_at.is_some())
+            .count();
+
+        if self.created_at.len() != values.len()
+            || self.delete_at.len() != values_with_delete_at
+            || self.key.len() != values.len()
+            || self.updated_at.len() != values.len()
+        {
+            return false;
+        }
+
+        for entry in self.key.iter() {
+            match values.get(entry.value()) {
+                Some(value_record) if &value_record.key == entry.key() => {}
+                _ => return false,
+            }
+        }
+
+        for entry in self.created_at.iter() {
+            let (index_timestamp, id) = entry.value();
+            match values.get(id) {
+                Some(value_record) if timestamp(&value_record.created_at) == *index_timestamp => {}
+                _ => return false,
+            }
+        }
+
+        for entry in self.delete_at.iter() {
+            let (index_timestamp, id) = entry.value();
+            match values
+                .get(id)
+                .and_then(|value_record| value_record.delete_at)
+            {
+                Some(delete_at) if timestamp(&delete_at) == *index_timestamp => {}
+                _ => return false,
+            }
+        }
+
+        for entry in self.updated_at.iter() {
+            let (index_timestamp, id) = entry.value();
+            match values.get(id) {
+                Some(value_record) if timestamp(&value_record.updated_at) == *index_timestamp => {}
+                _ => return false,
+            }
+        }
+
+        true
+    }
 }
 
 impl Default for ValueIndex {
@@ -26,3 +323,62 @@ impl Default for ValueIndex {
         Self::new()
     }
 }
+
+/// Narrows the range to the positions following the cursor in the direction of listing.
+/// A cursor outside of the range leaves it as it is.
+fn narrow<T: Ord>(
+    start: &mut Bound<T>,
+    end: &mut Bound<T>,
+    direction: &Direction,
+    after: Option<T>,
+) {
+    match (direction, after) {
+        (Direction::Asc, Some(after)) => {
+            let is_after = match start {
+                Included(start) | Excluded(start) => *start > after,
+                Unbounded => false,
+            };
+            if !is_after {
+                *start = Excluded(after);
+            }
+        }
+        (Direction::Desc, Some(after)) => {
+            let is_before = match end {
+                Included(end) => *end < after,
+                Excluded(end) => *end <= after,
+                Unbounded => false,
+            };
+            if !is_before {
+                *end = Excluded(after);
+            }
+        }
+        (_direction, None) => {}
+    }
+}
+
+/// Returns the smallest string greater than all strings starting with the prefix.
+fn prefix_end(prefix: &str) -> Option<String> {
+    let mut chars = prefix.chars().collect::<Vec<char>>();
+
+    while let Some(c) = chars.pop() {
+        if let Some(next) = (c as u32 + 1..=char::MAX as u32).find_map(char::from_u32) {
+            chars.append(&mut vec![next]);
+
+            return Some(chars.into_iter().collect());
+        }
+    }
+
+    None
+}
+
+/// Adds the new entry of a timestamp index before removing the original one, when they differ.
+fn replace_timestamp(index: &SkipSet<(i64, Uuid)>, original: (i64, Uuid), new: (i64, Uuid)) {
+    if original != new {
+        index.insert(new);
+        index.remove(&original);
+    }
+}
+
+fn timestamp(date_time: &DateTime<Utc>) -> i64 {
+    date_time.timestamp_nanos_opt().unwrap_or_default()
+}
@@ -1,15 +1,22 @@
 use crate::error::Error;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use lazy_static::lazy_static;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::{collections::VecDeque, str::FromStr};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    str::FromStr,
+};
 use utoipa::ToSchema;
 use uuid::Uuid;
-use validator::Validate;
+use validator::{Validate, ValidationError};
+
+/// Longest time to live in seconds, which keeps expiration times within the range of the indexes.
+pub const MAX_TTL: i64 = 100 * 365 * 24 * 60 * 60;
 
 lazy_static! {
-    static ref VALID_KEY: Regex = Regex::new(r"^[a-zA-Z0-9._~!$&'()*+,;=:@/?-]+$").unwrap();
+    pub(crate) static ref VALID_KEY: Regex =
+        Regex::new(r"^[a-zA-Z0-9._~!$&'()*+,;=:@/?-]+$").unwrap();
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, ToSchema)]
@@ -19,6 +26,7 @@ pub enum Value {
     Boolean(bool),
     Integer(i64),
     Float(f64),
+    Object(BTreeMap<String, Value>),
     String(String),
 }
 
@@ -38,8 +46,15 @@ impl FromStr for Value {
             };
         }
 
+        // Objects are given as JSON, as their fields can't be told apart from the text otherwise.
+        if destination_value.is_none() && s.starts_with('{') {
+            destination_value = serde_json::from_str::<BTreeMap<String, Value>>(s)
+                .ok()
+                .map(Value::Object);
+        }
+
         if destination_value.is_none() {
-            let splitted_arguments = s.split("::").into_iter().collect::<Vec<&str>>();
+            let splitted_arguments = s.split("::").collect::<Vec<&str>>();
             if splitted_arguments.len() > 1 {
                 let mut splitted_argument_values = VecDeque::new();
                 for splitted_argument in splitted_arguments {
@@ -63,47 +78,515 @@ impl FromStr for Value {
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Validate)]
 pub struct ValueAppend {
     pub append: Value,
+    /// Create the record when it doesn't exist, starting from an empty array.
+    #[serde(default)]
+    pub create: bool,
+    /// Version the record is expected to have.
+    pub expected_version: Option<u64>,
+    /// Time to live in seconds of the record when it is created.
+    #[validate(custom = "validate_ttl")]
+    pub ttl: Option<i64>,
+}
+
+impl ValueAppend {
+    /// Appends the values to an array. Returns `None` when either value is not an array.
+    pub fn apply(self, value: Value) -> Option<Value> {
+        match (value, self.append) {
+            (Value::Array(original_value_vec), Value::Array(mut value_append_vec)) => {
+                let mut new_value = original_value_vec;
+                new_value.append(&mut value_append_vec);
+
+                Some(Value::Array(new_value))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the value and the expiration a missing record is created with, or `None` when it
+    /// shouldn't be created.
+    pub fn initial(
+        &self,
+        now: DateTime<Utc>,
+    ) -> crate::Result<Option<(Value, Option<DateTime<Utc>>)>> {
+        initial(
+            self.create,
+            self.expected_version,
+            Value::Array(VecDeque::new()),
+            self.ttl,
+            now,
+        )
+    }
+}
+
+/// Value replacing the current one only when the current one equals the expected value.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Validate)]
+pub struct ValueCompareAndSwap {
+    pub expected: Value,
+    pub new: Value,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ValueCompareAndSwapResponse {
+    pub key: String,
+    /// Whether the value was replaced.
+    pub swapped: bool,
+    /// Value of the record after the operation.
+    pub value: Value,
+    pub version: u64,
+}
+
+impl ValueCompareAndSwapResponse {
+    pub fn new(value_record: ValueRecord, swapped: bool) -> Self {
+        ValueCompareAndSwapResponse {
+            key: value_record.key,
+            swapped,
+            value: value_record.value,
+            version: value_record.version,
+        }
+    }
+}
+
+/// Copy of a record under a new key.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Validate)]
+pub struct ValueCopy {
+    /// Replace the record already stored under the new key instead of failing.
+    #[serde(default)]
+    pub overwrite: bool,
+    #[validate(regex = "VALID_KEY")]
+    pub to: String,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Validate)]
 pub struct ValueDecrement {
+    /// Create the record when it doesn't exist, starting from zero.
+    #[serde(default)]
+    pub create: bool,
     pub decrement: Option<i64>,
+    /// Version the record is expected to have.
+    pub expected_version: Option<u64>,
+    /// Time to live in seconds of the record when it is created.
+    #[validate(custom = "validate_ttl")]
+    pub ttl: Option<i64>,
+}
+
+impl ValueDecrement {
+    /// Decrements an integer. Returns `None` when the value is not an integer.
+    pub fn apply(&self, value: Value) -> Option<Value> {
+        match value {
+            Value::Integer(original_value_integer) => match self.decrement {
+                None => Some(Value::Integer(original_value_integer.saturating_sub(1))),
+                Some(decrement) => {
+                    if let Some(abs) = decrement.checked_abs() {
+                        Some(Value::Integer(original_value_integer.saturating_sub(abs)))
+                    } else {
+                        Some(Value::Integer(original_value_integer))
+                    }
+                }
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the value and the expiration a missing record is created with, or `None` when it
+    /// shouldn't be created.
+    pub fn initial(
+        &self,
+        now: DateTime<Utc>,
+    ) -> crate::Result<Option<(Value, Option<DateTime<Utc>>)>> {
+        initial(
+            self.create,
+            self.expected_version,
+            Value::Integer(0),
+            self.ttl,
+            now,
+        )
+    }
+}
+
+/// Expiration of a record, either relative or absolute. Exactly one field has to be set.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Validate)]
+#[validate(schema(function = "validate_value_expire"))]
+pub struct ValueExpire {
+    /// Version the record is expected to have.
+    pub expected_version: Option<u64>,
+    /// Absolute expiration time.
+    pub expire_at: Option<DateTime<Utc>>,
+    /// Time to live in seconds.
+    pub ttl: Option<i64>,
+    /// Time to live in milliseconds.
+    pub ttl_ms: Option<i64>,
+}
+
+impl ValueExpire {
+    /// Returns the moment of expiration counting the relative time to live from the specified moment.
+    pub fn delete_at(&self, now: DateTime<Utc>) -> crate::Result<Option<DateTime<Utc>>> {
+        delete_at(self.expire_at, self.ttl, self.ttl_ms, now)
+    }
+}
+
+fn validate_value_expire(value_expire: &ValueExpire) -> Result<(), ValidationError> {
+    if expirations(
+        value_expire.expire_at,
+        value_expire.ttl,
+        value_expire.ttl_ms,
+    ) != 1
+    {
+        return Err(ValidationError::new("expiration"));
+    }
+
+    if !is_ttl_in_range(value_expire.ttl, value_expire.ttl_ms) {
+        return Err(ValidationError::new("ttl"));
+    }
+
+    if !is_expire_at_in_range(value_expire.expire_at) {
+        return Err(ValidationError::new("expire_at"));
+    }
+
+    Ok(())
+}
+
+/// Increment of an integer field of an object. A missing field starts from zero.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Validate)]
+pub struct ValueFieldIncrement {
+    /// Version the record is expected to have.
+    pub expected_version: Option<u64>,
+    /// Amount added to the field, which can be negative. Defaults to one.
+    pub increment: Option<i64>,
+}
+
+impl ValueFieldIncrement {
+    /// Increments an integer field of an object, returning the object and the new field value.
+    /// Returns `None` when the value is not an object or the field is not an integer.
+    pub fn apply(&self, field: &str, value: Value) -> Option<(Value, Value)> {
+        match value {
+            Value::Object(mut fields) => {
+                let original_field_integer = match fields.get(field) {
+                    None => 0,
+                    Some(Value::Integer(original_field_integer)) => *original_field_integer,
+                    Some(_) => return None,
+                };
+                let field_value = Value::Integer(
+                    original_field_integer.saturating_add(self.increment.unwrap_or(1)),
+                );
+                fields.insert(field.to_string(), field_value.clone());
+
+                Some((Value::Object(fields), field_value))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// New value of a field of an object.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Validate)]
+pub struct ValueFieldPut {
+    /// Version the record is expected to have.
+    pub expected_version: Option<u64>,
+    pub value: Value,
+}
+
+impl ValueFieldPut {
+    /// Sets a field of an object, adding it when missing. Returns `None` when the value is not an object.
+    pub fn apply(&self, field: &str, value: Value) -> Option<Value> {
+        match value {
+            Value::Object(mut fields) => {
+                fields.insert(field.to_string(), self.value.clone());
+
+                Some(Value::Object(fields))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ValueFieldResponse {
+    pub field: String,
+    pub key: String,
+    pub value: Value,
+    /// Version of the record.
+    pub version: u64,
+}
+
+/// Names of the fields of an object, in order.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ValueFieldsResponse {
+    pub fields: Vec<String>,
+    pub key: String,
+    /// Version of the record.
+    pub version: u64,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Validate)]
 pub struct ValueIncrement {
+    /// Create the record when it doesn't exist, starting from zero.
+    #[serde(default)]
+    pub create: bool,
+    /// Version the record is expected to have.
+    pub expected_version: Option<u64>,
     pub increment: Option<i64>,
+    /// Time to live in seconds of the record when it is created.
+    #[validate(custom = "validate_ttl")]
+    pub ttl: Option<i64>,
 }
 
+impl ValueIncrement {
+    /// Increments an integer. Returns `None` when the value is not an integer.
+    pub fn apply(&self, value: Value) -> Option<Value> {
+        match value {
+            Value::Integer(original_value_integer) => match self.increment {
+                None => Some(Value::Integer(original_value_integer.saturating_add(1))),
+                Some(increment) => {
+                    if let Some(abs) = increment.checked_abs() {
+                        Some(Value::Integer(original_value_integer.saturating_add(abs)))
+                    } else {
+                        Some(Value::Integer(original_value_integer))
+                    }
+                }
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the value and the expiration a missing record is created with, or `None` when it
+    /// shouldn't be created.
+    pub fn initial(
+        &self,
+        now: DateTime<Utc>,
+    ) -> crate::Result<Option<(Value, Option<DateTime<Utc>>)>> {
+        initial(
+            self.create,
+            self.expected_version,
+            Value::Integer(0),
+            self.ttl,
+            now,
+        )
+    }
+}
+
+/// Page of records returned by listing.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ValueListResponse {
+    /// Cursor to continue listing from, set when more records may follow.
+    pub next_cursor: Option<String>,
+    pub values: Vec<ValueResponse>,
+}
+
+/// New record. At most one of `expire_at`, `ttl` and `ttl_ms` can be set.
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Validate)]
+#[validate(schema(function = "validate_value_post"))]
 pub struct ValuePost {
+    /// Absolute expiration time.
+    pub expire_at: Option<DateTime<Utc>>,
     #[validate(regex = "VALID_KEY")]
     pub key: String,
+    /// Time to live in seconds.
     pub ttl: Option<i64>,
+    /// Time to live in milliseconds.
+    pub ttl_ms: Option<i64>,
     pub value: Value,
 }
 
+impl ValuePost {
+    /// Returns the moment of expiration counting the relative time to live from the specified moment.
+    pub fn delete_at(&self, now: DateTime<Utc>) -> crate::Result<Option<DateTime<Utc>>> {
+        delete_at(self.expire_at, self.ttl, self.ttl_ms, now)
+    }
+}
+
+fn validate_value_post(value_post: &ValuePost) -> Result<(), ValidationError> {
+    if expirations(value_post.expire_at, value_post.ttl, value_post.ttl_ms) > 1 {
+        return Err(ValidationError::new("expiration"));
+    }
+
+    if !is_ttl_in_range(value_post.ttl, value_post.ttl_ms) {
+        return Err(ValidationError::new("ttl"));
+    }
+
+    if !is_expire_at_in_range(value_post.expire_at) {
+        return Err(ValidationError::new("expire_at"));
+    }
+
+    Ok(())
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Validate)]
 pub struct ValuePopBack {
+    /// Version the record is expected to have.
+    pub expected_version: Option<u64>,
     pub pop_back: Option<usize>,
 }
 
+impl ValuePopBack {
+    /// Pops values from the back of an array, returning the remaining array and the popped values.
+    /// Returns `None` when the value is not an array.
+    pub fn apply(&self, value: Value) -> Option<(Value, Vec<Value>)> {
+        match value {
+            Value::Array(original_value_vec) => {
+                let pop_back = self.pop_back.unwrap_or(1).min(original_value_vec.len());
+                let mut new_value = original_value_vec;
+                let mut return_values = vec![];
+
+                for _i in 1..=pop_back {
+                    let pop_value = new_value.pop_back();
+                    if let Some(pop_value) = pop_value {
+                        return_values.append(&mut vec![pop_value]);
+                    }
+                }
+
+                Some((Value::Array(new_value), return_values))
+            }
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Validate)]
 pub struct ValuePopFront {
+    /// Version the record is expected to have.
+    pub expected_version: Option<u64>,
     pub pop_front: Option<usize>,
 }
 
+impl ValuePopFront {
+    /// Pops values from the front of an array, returning the remaining array and the popped values.
+    /// Returns `None` when the value is not an array.
+    pub fn apply(&self, value: Value) -> Option<(Value, Vec<Value>)> {
+        match value {
+            Value::Array(original_value_vec) => {
+                let pop_front = self.pop_front.unwrap_or(1).min(original_value_vec.len());
+                let mut new_value = original_value_vec;
+                let mut return_values = vec![];
+
+                for _i in 1..=pop_front {
+                    let pop_value = new_value.pop_front();
+                    if let Some(pop_value) = pop_value {
+                        return_values.append(&mut vec![pop_value]);
+                    }
+                }
+
+                Some((Value::Array(new_value), return_values))
+            }
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Validate)]
 pub struct ValuePrepend {
+    /// Create the record when it doesn't exist, starting from an empty array.
+    #[serde(default)]
+    pub create: bool,
+    /// Version the record is expected to have.
+    pub expected_version: Option<u64>,
     pub prepend: Value,
+    /// Time to live in seconds of the record when it is created.
+    #[validate(custom = "validate_ttl")]
+    pub ttl: Option<i64>,
+}
+
+impl ValuePrepend {
+    /// Prepends the values to an array one by one. Returns `None` when either value is not an array.
+    pub fn apply(self, value: Value) -> Option<Value> {
+        match (value, self.prepend) {
+            (Value::Array(original_value_vec), Value::Array(value_prepend_vec)) => {
+                let mut new_value = original_value_vec;
+
+                for value_prepend_item in value_prepend_vec {
+                    new_value.push_front(value_prepend_item);
+                }
+
+                Some(Value::Array(new_value))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the value and the expiration a missing record is created with, or `None` when it
+    /// shouldn't be created.
+    pub fn initial(
+        &self,
+        now: DateTime<Utc>,
+    ) -> crate::Result<Option<(Value, Option<DateTime<Utc>>)>> {
+        initial(
+            self.create,
+            self.expected_version,
+            Value::Array(VecDeque::new()),
+            self.ttl,
+            now,
+        )
+    }
 }
 
+/// New value of a record. At most one of `expire_at`, `keep_ttl`, `ttl` and `ttl_ms` can be set.
+/// When none is set, the record no longer expires.
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Validate)]
+#[validate(schema(function = "validate_value_put"))]
 pub struct ValuePut {
+    /// Version the record is expected to have.
+    pub expected_version: Option<u64>,
+    /// Absolute expiration time.
+    pub expire_at: Option<DateTime<Utc>>,
+    /// Keep the current expiration of the record.
+    #[serde(default)]
+    pub keep_ttl: bool,
+    /// Time to live in seconds.
     pub ttl: Option<i64>,
+    /// Time to live in milliseconds.
+    pub ttl_ms: Option<i64>,
+    /// Create the record when it doesn't exist.
+    #[serde(default)]
+    pub upsert: bool,
     pub value: Value,
 }
 
+impl ValuePut {
+    /// Returns the moment of expiration counting the relative time to live from the specified moment.
+    /// The current expiration is kept when `keep_ttl` is set.
+    pub fn delete_at(
+        &self,
+        now: DateTime<Utc>,
+        current_delete_at: Option<DateTime<Utc>>,
+    ) -> crate::Result<Option<DateTime<Utc>>> {
+        if self.keep_ttl {
+            return Ok(current_delete_at);
+        }
+
+        delete_at(self.expire_at, self.ttl, self.ttl_ms, now)
+    }
+
+    /// Returns the value and the expiration a missing record is created with, or `None` when it
+    /// shouldn't be created.
+    pub fn initial(
+        &self,
+        now: DateTime<Utc>,
+    ) -> crate::Result<Option<(Value, Option<DateTime<Utc>>)>> {
+        if !self.upsert || self.expected_version.is_some() {
+            return Ok(None);
+        }
+
+        Ok(Some((self.value.clone(), self.delete_at(now, None)?)))
+    }
+}
+
+fn validate_value_put(value_put: &ValuePut) -> Result<(), ValidationError> {
+    let expirations = expirations(value_put.expire_at, value_put.ttl, value_put.ttl_ms);
+
+    if expirations > 1 || (value_put.keep_ttl && expirations > 0) {
+        return Err(ValidationError::new("expiration"));
+    }
+
+    if !is_ttl_in_range(value_put.ttl, value_put.ttl_ms) {
+        return Err(ValidationError::new("ttl"));
+    }
+
+    if !is_expire_at_in_range(value_put.expire_at) {
+        return Err(ValidationError::new("expire_at"));
+    }
+
+    Ok(())
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ValueRecord {
     pub id: Uuid,
@@ -112,6 +595,9 @@ pub struct ValueRecord {
     pub created_at: DateTime<Utc>,
     pub delete_at: Option<DateTime<Utc>>,
     pub updated_at: DateTime<Utc>,
+    /// Version increased on every change of the record.
+    #[serde(default = "default_version")]
+    pub version: u64,
 }
 
 impl ValueRecord {
@@ -122,6 +608,7 @@ impl ValueRecord {
         created_at: DateTime<Utc>,
         delete_at: Option<DateTime<Utc>>,
         updated_at: DateTime<Utc>,
+        version: u64,
     ) -> Self {
         Self {
             id,
@@ -130,14 +617,42 @@ impl ValueRecord {
             created_at,
             delete_at,
             updated_at,
+            version,
+        }
+    }
+
+    /// Checks whether the record has the expected version. Any version matches when none is expected.
+    pub fn has_version(&self, expected_version: Option<u64>) -> bool {
+        match expected_version {
+            None => true,
+            Some(expected_version) => self.version == expected_version,
+        }
+    }
+
+    /// Checks whether the time to live of the record has passed at the specified moment.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        match self.delete_at {
+            None => false,
+            Some(delete_at) => delete_at <= now,
         }
     }
 }
 
+/// New key of a record.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Validate)]
+pub struct ValueRename {
+    /// Replace the record already stored under the new key instead of failing.
+    #[serde(default)]
+    pub overwrite: bool,
+    #[validate(regex = "VALID_KEY")]
+    pub to: String,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct ValueResponse {
     pub key: String,
     pub value: Value,
+    pub version: u64,
 }
 
 impl From<ValueRecord> for ValueResponse {
@@ -145,6 +660,105 @@ impl From<ValueRecord> for ValueResponse {
         ValueResponse {
             key: db_record.key,
             value: db_record.value,
+            version: db_record.version,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ValueTtlResponse {
+    /// Absolute expiration time.
+    pub expire_at: Option<DateTime<Utc>>,
+    pub key: String,
+    /// Remaining time to live in seconds.
+    pub ttl: Option<i64>,
+    /// Remaining time to live in milliseconds.
+    pub ttl_ms: Option<i64>,
+    pub version: u64,
+}
+
+impl ValueTtlResponse {
+    /// Describes the expiration of the record with the time to live remaining at the specified moment.
+    pub fn new(value_record: &ValueRecord, now: DateTime<Utc>) -> Self {
+        ValueTtlResponse {
+            expire_at: value_record.delete_at,
+            key: value_record.key.clone(),
+            ttl: value_record
+                .delete_at
+                .map(|delete_at| (delete_at - now).num_seconds().max(0)),
+            ttl_ms: value_record
+                .delete_at
+                .map(|delete_at| (delete_at - now).num_milliseconds().max(0)),
+            version: value_record.version,
+        }
+    }
+}
+
+// Records written before versions were introduced start at the first version.
+fn default_version() -> u64 {
+    1
+}
+
+fn delete_at(
+    expire_at: Option<DateTime<Utc>>,
+    ttl: Option<i64>,
+    ttl_ms: Option<i64>,
+    now: DateTime<Utc>,
+) -> crate::Result<Option<DateTime<Utc>>> {
+    let ttl = match (expire_at, ttl, ttl_ms) {
+        (Some(expire_at), _, _) if is_expire_at_in_range(Some(expire_at)) => {
+            return Ok(Some(expire_at))
         }
+        (Some(_), _, _) => return Err(Box::new(Error::ExpiryRange)),
+        (None, Some(ttl), _) => Duration::try_seconds(ttl),
+        (None, None, Some(ttl_ms)) => Duration::try_milliseconds(ttl_ms),
+        (None, None, None) => return Ok(None),
+    };
+
+    match ttl.and_then(|ttl| now.checked_add_signed(ttl)) {
+        None => Err(Box::new(Error::ExpiryRange)),
+        Some(delete_at) => Ok(Some(delete_at)),
+    }
+}
+
+fn validate_ttl(ttl: i64) -> Result<(), ValidationError> {
+    if !is_ttl_in_range(Some(ttl), None) {
+        return Err(ValidationError::new("ttl"));
     }
+
+    Ok(())
+}
+
+fn is_expire_at_in_range(expire_at: Option<DateTime<Utc>>) -> bool {
+    expire_at.map_or(true, |expire_at| expire_at.timestamp_nanos_opt().is_some())
+}
+
+fn is_ttl_in_range(ttl: Option<i64>, ttl_ms: Option<i64>) -> bool {
+    ttl.map_or(true, |ttl| (-MAX_TTL..=MAX_TTL).contains(&ttl))
+        && ttl_ms.map_or(true, |ttl_ms| {
+            (-MAX_TTL * 1000..=MAX_TTL * 1000).contains(&ttl_ms)
+        })
+}
+
+fn expirations(expire_at: Option<DateTime<Utc>>, ttl: Option<i64>, ttl_ms: Option<i64>) -> usize {
+    [expire_at.is_some(), ttl.is_some(), ttl_ms.is_some()]
+        .iter()
+        .filter(|is_set| **is_set)
+        .count()
+}
+
+/// Returns the initial value and expiration of a record created by an operation when requested.
+/// A record is never created when a version is expected, as a missing one can't match it.
+fn initial(
+    create: bool,
+    expected_version: Option<u64>,
+    value: Value,
+    ttl: Option<i64>,
+    now: DateTime<Utc>,
+) -> crate::Result<Option<(Value, Option<DateTime<Utc>>)>> {
+    if !create || expected_version.is_some() {
+        return Ok(None);
+    }
+
+    Ok(Some((value, delete_at(None, ttl, None, now)?)))
 }
@@ -0,0 +1,254 @@
+use crate::{error::Error, value_record::ValueRecord, Result};
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap, BTreeSet, HashMap},
+    hash::{Hash, Hasher},
+    sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+use uuid::Uuid;
+
+pub const VALUE_SHARDS: usize = 64;
+
+/// Values split into shards by id, each behind its own lock,
+/// so operations on records in different shards proceed in parallel.
+#[derive(Debug)]
+pub struct ValueShards {
+    shards: Vec<RwLock<HashMap<Uuid, ValueRecord>>>,
+}
+
+impl ValueShards {
+    pub fn new() -> Self {
+        let mut shards = Vec::with_capacity(VALUE_SHARDS);
+        for _i in 0..VALUE_SHARDS {
+            shards.append(&mut vec![RwLock::new(HashMap::new())]);
+        }
+
+        Self { shards }
+    }
+
+    /// Distributes the specified values into shards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{value_record::{Value, ValueRecord}, value_shards::ValueShards};
+    /// use chrono::Utc;
+    /// use std::collections::HashMap;
+    /// use uuid::Uuid;
+    ///
+    /// let now = Utc::now();
+    /// let mut values = HashMap::new();
+    /// for i in 0..100 {
+    ///     let id = Uuid::new_v4();
+    ///     values.insert(id, ValueRecord::new(id, &format!("test_key_{i}"), &Value::Integer(i), now, None, now, 1));
+    /// }
+    /// let id = *values.keys().next().unwrap();
+    /// let value_shards = ValueShards::from_values(values);
+    ///
+    /// assert_eq!(100, value_shards.len().unwrap());
+    /// assert!(value_shards.get(&id).unwrap().is_some());
+    /// assert!(value_shards.get(&Uuid::new_v4()).unwrap().is_none());
+    /// ```
+    pub fn from_values(values: HashMap<Uuid, ValueRecord>) -> Self {
+        let mut shards = (0..VALUE_SHARDS)
+            .map(|_i| HashMap::new())
+            .collect::<Vec<HashMap<Uuid, ValueRecord>>>();
+
+        for (id, value_record) in values {
+            shards[shard_index(&id)].insert(id, value_record);
+        }
+
+        Self {
+            shards: shards.into_iter().map(RwLock::new).collect(),
+        }
+    }
+
+    /// Returns a copy of the record with the specified id.
+    pub fn get(&self, id: &Uuid) -> Result<Option<ValueRecord>> {
+        let values = self.shard(id).read().map_err(|_| Error::Lock)?;
+
+        Ok(values.get(id).cloned())
+    }
+
+    /// Returns copies of the records with the specified ids. Read locks are taken only on the
+    /// shards holding them, once each and in the order of their indexes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{value_record::{Value, ValueRecord}, value_shards::ValueShards};
+    /// use chrono::Utc;
+    /// use std::collections::HashMap;
+    /// use uuid::Uuid;
+    ///
+    /// let now = Utc::now();
+    /// let id = Uuid::new_v4();
+    /// let value_record = ValueRecord::new(id, "test_key", &Value::Integer(10), now, None, now, 1);
+    /// let value_shards = ValueShards::from_values(HashMap::from([(id, value_record)]));
+    /// let values = value_shards.get_many(&[id, Uuid::new_v4()]).unwrap();
+    ///
+    /// assert_eq!(1, values.len());
+    /// assert_eq!(Value::Integer(10), values[&id].value);
+    /// ```
+    pub fn get_many(&self, ids: &[Uuid]) -> Result<HashMap<Uuid, ValueRecord>> {
+        let shard_indexes = ids.iter().map(shard_index).collect::<BTreeSet<usize>>();
+        let mut shards = BTreeMap::new();
+
+        for shard_index in shard_indexes {
+            let shard = self.shards[shard_index].read().map_err(|_| Error::Lock)?;
+            shards.insert(shard_index, shard);
+        }
+
+        let mut values = HashMap::with_capacity(ids.len());
+
+        for id in ids {
+            if let Some(value_record) = shards[&shard_index(id)].get(id) {
+                values.insert(*id, value_record.clone());
+            }
+        }
+
+        Ok(values)
+    }
+
+    pub fn is_empty(&self) -> Result<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    pub fn len(&self) -> Result<usize> {
+        let mut len = 0;
+
+        for shard in &self.shards {
+            len += shard.read().map_err(|_| Error::Lock)?.len();
+        }
+
+        Ok(len)
+    }
+
+    /// Takes read locks on all shards. Used for saving a consistent snapshot.
+    pub fn read_all(&self) -> Result<Vec<RwLockReadGuard<'_, HashMap<Uuid, ValueRecord>>>> {
+        let mut shards = Vec::with_capacity(self.shards.len());
+
+        for shard in &self.shards {
+            shards.append(&mut vec![shard.read().map_err(|_| Error::Lock)?]);
+        }
+
+        Ok(shards)
+    }
+
+    /// Returns the shard holding the record with the specified id.
+    pub fn shard(&self, id: &Uuid) -> &RwLock<HashMap<Uuid, ValueRecord>> {
+        &self.shards[shard_index(id)]
+    }
+
+    /// Takes write locks on the specified shards in the order of their indexes, so writers
+    /// locking several shards don't deadlock each other. Used for applying a batch atomically.
+    pub fn write_many(&self, shard_indexes: &BTreeSet<usize>) -> Result<ValueShardsWriteGuard<'_>> {
+        let mut shards = BTreeMap::new();
+
+        for shard_index in shard_indexes {
+            let shard = self.shards[*shard_index].write().map_err(|_| Error::Lock)?;
+            shards.insert(*shard_index, shard);
+        }
+
+        Ok(ValueShardsWriteGuard { shards })
+    }
+}
+
+impl Default for ValueShards {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Write locks on a set of shards, giving access to the records in them by their ids.
+///
+/// # Examples
+///
+/// ```
+/// use alex_db_lib::{value_record::{Value, ValueRecord}, value_shards::{key_shard_index, new_id, ValueShards}};
+/// use chrono::Utc;
+/// use std::collections::BTreeSet;
+/// use uuid::Uuid;
+///
+/// let value_shards = ValueShards::new();
+/// let now = Utc::now();
+/// let id = new_id("test_key");
+///
+/// let mut values = value_shards.write_many(&BTreeSet::from([key_shard_index("test_key")])).unwrap();
+/// values.insert(id, ValueRecord::new(id, "test_key", &Value::Integer(10), now, None, now, 1)).unwrap();
+///
+/// assert!(values.is_locked(&id));
+/// assert!(values.get(&id).is_some());
+/// assert!(values.remove(&id).unwrap().is_some());
+/// assert!(values.get(&id).is_none());
+///
+/// let other_id = Uuid::from_u128(id.as_u128() ^ 1);
+///
+/// assert!(!values.is_locked(&other_id));
+/// assert!(values.insert(other_id, ValueRecord::new(other_id, "other_key", &Value::Integer(20), now, None, now, 1)).is_err());
+/// ```
+#[derive(Debug)]
+pub struct ValueShardsWriteGuard<'a> {
+    shards: BTreeMap<usize, RwLockWriteGuard<'a, HashMap<Uuid, ValueRecord>>>,
+}
+
+impl ValueShardsWriteGuard<'_> {
+    pub fn get(&self, id: &Uuid) -> Option<&ValueRecord> {
+        self.shards
+            .get(&shard_index(id))
+            .and_then(|shard| shard.get(id))
+    }
+
+    pub fn insert(&mut self, id: Uuid, value_record: ValueRecord) -> Result<Option<ValueRecord>> {
+        Ok(self.locked_shard(&id)?.insert(id, value_record))
+    }
+
+    /// Checks that the shard of the record with the specified id is locked.
+    pub fn is_locked(&self, id: &Uuid) -> bool {
+        self.shards.contains_key(&shard_index(id))
+    }
+
+    pub fn remove(&mut self, id: &Uuid) -> Result<Option<ValueRecord>> {
+        Ok(self.locked_shard(id)?.remove(id))
+    }
+
+    fn locked_shard(&mut self, id: &Uuid) -> Result<&mut HashMap<Uuid, ValueRecord>> {
+        match self.shards.get_mut(&shard_index(id)) {
+            None => Err(Box::new(Error::Lock)),
+            Some(shard) => Ok(shard),
+        }
+    }
+}
+
+/// Returns the index of the shard new records with the specified key are created in.
+pub fn key_shard_index(key: &str) -> usize {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+
+    (hasher.finish() % VALUE_SHARDS as u64) as usize
+}
+
+/// Returns a random id for a new record with the specified key, which puts the record in the
+/// shard of its key. Holding the lock of that shard keeps the key from being created meanwhile.
+///
+/// # Examples
+///
+/// ```
+/// use alex_db_lib::value_shards::{key_shard_index, new_id, shard_index};
+///
+/// let id = new_id("test_key");
+///
+/// assert_eq!(key_shard_index("test_key"), shard_index(&id));
+/// assert_eq!(Some(uuid::Version::Random), id.get_version());
+/// assert_ne!(id, new_id("test_key"));
+/// ```
+pub fn new_id(key: &str) -> Uuid {
+    // Only the last bits of the random part are replaced, so the id remains a valid random UUID.
+    let id = Uuid::new_v4().as_u128();
+
+    Uuid::from_u128(id - id % VALUE_SHARDS as u128 + key_shard_index(key) as u128)
+}
+
+/// Returns the index of the shard holding the record with the specified id.
+pub fn shard_index(id: &Uuid) -> usize {
+    (id.as_u128() % VALUE_SHARDS as u128) as usize
+}
@@ -0,0 +1,264 @@
+use crate::{
+    encryption::{decrypt_with_any, EncryptionKey},
+    error::Error,
+    storage::StorageBackend,
+    value_record::ValueRecord,
+    Result,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+use uuid::Uuid;
+
+const WAL_ENTRY_ENCRYPTED: u8 = 1;
+const WAL_ENTRY_HEADER_LEN: usize = 8;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum WalEntry {
+    Delete(Uuid),
+    /// Entries of a transaction, written at once so they are replayed all or none.
+    Transaction(Vec<WalEntry>),
+    Upsert(ValueRecord),
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WalSyncPolicy {
+    Always,
+    EveryMs(u64),
+    Never,
+}
+
+impl Default for WalSyncPolicy {
+    fn default() -> Self {
+        WalSyncPolicy::EveryMs(1000)
+    }
+}
+
+impl fmt::Display for WalSyncPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WalSyncPolicy::Always => write!(f, "always"),
+            WalSyncPolicy::EveryMs(ms) => write!(f, "{ms}"),
+            WalSyncPolicy::Never => write!(f, "never"),
+        }
+    }
+}
+
+impl FromStr for WalSyncPolicy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "always" => Ok(WalSyncPolicy::Always),
+            "never" => Ok(WalSyncPolicy::Never),
+            _ => match s.parse::<u64>() {
+                Err(_) => Err(Error::ValueParse),
+                Ok(ms) => Ok(WalSyncPolicy::EveryMs(ms)),
+            },
+        }
+    }
+}
+
+/// Append-only log of the writes applied since the last snapshot.
+///
+/// Every entry is framed as `[payload length: u32 LE][crc32: u32 LE][payload]`,
+/// so a torn write at the end of the log is detected and dropped on open.
+/// Encrypted payloads start with a marker byte followed by the nonce and the ciphertext,
+/// plain payloads are JSON.
+#[derive(Debug)]
+pub struct Wal {
+    encryption_key: Option<EncryptionKey>,
+    storage: Arc<dyn StorageBackend>,
+    sync_policy: WalSyncPolicy,
+    sync_state: Mutex<WalSyncState>,
+}
+
+#[derive(Debug)]
+struct WalSyncState {
+    synced_at: Instant,
+    /// Set when entries were appended without being synced.
+    unsynced: bool,
+}
+
+impl Wal {
+    /// Opens the log kept by the specified storage backend.
+    ///
+    /// Returns the log ready for appending together with the entries
+    /// that were already stored in it. A corrupted or truncated tail is cut off.
+    /// Encrypted entries are decrypted with the first of the specified keys that fits
+    /// and new entries are encrypted with the encryption key when it is specified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alex_db_lib::{encryption::EncryptionKey, storage::{MemoryStorage, StorageBackend}, value_record::{Value, ValueRecord}, wal::{Wal, WalEntry, WalSyncPolicy}};
+    /// use chrono::Utc;
+    /// use std::sync::Arc;
+    /// use uuid::Uuid;
+    ///
+    /// let storage = Arc::new(MemoryStorage::default());
+    ///
+    /// let (wal, entries) = Wal::open(storage.clone(), WalSyncPolicy::Always, None, &[]).unwrap();
+    ///
+    /// assert!(entries.is_empty());
+    ///
+    /// let now = Utc::now();
+    /// let value_record = ValueRecord::new(Uuid::new_v4(), "test_key", &Value::Integer(10), now, None, now, 1);
+    /// wal.append(&WalEntry::Upsert(value_record)).unwrap();
+    /// wal.append(&WalEntry::Delete(Uuid::new_v4())).unwrap();
+    /// storage.append_wal(&[1, 2, 3], false).unwrap();
+    ///
+    /// let key = EncryptionKey::generate();
+    /// let (wal, entries) = Wal::open(storage.clone(), WalSyncPolicy::Always, Some(key.clone()), &[key.clone()]).unwrap();
+    ///
+    /// assert_eq!(2, entries.len());
+    ///
+    /// wal.append(&WalEntry::Delete(Uuid::new_v4())).unwrap();
+    ///
+    /// assert!(Wal::open(storage.clone(), WalSyncPolicy::Always, None, &[]).is_err());
+    ///
+    /// let (wal, entries) = Wal::open(storage.clone(), WalSyncPolicy::Always, None, &[key]).unwrap();
+    ///
+    /// assert_eq!(3, entries.len());
+    ///
+    /// wal.truncate().unwrap();
+    ///
+    /// let (_wal, entries) = Wal::open(storage.clone(), WalSyncPolicy::Always, None, &[]).unwrap();
+    ///
+    /// assert!(entries.is_empty());
+    /// assert!(storage.wal().unwrap().is_empty());
+    /// ```
+    pub fn open(
+        storage: Arc<dyn StorageBackend>,
+        sync_policy: WalSyncPolicy,
+        encryption_key: Option<EncryptionKey>,
+        decryption_keys: &[EncryptionKey],
+    ) -> Result<(Self, Vec<WalEntry>)> {
+        let buffer = storage.load_wal()?;
+
+        let mut entries = vec![];
+        let mut position = 0;
+
+        while buffer.len() >= position + WAL_ENTRY_HEADER_LEN {
+            let len = u32::from_le_bytes(buffer[position..position + 4].try_into()?) as usize;
+            let checksum = u32::from_le_bytes(buffer[position + 4..position + 8].try_into()?);
+            let start = position + WAL_ENTRY_HEADER_LEN;
+
+            if buffer.len() < start + len {
+                break;
+            }
+
+            let payload = &buffer[start..start + len];
+            if crc32fast::hash(payload) != checksum {
+                break;
+            }
+
+            let entry = if payload.first() == Some(&WAL_ENTRY_ENCRYPTED) {
+                let decrypted =
+                    decrypt_with_any(decryption_keys, &payload[1..], &[WAL_ENTRY_ENCRYPTED])?;
+                serde_json::from_slice::<WalEntry>(&decrypted)
+            } else {
+                serde_json::from_slice::<WalEntry>(payload)
+            };
+
+            match entry {
+                Err(_) => break,
+                Ok(entry) => entries.append(&mut vec![entry]),
+            }
+
+            position = start + len;
+        }
+
+        if position < buffer.len() {
+            storage.truncate_wal(position as u64)?;
+        }
+
+        let wal = Self {
+            encryption_key,
+            storage,
+            sync_policy,
+            sync_state: Mutex::new(WalSyncState {
+                synced_at: Instant::now(),
+                unsynced: false,
+            }),
+        };
+
+        Ok((wal, entries))
+    }
+
+    /// Appends an entry to the log and syncs it according to the sync policy.
+    pub fn append(&self, entry: &WalEntry) -> Result<()> {
+        let serialized = serde_json::to_vec(entry)?;
+        let payload = match &self.encryption_key {
+            None => serialized,
+            Some(encryption_key) => {
+                let mut payload = vec![WAL_ENTRY_ENCRYPTED];
+                payload.extend(encryption_key.encrypt(&serialized, &[WAL_ENTRY_ENCRYPTED])?);
+                payload
+            }
+        };
+        let len = u32::try_from(payload.len())?;
+
+        let mut frame = Vec::with_capacity(WAL_ENTRY_HEADER_LEN + payload.len());
+        frame.extend_from_slice(&len.to_le_bytes());
+        frame.extend_from_slice(&crc32fast::hash(&payload).to_le_bytes());
+        frame.extend_from_slice(&payload);
+
+        let mut sync_state = self.sync_state.lock().map_err(|_| Error::Lock)?;
+
+        let sync = match self.sync_policy {
+            WalSyncPolicy::Always => true,
+            WalSyncPolicy::EveryMs(ms) => {
+                sync_state.synced_at.elapsed().as_millis() >= u128::from(ms)
+            }
+            WalSyncPolicy::Never => false,
+        };
+
+        self.storage.append_wal(&frame, sync)?;
+
+        if sync {
+            sync_state.synced_at = Instant::now();
+            sync_state.unsynced = false;
+        } else {
+            sync_state.unsynced = true;
+        }
+
+        Ok(())
+    }
+
+    /// Syncs the entries appended since the last sync once the interval of the `EveryMs` policy
+    /// has passed. Called periodically, so the last entries before a quiet period get synced too.
+    pub fn sync(&self) -> Result<()> {
+        let mut sync_state = self.sync_state.lock().map_err(|_| Error::Lock)?;
+
+        let sync = match self.sync_policy {
+            WalSyncPolicy::EveryMs(ms) => {
+                sync_state.unsynced && sync_state.synced_at.elapsed().as_millis() >= u128::from(ms)
+            }
+            WalSyncPolicy::Always | WalSyncPolicy::Never => false,
+        };
+
+        if sync {
+            self.storage.sync_wal()?;
+            sync_state.synced_at = Instant::now();
+            sync_state.unsynced = false;
+        }
+
+        Ok(())
+    }
+
+    /// Removes all entries from the log. Called once a snapshot containing them has been saved.
+    pub fn truncate(&self) -> Result<()> {
+        let mut sync_state = self.sync_state.lock().map_err(|_| Error::Lock)?;
+        self.storage.truncate_wal(0)?;
+        sync_state.synced_at = Instant::now();
+        sync_state.unsynced = false;
+
+        Ok(())
+    }
+}
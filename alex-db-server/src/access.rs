@@ -1,5 +1,5 @@
 use crate::error::AppError;
-use alex_db_lib::db::Db;
+use alex_db_lib::keyspaces::Keyspaces;
 use axum::{
     async_trait,
     extract::{FromRef, FromRequestParts},
@@ -31,13 +31,13 @@ impl Access {
 #[async_trait]
 impl<S> FromRequestParts<S> for Access
 where
-    Arc<Db>: FromRef<S>,
+    Arc<Keyspaces>: FromRef<S>,
     S: Send + Sync,
 {
     type Rejection = AppError;
 
     async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
-        let db = Arc::from_ref(state);
+        let db = Arc::<Keyspaces>::from_ref(state).default_keyspace();
 
         let token_header = parts.headers.get("X-Auth-Token");
 
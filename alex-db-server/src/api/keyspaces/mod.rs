@@ -0,0 +1,114 @@
+use crate::{access::Access, error::AppError};
+use alex_db_lib::keyspaces::{KeyspacePost, KeyspaceResponse, Keyspaces, DEFAULT_KEYSPACE};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use std::sync::Arc;
+use validator::Validate;
+
+mod test;
+
+#[axum_macros::debug_handler]
+#[utoipa::path(
+    post,
+    path = "/keyspaces",
+    request_body = KeyspacePost,
+    responses(
+        (status = 201, description = "Keyspace created.", body = KeyspaceResponse),
+        (status = 400, description = "Invalid request.", body = ResponseError),
+        (status = 401, description = "Unauthorized request.", body = ResponseError),
+        (status = 409, description = "Conflicting request.", body = ResponseError),
+    ),
+    security(
+        (),
+        ("api_key" = [])
+    )
+)]
+pub async fn create(
+    access: Access,
+    State(keyspaces): State<Arc<Keyspaces>>,
+    Json(input): Json<KeyspacePost>,
+) -> Result<impl IntoResponse, AppError> {
+    if !access.granted() {
+        return Err(AppError::Unauthorized);
+    }
+
+    input.validate()?;
+
+    keyspaces
+        .try_create(&input.name)?
+        .ok_or(AppError::Conflict)?;
+
+    let keyspace = KeyspaceResponse { name: input.name };
+
+    Ok((StatusCode::CREATED, Json(keyspace)).into_response())
+}
+
+#[axum_macros::debug_handler]
+#[utoipa::path(
+    delete,
+    params(
+        ("name" = String, Path, description = "Keyspace name.")
+    ),
+    path = "/keyspaces/:name",
+    responses(
+        (status = 204, description = "Keyspace dropped."),
+        (status = 401, description = "Unauthorized request.", body = ResponseError),
+        (status = 404, description = "Keyspace not found by name.", body = ResponseError),
+        (status = 409, description = "Default keyspace can't be dropped.", body = ResponseError),
+    ),
+    security(
+        (),
+        ("api_key" = [])
+    )
+)]
+pub async fn delete(
+    access: Access,
+    State(keyspaces): State<Arc<Keyspaces>>,
+    Path(name): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    if !access.granted() {
+        return Err(AppError::Unauthorized);
+    }
+
+    if name == DEFAULT_KEYSPACE {
+        return Err(AppError::Conflict);
+    }
+
+    keyspaces.try_drop(&name)?.ok_or(AppError::NotFound)?;
+
+    Ok((StatusCode::NO_CONTENT, ()).into_response())
+}
+
+#[axum_macros::debug_handler]
+#[utoipa::path(
+    get,
+    path = "/keyspaces",
+    responses(
+        (status = 200, description = "List of keyspaces.", body = [KeyspaceResponse]),
+        (status = 401, description = "Unauthorized request.", body = ResponseError),
+    ),
+    security(
+        (),
+        ("api_key" = [])
+    )
+)]
+pub async fn list(
+    access: Access,
+    State(keyspaces): State<Arc<Keyspaces>>,
+) -> Result<impl IntoResponse, AppError> {
+    if !access.granted() {
+        return Err(AppError::Unauthorized);
+    }
+
+    let keyspaces = keyspaces
+        .list()?
+        .into_iter()
+        .map(|name| KeyspaceResponse { name })
+        .collect::<Vec<KeyspaceResponse>>();
+
+    Ok((StatusCode::OK, Json(keyspaces)).into_response())
+}
@@ -0,0 +1,466 @@
+#[cfg(test)]
+mod tests {
+    use crate::{app, config::Config};
+    use alex_db_lib::{
+        config::Config as DbConfig, keyspaces::KeyspaceResponse, value_record::ValueResponse,
+    };
+    use axum::{
+        body::Body,
+        http::{self, Request, StatusCode},
+    };
+    use fake::{
+        faker::lorem::en::{Paragraph, Word},
+        Fake,
+    };
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn create_201() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let name = Word().fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/keyspaces")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "name": &name
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: KeyspaceResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.name, name);
+    }
+
+    #[tokio::test]
+    async fn create_400() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/keyspaces")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "name": "../keyspace"
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn create_401() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let name = Word().fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/keyspaces")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "name": &name
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn create_409() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+        let name = Word().fake::<String>();
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/keyspaces")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "name": &name
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/keyspaces")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "name": &name
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn delete_204() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+        let cloned_router2 = router.clone();
+        let name = Word().fake::<String>();
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/keyspaces")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "name": &name
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router2
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::DELETE)
+                    .uri(format!("/keyspaces/{name}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/keyspaces/{name}/values"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn delete_404() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let name = Word().fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::DELETE)
+                    .uri(format!("/keyspaces/{name}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn delete_409() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::DELETE)
+                    .uri("/keyspaces/default")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn list_200() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+        let name = Word().fake::<String>();
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/keyspaces")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "name": &name
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/keyspaces")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<KeyspaceResponse> = serde_json::from_slice(&body).unwrap();
+        let names = body
+            .into_iter()
+            .map(|keyspace| keyspace.name)
+            .collect::<Vec<String>>();
+
+        assert_eq!(names, vec!["default".to_string(), name]);
+    }
+
+    #[tokio::test]
+    async fn values_201_keyspace() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+        let cloned_router2 = router.clone();
+        let cloned_router3 = router.clone();
+        let cloned_router4 = router.clone();
+        let name = Word().fake::<String>();
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/keyspaces")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "name": &name
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router2
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri(format!("/keyspaces/{name}/values"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router3
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/keyspaces/{name}/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+
+        let response = cloned_router4
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/keyspaces/default/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn values_404_keyspace() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let name = Word().fake::<String>();
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri(format!("/keyspaces/{name}/values"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{
+    access::Access,
+    error::AppError,
+    metrics::{KeyspaceStats, Metrics},
+};
+use alex_db_lib::keyspaces::Keyspaces;
+use axum::{
+    extract::State,
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Extension,
+};
+use std::sync::Arc;
+
+mod test;
+
+#[axum_macros::debug_handler]
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    responses(
+        (status = 200, description = "Metrics in Prometheus text format.", body = String, content_type = "text/plain"),
+        (status = 401, description = "Unauthorized request.", body = ResponseError),
+    ),
+    security(
+        (),
+        ("api_key" = [])
+    )
+)]
+pub async fn list(
+    access: Access,
+    State(keyspaces): State<Arc<Keyspaces>>,
+    Extension(metrics): Extension<Arc<Metrics>>,
+) -> Result<impl IntoResponse, AppError> {
+    if metrics.require_api_key && !access.granted() {
+        return Err(AppError::Unauthorized);
+    }
+
+    let mut keyspace_stats = vec![];
+
+    for name in keyspaces.list()? {
+        if let Some(db) = keyspaces.get(&name)? {
+            keyspace_stats.append(&mut vec![KeyspaceStats {
+                keys: db.values_indexes.key.len(),
+                keys_with_ttl: db.values_indexes.delete_at.len(),
+                name,
+                stat_record: db.get_stats()?,
+            }]);
+        }
+    }
+
+    let output = metrics.render(&keyspace_stats)?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        output,
+    )
+        .into_response())
+}
@@ -0,0 +1,182 @@
+#[cfg(test)]
+mod tests {
+    use crate::{app, config::Config};
+    use alex_db_lib::{
+        config::Config as DbConfig,
+        value_record::{Value, ValuePost},
+    };
+    use axum::{
+        body::Body,
+        http::{self, Request, StatusCode},
+    };
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn list_200() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let db = app.keyspaces.default_keyspace();
+        let value_post = ValuePost {
+            expire_at: None,
+            key: "test_key".to_string(),
+            ttl: Some(100),
+            ttl_ms: None,
+            value: Value::Integer(10),
+        };
+        db.try_create(value_post).unwrap();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/values/test_key")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/metrics")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(body.contains("alex_db_requests_total{keyspace=\"default\"} 2\n"));
+        assert!(
+            body.contains("alex_db_operations_total{keyspace=\"default\",operation=\"read\"} 1\n")
+        );
+        assert!(body.contains("alex_db_keys{keyspace=\"default\"} 1\n"));
+        assert!(body.contains("alex_db_keys_with_ttl{keyspace=\"default\"} 1\n"));
+        assert!(body.contains(
+            "alex_db_http_request_duration_seconds_count{method=\"GET\",route=\"/values/:key\"} 1\n"
+        ));
+    }
+
+    #[tokio::test]
+    async fn list_200_api_key_not_required() {
+        let db_config = DbConfig::default();
+        let mut config = Config::new(db_config, 10240);
+        config.metrics_require_api_key = false;
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/metrics")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn list_200_authentication() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/metrics")
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn list_200_keyspaces() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let value_post = ValuePost {
+            expire_at: None,
+            key: "test_key".to_string(),
+            ttl: None,
+            ttl_ms: None,
+            value: Value::Integer(10),
+        };
+        let db = app.keyspaces.try_create("staging").unwrap().unwrap();
+        db.try_create(value_post).unwrap();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/metrics")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(body.contains("alex_db_keys{keyspace=\"default\"} 0\n"));
+        assert!(body.contains("alex_db_keys{keyspace=\"staging\"} 1\n"));
+        assert!(body
+            .contains("alex_db_operations_total{keyspace=\"staging\",operation=\"create\"} 1\n"));
+    }
+
+    #[tokio::test]
+    async fn list_401() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/metrics")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+}
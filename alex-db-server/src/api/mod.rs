@@ -1,17 +1,22 @@
-use crate::error::ResponseError;
+use crate::{error::ResponseError, metrics::Metrics};
 use alex_db_lib::{
-    db::Db,
-    stat_record::StatRecord,
+    batch::{BatchKeys, BatchResponse, BatchResult, BatchSetPost, BatchStatus},
+    keyspaces::{KeyspacePost, KeyspaceResponse, Keyspaces},
+    stat_record::{ErrorStatRecord, OperationStatRecord, StatRecord},
+    transaction::{TransactionOperation, TransactionPost, TransactionResponse, TransactionResult},
     value_record::{
-        Value, ValueAppend, ValueDecrement, ValueIncrement, ValuePopBack, ValuePopFront, ValuePost,
-        ValuePrepend, ValuePut, ValueResponse,
+        Value, ValueAppend, ValueCompareAndSwap, ValueCompareAndSwapResponse, ValueCopy,
+        ValueDecrement, ValueExpire, ValueFieldIncrement, ValueFieldPut, ValueFieldResponse,
+        ValueFieldsResponse, ValueIncrement, ValuePopBack, ValuePopFront, ValuePost, ValuePrepend,
+        ValuePut, ValueRename, ValueResponse, ValueTtlResponse,
     },
 };
 use axum::{
     error_handling::HandleErrorLayer,
     http::StatusCode,
-    routing::{delete, get, put},
-    Router,
+    middleware,
+    routing::{delete, get, post, put},
+    Extension, Router,
 };
 use std::{sync::Arc, time::Duration};
 use tower::{BoxError, ServiceBuilder};
@@ -22,45 +27,93 @@ use utoipa::{
 };
 use utoipa_swagger_ui::SwaggerUi;
 
+mod keyspaces;
+mod metrics;
 mod stats;
+mod transactions;
 mod values;
 
-pub async fn router(db: Arc<Db>) -> Router {
+pub async fn router(keyspaces: Arc<Keyspaces>, metrics: Arc<Metrics>) -> Router {
     #[derive(OpenApi)]
     #[openapi(
         components(
             schemas(
+                BatchKeys,
+                BatchResponse,
+                BatchResult,
+                BatchSetPost,
+                BatchStatus,
+                ErrorStatRecord,
+                KeyspacePost,
+                KeyspaceResponse,
+                OperationStatRecord,
                 ResponseError,
                 StatRecord,
+                TransactionOperation,
+                TransactionPost,
+                TransactionResponse,
+                TransactionResult,
                 Value,
                 ValueAppend,
+                ValueCompareAndSwap,
+                ValueCompareAndSwapResponse,
+                ValueCopy,
                 ValueDecrement,
+                ValueExpire,
+                ValueFieldIncrement,
+                ValueFieldPut,
+                ValueFieldResponse,
+                ValueFieldsResponse,
                 ValueIncrement,
                 ValuePopBack,
                 ValuePopFront,
                 ValuePost,
                 ValuePrepend,
                 ValuePut,
+                ValueRename,
                 ValueResponse,
+                ValueTtlResponse,
             )
         ),
         modifiers(&SecurityAddon),
         paths(
+            keyspaces::create,
+            keyspaces::delete,
+            keyspaces::list,
+            metrics::list,
             stats::list,
+            transactions::create,
             values::append,
+            values::batch_delete,
+            values::batch_get,
+            values::batch_set,
+            values::compare_and_swap,
+            values::copy,
             values::create,
             values::decrement,
             values::delete,
+            values::delete_field,
+            values::delete_ttl,
             values::increment,
+            values::increment_field,
             values::list,
+            values::list_fields,
             values::pop_back,
             values::pop_front,
             values::prepend,
             values::read,
+            values::read_field,
+            values::read_ttl,
+            values::rename,
             values::update,
+            values::update_field,
+            values::update_ttl,
         ),
         tags(
+            (name = "keyspaces", description = "Keyspaces management API."),
+            (name = "metrics", description = "Metrics API."),
             (name = "stats", description = "Stats API."),
+            (name = "transactions", description = "Transactions API."),
             (name = "values", description = "Values management API."),
         )
     )]
@@ -79,20 +132,58 @@ pub async fn router(db: Arc<Db>) -> Router {
         }
     }
 
-    Router::new()
-        .merge(SwaggerUi::new("/swagger-ui").url("/api-doc/openapi.json", ApiDoc::openapi()))
+    // Routes operating on a single keyspace, served for the default keyspace at the root
+    // and for named keyspaces under `/keyspaces/:name`.
+    let keyspace_router = Router::new()
+        .route("/batch/values/delete", post(values::batch_delete))
+        .route("/batch/values/get", post(values::batch_get))
+        .route("/batch/values/set", post(values::batch_set))
         .route("/stats", get(stats::list))
+        .route("/transactions", post(transactions::create))
         .route("/values", get(values::list).post(values::create))
         .route(
             "/values/:key",
             delete(values::delete).get(values::read).put(values::update),
         )
         .route("/values/:key/append", put(values::append))
+        .route("/values/:key/cas", put(values::compare_and_swap))
+        .route("/values/:key/copy", put(values::copy))
         .route("/values/:key/decrement", put(values::decrement))
+        .route("/values/:key/fields", get(values::list_fields))
+        .route(
+            "/values/:key/fields/:field",
+            delete(values::delete_field)
+                .get(values::read_field)
+                .put(values::update_field),
+        )
+        .route(
+            "/values/:key/fields/:field/increment",
+            put(values::increment_field),
+        )
         .route("/values/:key/increment", put(values::increment))
         .route("/values/:key/pop-back", put(values::pop_back))
         .route("/values/:key/pop-front", put(values::pop_front))
         .route("/values/:key/prepend", put(values::prepend))
+        .route("/values/:key/rename", put(values::rename))
+        .route(
+            "/values/:key/ttl",
+            delete(values::delete_ttl)
+                .get(values::read_ttl)
+                .put(values::update_ttl),
+        );
+
+    Router::new()
+        .merge(SwaggerUi::new("/swagger-ui").url("/api-doc/openapi.json", ApiDoc::openapi()))
+        .route("/keyspaces", get(keyspaces::list).post(keyspaces::create))
+        .route("/keyspaces/:name", delete(keyspaces::delete))
+        .nest("/keyspaces/:name", keyspace_router.clone())
+        .route("/metrics", get(metrics::list))
+        .merge(keyspace_router)
+        .route_layer(middleware::from_fn_with_state(
+            metrics.clone(),
+            crate::metrics::track,
+        ))
+        .layer(Extension(metrics))
         .layer(
             ServiceBuilder::new()
                 .layer(HandleErrorLayer::new(|error: BoxError| async move {
@@ -109,5 +200,5 @@ pub async fn router(db: Arc<Db>) -> Router {
                 .layer(TraceLayer::new_for_http())
                 .into_inner(),
         )
-        .with_state(db)
+        .with_state(keyspaces)
 }
@@ -1,11 +1,11 @@
-use crate::{access::Access, error::AppError};
-use alex_db_lib::db::Db;
-use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use crate::{access::Access, error::AppError, keyspace::Keyspace};
+use alex_db_lib::keyspaces::Keyspaces;
+use axum::{http::StatusCode, response::IntoResponse, Json};
 use std::sync::Arc;
 
 mod test;
 
-#[axum_macros::debug_handler]
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
 #[utoipa::path(
     get,
     path = "/stats",
@@ -18,10 +18,7 @@ mod test;
         ("api_key" = [])
     )
 )]
-pub async fn list(
-    access: Access,
-    State(db): State<Arc<Db>>,
-) -> Result<impl IntoResponse, AppError> {
+pub async fn list(access: Access, Keyspace(db): Keyspace) -> Result<impl IntoResponse, AppError> {
     if !access.granted() {
         return Err(AppError::Unauthorized);
     }
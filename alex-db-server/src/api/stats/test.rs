@@ -1,7 +1,11 @@
 #[cfg(test)]
 mod tests {
     use crate::{app, config::Config};
-    use alex_db_lib::{config::Config as DbConfig, stat_record::StatRecord};
+    use alex_db_lib::{
+        config::Config as DbConfig,
+        stat_record::StatRecord,
+        value_record::{Value, ValuePost},
+    };
     use axum::{
         body::Body,
         http::{self, Request, StatusCode},
@@ -72,6 +76,96 @@ mod tests {
         assert_eq!(body.writes, 0);
     }
 
+    #[tokio::test]
+    async fn list_200_operations() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let db = app.keyspaces.default_keyspace();
+        let value_post = ValuePost {
+            expire_at: None,
+            key: "test_key".to_string(),
+            ttl: None,
+            ttl_ms: None,
+            value: Value::Integer(10),
+        };
+        db.try_create(value_post.clone()).unwrap();
+        assert!(db.try_create(value_post).is_err());
+        db.try_read("test_key").unwrap();
+        db.try_read("wrong_key").unwrap();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/stats")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: StatRecord = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.reads, 1);
+        assert_eq!(body.requests, 4);
+        assert_eq!(body.writes, 1);
+        assert_eq!(body.operations.create, 2);
+        assert_eq!(body.operations.read, 2);
+        assert_eq!(body.errors.conflict, 1);
+        assert_eq!(body.errors.not_found, 1);
+    }
+
+    #[tokio::test]
+    async fn list_200_writes() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let db = app.keyspaces.default_keyspace();
+        let value_post = ValuePost {
+            expire_at: None,
+            key: "test_key".to_string(),
+            ttl: None,
+            ttl_ms: None,
+            value: Value::Integer(10),
+        };
+        db.try_create(value_post.clone()).unwrap();
+        db.try_upsert_many(vec![value_post]).unwrap();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/stats")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: StatRecord = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.writes, 2);
+    }
+
     #[tokio::test]
     async fn list_401() {
         let db_config = DbConfig::default();
@@ -0,0 +1,48 @@
+use crate::{access::Access, error::AppError, keyspace::Keyspace};
+use alex_db_lib::{
+    error::Error,
+    keyspaces::Keyspaces,
+    transaction::{TransactionPost, TransactionResponse},
+};
+use axum::{http::StatusCode, response::IntoResponse, Json};
+use std::sync::Arc;
+use validator::Validate;
+
+mod test;
+
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
+#[utoipa::path(
+    post,
+    path = "/transactions",
+    request_body = TransactionPost,
+    responses(
+        (status = 200, description = "Transaction applied.", body = TransactionResponse),
+        (status = 400, description = "Invalid request.", body = ResponseError),
+        (status = 401, description = "Unauthorized request.", body = ResponseError),
+        (status = 409, description = "Transaction aborted, nothing was changed.", body = ResponseError),
+    ),
+    security(
+        (),
+        ("api_key" = [])
+    )
+)]
+pub async fn create(
+    access: Access,
+    Keyspace(db): Keyspace,
+    Json(input): Json<TransactionPost>,
+) -> Result<impl IntoResponse, AppError> {
+    if !access.granted() {
+        return Err(AppError::Unauthorized);
+    }
+
+    input.validate()?;
+
+    let results =
+        db.try_transaction(input.operations)
+            .map_err(|e| match e.downcast_ref::<Error>() {
+                Some(error @ Error::Transaction(..)) => AppError::Transaction(error.to_string()),
+                _ => AppError::Generic(e),
+            })?;
+
+    Ok((StatusCode::OK, Json(TransactionResponse { results })).into_response())
+}
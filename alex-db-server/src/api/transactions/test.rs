@@ -0,0 +1,541 @@
+#[cfg(test)]
+mod tests {
+    use crate::{app, config::Config};
+    use alex_db_lib::{
+        config::Config as DbConfig,
+        transaction::{TransactionResponse, TransactionResult},
+        value_record::{Value, ValueResponse},
+    };
+    use axum::{
+        body::Body,
+        http::{self, Request, StatusCode},
+    };
+    use fake::{faker::lorem::en::Word, Fake};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn create_200() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+        let cloned_router2 = router.clone();
+
+        let key = format!("{}1", Word().fake::<String>());
+        let other_key = format!("{}2", Word().fake::<String>());
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": 100
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/transactions")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "operations": [
+                                {
+                                    "decrement": {
+                                        "key": &key,
+                                        "value": {
+                                            "decrement": 10
+                                        }
+                                    }
+                                },
+                                {
+                                    "create": {
+                                        "value": {
+                                            "key": &other_key,
+                                            "value": 10
+                                        }
+                                    }
+                                }
+                            ]
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: TransactionResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.results.len(), 2);
+        assert!(
+            matches!(&body.results[0], TransactionResult::Decrement(value_response) if value_response.value == Value::Integer(90))
+        );
+
+        let response = cloned_router2
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{other_key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.value, Value::Integer(10));
+    }
+
+    #[tokio::test]
+    async fn create_200_authentication() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = format!("{}1", Word().fake::<String>());
+        let other_key = format!("{}2", Word().fake::<String>());
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": 100
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/transactions")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "operations": [
+                                {
+                                    "decrement": {
+                                        "key": &key,
+                                        "value": {
+                                            "decrement": 10
+                                        }
+                                    }
+                                },
+                                {
+                                    "create": {
+                                        "value": {
+                                            "key": &other_key,
+                                            "value": 10
+                                        }
+                                    }
+                                }
+                            ]
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn create_200_concurrent_read() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+
+        let response = router
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": 100
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let transaction_key = key.clone();
+        let transactions = tokio::spawn(async move {
+            for _ in 0..500 {
+                let response = cloned_router
+                    .clone()
+                    .oneshot(
+                        Request::builder()
+                            .method(http::Method::POST)
+                            .uri("/transactions")
+                            .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                            .body(Body::from(
+                                serde_json::json!({
+                                    "operations": [
+                                        {
+                                            "increment": {
+                                                "key": &transaction_key,
+                                                "value": {}
+                                            }
+                                        }
+                                    ]
+                                })
+                                .to_string(),
+                            ))
+                            .unwrap(),
+                    )
+                    .await
+                    .unwrap();
+
+                assert_eq!(response.status(), StatusCode::OK);
+            }
+        });
+
+        while !transactions.is_finished() {
+            let response = router
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .method(http::Method::GET)
+                        .uri(format!("/values/{key}"))
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        transactions.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn create_400() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/transactions")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "operations": [
+                                {
+                                    "create": {
+                                        "value": {
+                                            "key": "invalid key",
+                                            "value": 10
+                                        }
+                                    }
+                                }
+                            ]
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn create_400_key() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/transactions")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "operations": [
+                                {
+                                    "delete": {
+                                        "key": "invalid key"
+                                    }
+                                }
+                            ]
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn create_400_ttl() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let response = router
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/transactions")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "operations": [
+                                {
+                                    "increment": {
+                                        "key": "counter",
+                                        "value": {
+                                            "create": true,
+                                            "ttl": i64::MAX
+                                        }
+                                    }
+                                }
+                            ]
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/transactions")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "operations": [
+                                {
+                                    "increment": {
+                                        "key": "counter",
+                                        "value": {
+                                            "create": true
+                                        }
+                                    }
+                                }
+                            ]
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn create_401() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let key = format!("{}1", Word().fake::<String>());
+        let other_key = format!("{}2", Word().fake::<String>());
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/transactions")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "operations": [
+                                {
+                                    "decrement": {
+                                        "key": &key,
+                                        "value": {
+                                            "decrement": 10
+                                        }
+                                    }
+                                },
+                                {
+                                    "create": {
+                                        "value": {
+                                            "key": &other_key,
+                                            "value": 10
+                                        }
+                                    }
+                                }
+                            ]
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn create_409() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+        let cloned_router2 = router.clone();
+
+        let key = format!("{}1", Word().fake::<String>());
+        let other_key = format!("{}2", Word().fake::<String>());
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": 100
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/transactions")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "operations": [
+                                {
+                                    "delete": {
+                                        "key": &key
+                                    }
+                                },
+                                {
+                                    "increment": {
+                                        "key": &other_key,
+                                        "value": {}
+                                    }
+                                }
+                            ]
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let response = cloned_router2
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
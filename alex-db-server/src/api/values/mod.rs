@@ -1,14 +1,22 @@
-use crate::{access::Access, error::AppError};
+use crate::{
+    access::Access,
+    error::AppError,
+    if_match::{entity_tag, IfMatch},
+    keyspace::Keyspace,
+};
 use alex_db_lib::{
-    db::{Db, Direction, Sort},
+    batch::{BatchKeys, BatchResponse, BatchSetPost},
+    db::{Direction, ListFilter, Sort},
+    keyspaces::Keyspaces,
     value_record::{
-        ValueAppend, ValueDecrement, ValueIncrement, ValuePopBack, ValuePopFront, ValuePost,
-        ValuePrepend, ValuePut,
+        ValueAppend, ValueCompareAndSwap, ValueCopy, ValueDecrement, ValueExpire,
+        ValueFieldIncrement, ValueFieldPut, ValueIncrement, ValuePopBack, ValuePopFront, ValuePost,
+        ValuePrepend, ValuePut, ValueRename,
     },
 };
 use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
+    extract::{Path, Query},
+    http::{header, StatusCode},
     response::IntoResponse,
     Json,
 };
@@ -19,29 +27,50 @@ use validator::Validate;
 
 mod test;
 
+/// Header holding the cursor of the next page of a list.
+pub const NEXT_CURSOR: &str = "X-Next-Cursor";
+
 #[derive(Debug, Deserialize)]
 pub struct QueryParams {
+    pub cursor: Option<String>,
     pub direction: Option<Direction>,
     pub ends_at: Option<DateTime<Utc>>,
     pub limit: Option<usize>,
     pub page: Option<usize>,
+    pub pattern: Option<String>,
+    pub prefix: Option<String>,
     pub sort: Option<Sort>,
     pub starts_at: Option<DateTime<Utc>>,
 }
 
-#[axum_macros::debug_handler]
+/// Path parameters of the routes addressing a field of a value, the keyspace name is ignored.
+#[derive(Debug, Deserialize)]
+pub struct ValueFieldPath {
+    pub field: String,
+    pub key: String,
+}
+
+/// Path parameters of the routes addressing a single value, the keyspace name is ignored.
+#[derive(Debug, Deserialize)]
+pub struct ValuePath {
+    pub key: String,
+}
+
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
 #[utoipa::path(
     put,
     params(
-        ("key" = String, Path, description = "Value key.")
+        ("key" = String, Path, description = "Value key."),
+        ("If-Match" = Option<String>, Header, description = "Expected version of the value.")
     ),
     path = "/values/:key/append",
     request_body = ValueAppend,
     responses(
-        (status = 200, description = "Value appended.", body = ValueResponse),
+        (status = 200, description = "Value appended.", body = ValueResponse, headers(("ETag" = String, description = "Version of the value."))),
         (status = 401, description = "Unauthorized request.", body = ResponseError),
         (status = 404, description = "Value not found by key.", body = ResponseError),
         (status = 409, description = "Conflicting request.", body = ResponseError),
+        (status = 412, description = "Version does not match.", body = ResponseError),
     ),
     security(
         (),
@@ -50,30 +79,227 @@ pub struct QueryParams {
 )]
 pub async fn append(
     access: Access,
-    State(db): State<Arc<Db>>,
-    Path(key): Path<String>,
-    Json(input): Json<ValueAppend>,
+    Keyspace(db): Keyspace,
+    Path(ValuePath { key }): Path<ValuePath>,
+    if_match: IfMatch,
+    Json(mut input): Json<ValueAppend>,
 ) -> Result<impl IntoResponse, AppError> {
     if !access.granted() {
         return Err(AppError::Unauthorized);
     }
 
     input.validate()?;
+    input.expected_version = if_match.expected_version(input.expected_version)?;
 
-    db.try_read(&key)?.ok_or(AppError::NotFound)?;
+    if input.initial(Utc::now())?.is_none() {
+        db.try_read(&key)?.ok_or(AppError::NotFound)?;
+    }
 
     let value = db.try_append(&key, input)?.ok_or(AppError::Conflict)?;
 
-    Ok((StatusCode::OK, Json(value)).into_response())
+    Ok((
+        StatusCode::OK,
+        [(header::ETAG, entity_tag(value.version))],
+        Json(value),
+    )
+        .into_response())
+}
+
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
+#[utoipa::path(
+    post,
+    path = "/batch/values/delete",
+    request_body = BatchKeys,
+    responses(
+        (status = 200, description = "Values deleted, results by key.", body = BatchResponse),
+        (status = 401, description = "Unauthorized request.", body = ResponseError),
+    ),
+    security(
+        (),
+        ("api_key" = [])
+    )
+)]
+pub async fn batch_delete(
+    access: Access,
+    Keyspace(db): Keyspace,
+    Json(input): Json<BatchKeys>,
+) -> Result<impl IntoResponse, AppError> {
+    if !access.granted() {
+        return Err(AppError::Unauthorized);
+    }
+
+    input.validate()?;
+
+    let results = db.try_delete_many(&input.keys)?;
+
+    Ok((StatusCode::OK, Json(BatchResponse { results })).into_response())
+}
+
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
+#[utoipa::path(
+    post,
+    path = "/batch/values/get",
+    request_body = BatchKeys,
+    responses(
+        (status = 200, description = "Values read, results by key.", body = BatchResponse),
+        (status = 401, description = "Unauthorized request.", body = ResponseError),
+    ),
+    security(
+        (),
+        ("api_key" = [])
+    )
+)]
+pub async fn batch_get(
+    access: Access,
+    Keyspace(db): Keyspace,
+    Json(input): Json<BatchKeys>,
+) -> Result<impl IntoResponse, AppError> {
+    if !access.granted() {
+        return Err(AppError::Unauthorized);
+    }
+
+    input.validate()?;
+
+    let results = db.try_read_many(&input.keys)?;
+
+    Ok((StatusCode::OK, Json(BatchResponse { results })).into_response())
+}
+
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
+#[utoipa::path(
+    post,
+    path = "/batch/values/set",
+    request_body = BatchSetPost,
+    responses(
+        (status = 200, description = "Values set, results by key.", body = BatchResponse),
+        (status = 401, description = "Unauthorized request.", body = ResponseError),
+    ),
+    security(
+        (),
+        ("api_key" = [])
+    )
+)]
+pub async fn batch_set(
+    access: Access,
+    Keyspace(db): Keyspace,
+    Json(input): Json<BatchSetPost>,
+) -> Result<impl IntoResponse, AppError> {
+    if !access.granted() {
+        return Err(AppError::Unauthorized);
+    }
+
+    input.validate()?;
+
+    let results = if input.upsert {
+        db.try_upsert_many(input.values)?
+    } else {
+        db.try_create_many(input.values)?
+    };
+
+    Ok((StatusCode::OK, Json(BatchResponse { results })).into_response())
+}
+
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
+#[utoipa::path(
+    put,
+    params(
+        ("key" = String, Path, description = "Value key.")
+    ),
+    path = "/values/:key/cas",
+    request_body = ValueCompareAndSwap,
+    responses(
+        (status = 200, description = "Value compared and swapped if equal to the expected one.", body = ValueCompareAndSwapResponse, headers(("ETag" = String, description = "Version of the value."))),
+        (status = 401, description = "Unauthorized request.", body = ResponseError),
+        (status = 404, description = "Value not found by key.", body = ResponseError),
+    ),
+    security(
+        (),
+        ("api_key" = [])
+    )
+)]
+pub async fn compare_and_swap(
+    access: Access,
+    Keyspace(db): Keyspace,
+    Path(ValuePath { key }): Path<ValuePath>,
+    Json(input): Json<ValueCompareAndSwap>,
+) -> Result<impl IntoResponse, AppError> {
+    if !access.granted() {
+        return Err(AppError::Unauthorized);
+    }
+
+    input.validate()?;
+
+    db.try_read(&key)?.ok_or(AppError::NotFound)?;
+
+    let value = db
+        .try_compare_and_swap(&key, input.expected, input.new)?
+        .ok_or(AppError::Conflict)?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::ETAG, entity_tag(value.version))],
+        Json(value),
+    )
+        .into_response())
 }
 
-#[axum_macros::debug_handler]
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
+#[utoipa::path(
+    put,
+    params(
+        ("key" = String, Path, description = "Value key.")
+    ),
+    path = "/values/:key/copy",
+    request_body = ValueCopy,
+    responses(
+        (status = 200, description = "Value copied to the new key.", body = ValueResponse, headers(("ETag" = String, description = "Version of the value."))),
+        (status = 400, description = "Invalid request.", body = ResponseError),
+        (status = 401, description = "Unauthorized request.", body = ResponseError),
+        (status = 404, description = "Value not found by key.", body = ResponseError),
+        (status = 409, description = "Value already exists under the new key.", body = ResponseError),
+    ),
+    security(
+        (),
+        ("api_key" = [])
+    )
+)]
+pub async fn copy(
+    access: Access,
+    Keyspace(db): Keyspace,
+    Path(ValuePath { key }): Path<ValuePath>,
+    Json(input): Json<ValueCopy>,
+) -> Result<impl IntoResponse, AppError> {
+    if !access.granted() {
+        return Err(AppError::Unauthorized);
+    }
+
+    input.validate()?;
+
+    db.try_read(&key)?.ok_or(AppError::NotFound)?;
+
+    if !input.overwrite && db.try_read(&input.to)?.is_some() {
+        return Err(AppError::Conflict);
+    }
+
+    let value = db
+        .try_copy(&key, &input.to, input.overwrite)?
+        .ok_or(AppError::NotFound)?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::ETAG, entity_tag(value.version))],
+        Json(value),
+    )
+        .into_response())
+}
+
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
 #[utoipa::path(
     post,
     path = "/values",
     request_body = ValuePost,
     responses(
-        (status = 201, description = "Value created.", body = ValueResponse),
+        (status = 201, description = "Value created.", body = ValueResponse, headers(("ETag" = String, description = "Version of the value."))),
         (status = 401, description = "Unauthorized request.", body = ResponseError),
         (status = 409, description = "Conflicting request.", body = ResponseError),
     ),
@@ -84,7 +310,7 @@ pub async fn append(
 )]
 pub async fn create(
     access: Access,
-    State(db): State<Arc<Db>>,
+    Keyspace(db): Keyspace,
     Json(input): Json<ValuePost>,
 ) -> Result<impl IntoResponse, AppError> {
     if !access.granted() {
@@ -99,25 +325,32 @@ pub async fn create(
         None => {
             let value = db.try_create(input)?.ok_or(AppError::Conflict)?;
 
-            Ok((StatusCode::CREATED, Json(value)).into_response())
+            Ok((
+                StatusCode::CREATED,
+                [(header::ETAG, entity_tag(value.version))],
+                Json(value),
+            )
+                .into_response())
         }
         Some(_value) => Err(AppError::Conflict),
     }
 }
 
-#[axum_macros::debug_handler]
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
 #[utoipa::path(
     put,
     params(
-        ("key" = String, Path, description = "Value key.")
+        ("key" = String, Path, description = "Value key."),
+        ("If-Match" = Option<String>, Header, description = "Expected version of the value.")
     ),
     path = "/values/:key/decrement",
     request_body = ValueDecrement,
     responses(
-        (status = 200, description = "Value decremented.", body = ValueResponse),
+        (status = 200, description = "Value decremented.", body = ValueResponse, headers(("ETag" = String, description = "Version of the value."))),
         (status = 401, description = "Unauthorized request.", body = ResponseError),
         (status = 404, description = "Value not found by key.", body = ResponseError),
         (status = 409, description = "Conflicting request.", body = ResponseError),
+        (status = 412, description = "Version does not match.", body = ResponseError),
         (status = 422, description = "Unprocessable entity."),
     ),
     security(
@@ -127,34 +360,45 @@ pub async fn create(
 )]
 pub async fn decrement(
     access: Access,
-    State(db): State<Arc<Db>>,
-    Path(key): Path<String>,
-    Json(input): Json<ValueDecrement>,
+    Keyspace(db): Keyspace,
+    Path(ValuePath { key }): Path<ValuePath>,
+    if_match: IfMatch,
+    Json(mut input): Json<ValueDecrement>,
 ) -> Result<impl IntoResponse, AppError> {
     if !access.granted() {
         return Err(AppError::Unauthorized);
     }
 
     input.validate()?;
+    input.expected_version = if_match.expected_version(input.expected_version)?;
 
-    db.try_read(&key)?.ok_or(AppError::NotFound)?;
+    if input.initial(Utc::now())?.is_none() {
+        db.try_read(&key)?.ok_or(AppError::NotFound)?;
+    }
 
     let value = db.try_decrement(&key, input)?.ok_or(AppError::Conflict)?;
 
-    Ok((StatusCode::OK, Json(value)).into_response())
+    Ok((
+        StatusCode::OK,
+        [(header::ETAG, entity_tag(value.version))],
+        Json(value),
+    )
+        .into_response())
 }
 
-#[axum_macros::debug_handler]
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
 #[utoipa::path(
     delete,
     params(
-        ("key" = String, Path, description = "Value key.")
+        ("key" = String, Path, description = "Value key."),
+        ("If-Match" = Option<String>, Header, description = "Expected version of the value.")
     ),
     path = "/values/:key",
     responses(
         (status = 204, description = "Value deleted."),
         (status = 401, description = "Unauthorized request.", body = ResponseError),
         (status = 404, description = "Value not found by key.", body = ResponseError),
+        (status = 412, description = "Version does not match.", body = ResponseError),
     ),
     security(
         (),
@@ -163,32 +407,129 @@ pub async fn decrement(
 )]
 pub async fn delete(
     access: Access,
-    State(db): State<Arc<Db>>,
-    Path(key): Path<String>,
+    Keyspace(db): Keyspace,
+    Path(ValuePath { key }): Path<ValuePath>,
+    if_match: IfMatch,
 ) -> Result<impl IntoResponse, AppError> {
     if !access.granted() {
         return Err(AppError::Unauthorized);
     }
 
+    let expected_version = if_match.expected_version(None)?;
+
     db.try_read(&key)?.ok_or(AppError::NotFound)?;
-    db.try_delete(&key)?;
+    db.try_delete(&key, expected_version)?;
 
     Ok((StatusCode::NO_CONTENT, ()).into_response())
 }
 
-#[axum_macros::debug_handler]
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
+#[utoipa::path(
+    delete,
+    params(
+        ("key" = String, Path, description = "Value key."),
+        ("field" = String, Path, description = "Field name."),
+        ("If-Match" = Option<String>, Header, description = "Expected version of the value.")
+    ),
+    path = "/values/:key/fields/:field",
+    responses(
+        (status = 200, description = "Field deleted.", body = ValueFieldResponse, headers(("ETag" = String, description = "Version of the value."))),
+        (status = 401, description = "Unauthorized request.", body = ResponseError),
+        (status = 404, description = "Value not found by key or field not found.", body = ResponseError),
+        (status = 409, description = "Value is not an object.", body = ResponseError),
+        (status = 412, description = "Version does not match.", body = ResponseError),
+    ),
+    security(
+        (),
+        ("api_key" = [])
+    )
+)]
+pub async fn delete_field(
+    access: Access,
+    Keyspace(db): Keyspace,
+    Path(ValueFieldPath { field, key }): Path<ValueFieldPath>,
+    if_match: IfMatch,
+) -> Result<impl IntoResponse, AppError> {
+    if !access.granted() {
+        return Err(AppError::Unauthorized);
+    }
+
+    let expected_version = if_match.expected_version(None)?;
+
+    db.try_read(&key)?.ok_or(AppError::NotFound)?;
+
+    let value_field = db
+        .try_delete_field(&key, &field, expected_version)?
+        .ok_or(AppError::Conflict)?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::ETAG, entity_tag(value_field.version))],
+        Json(value_field),
+    )
+        .into_response())
+}
+
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
+#[utoipa::path(
+    delete,
+    params(
+        ("key" = String, Path, description = "Value key."),
+        ("If-Match" = Option<String>, Header, description = "Expected version of the value.")
+    ),
+    path = "/values/:key/ttl",
+    responses(
+        (status = 200, description = "Value expiration removed.", body = ValueTtlResponse, headers(("ETag" = String, description = "Version of the value."))),
+        (status = 401, description = "Unauthorized request.", body = ResponseError),
+        (status = 404, description = "Value not found by key.", body = ResponseError),
+        (status = 412, description = "Version does not match.", body = ResponseError),
+    ),
+    security(
+        (),
+        ("api_key" = [])
+    )
+)]
+pub async fn delete_ttl(
+    access: Access,
+    Keyspace(db): Keyspace,
+    Path(ValuePath { key }): Path<ValuePath>,
+    if_match: IfMatch,
+) -> Result<impl IntoResponse, AppError> {
+    if !access.granted() {
+        return Err(AppError::Unauthorized);
+    }
+
+    let expected_version = if_match.expected_version(None)?;
+
+    db.try_read(&key)?.ok_or(AppError::NotFound)?;
+
+    let value_ttl = db
+        .try_persist(&key, expected_version)?
+        .ok_or(AppError::Conflict)?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::ETAG, entity_tag(value_ttl.version))],
+        Json(value_ttl),
+    )
+        .into_response())
+}
+
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
 #[utoipa::path(
     put,
     params(
-        ("key" = String, Path, description = "Value key.")
+        ("key" = String, Path, description = "Value key."),
+        ("If-Match" = Option<String>, Header, description = "Expected version of the value.")
     ),
     path = "/values/:key/increment",
     request_body = ValueIncrement,
     responses(
-        (status = 200, description = "Value incremented.", body = ValueResponse),
+        (status = 200, description = "Value incremented.", body = ValueResponse, headers(("ETag" = String, description = "Version of the value."))),
         (status = 401, description = "Unauthorized request.", body = ResponseError),
         (status = 404, description = "Value not found by key.", body = ResponseError),
         (status = 409, description = "Conflicting request.", body = ResponseError),
+        (status = 412, description = "Version does not match.", body = ResponseError),
         (status = 422, description = "Unprocessable entity."),
     ),
     security(
@@ -198,29 +539,90 @@ pub async fn delete(
 )]
 pub async fn increment(
     access: Access,
-    State(db): State<Arc<Db>>,
-    Path(key): Path<String>,
-    Json(input): Json<ValueIncrement>,
+    Keyspace(db): Keyspace,
+    Path(ValuePath { key }): Path<ValuePath>,
+    if_match: IfMatch,
+    Json(mut input): Json<ValueIncrement>,
 ) -> Result<impl IntoResponse, AppError> {
     if !access.granted() {
         return Err(AppError::Unauthorized);
     }
 
     input.validate()?;
+    input.expected_version = if_match.expected_version(input.expected_version)?;
 
-    db.try_read(&key)?.ok_or(AppError::NotFound)?;
+    if input.initial(Utc::now())?.is_none() {
+        db.try_read(&key)?.ok_or(AppError::NotFound)?;
+    }
 
     let value = db.try_increment(&key, input)?.ok_or(AppError::Conflict)?;
 
-    Ok((StatusCode::OK, Json(value)).into_response())
+    Ok((
+        StatusCode::OK,
+        [(header::ETAG, entity_tag(value.version))],
+        Json(value),
+    )
+        .into_response())
+}
+
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
+#[utoipa::path(
+    put,
+    params(
+        ("key" = String, Path, description = "Value key."),
+        ("field" = String, Path, description = "Field name."),
+        ("If-Match" = Option<String>, Header, description = "Expected version of the value.")
+    ),
+    path = "/values/:key/fields/:field/increment",
+    request_body = ValueFieldIncrement,
+    responses(
+        (status = 200, description = "Field incremented.", body = ValueFieldResponse, headers(("ETag" = String, description = "Version of the value."))),
+        (status = 401, description = "Unauthorized request.", body = ResponseError),
+        (status = 404, description = "Value not found by key.", body = ResponseError),
+        (status = 409, description = "Value is not an object or field is not an integer.", body = ResponseError),
+        (status = 412, description = "Version does not match.", body = ResponseError),
+        (status = 422, description = "Unprocessable entity."),
+    ),
+    security(
+        (),
+        ("api_key" = [])
+    )
+)]
+pub async fn increment_field(
+    access: Access,
+    Keyspace(db): Keyspace,
+    Path(ValueFieldPath { field, key }): Path<ValueFieldPath>,
+    if_match: IfMatch,
+    Json(mut input): Json<ValueFieldIncrement>,
+) -> Result<impl IntoResponse, AppError> {
+    if !access.granted() {
+        return Err(AppError::Unauthorized);
+    }
+
+    input.validate()?;
+    input.expected_version = if_match.expected_version(input.expected_version)?;
+
+    db.try_read(&key)?.ok_or(AppError::NotFound)?;
+
+    let value_field = db
+        .try_increment_field(&key, &field, input)?
+        .ok_or(AppError::Conflict)?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::ETAG, entity_tag(value_field.version))],
+        Json(value_field),
+    )
+        .into_response())
 }
 
-#[axum_macros::debug_handler]
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
 #[utoipa::path(
     get,
     path = "/values",
     responses(
-        (status = 200, description = "List of values.", body = [ValueResponse]),
+        (status = 200, description = "List of values.", body = [ValueResponse], headers(("X-Next-Cursor" = String, description = "Cursor to continue listing from, set when more values may follow."))),
+        (status = 400, description = "Invalid cursor.", body = ResponseError),
         (status = 401, description = "Unauthorized request.", body = ResponseError),
     ),
     security(
@@ -230,7 +632,7 @@ pub async fn increment(
 )]
 pub async fn list(
     access: Access,
-    State(db): State<Arc<Db>>,
+    Keyspace(db): Keyspace,
     query_params: Query<QueryParams>,
 ) -> Result<impl IntoResponse, AppError> {
     if !access.granted() {
@@ -239,19 +641,75 @@ pub async fn list(
 
     let Query(query_params) = query_params;
     let direction = query_params.direction.unwrap_or(Direction::Asc);
+    let filter = ListFilter {
+        ends_at: query_params.ends_at,
+        pattern: query_params.pattern,
+        prefix: query_params.prefix,
+        starts_at: query_params.starts_at,
+    };
     let sort = query_params.sort.unwrap_or(Sort::CreatedAt);
 
-    let values = db.list(direction, query_params.limit, query_params.page, sort)?;
+    let value_list = db.list(
+        query_params.cursor.as_deref(),
+        direction,
+        &filter,
+        query_params.limit,
+        query_params.page,
+        sort,
+    )?;
 
-    Ok((StatusCode::OK, Json(values)).into_response())
+    // The cursor goes in a header, so the body stays a plain list of values.
+    let next_cursor = value_list
+        .next_cursor
+        .map(|next_cursor| [(NEXT_CURSOR, next_cursor)]);
+
+    Ok((StatusCode::OK, next_cursor, Json(value_list.values)).into_response())
 }
 
-#[axum_macros::debug_handler]
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
 #[utoipa::path(
-    put,
+    get,
     params(
         ("key" = String, Path, description = "Value key.")
     ),
+    path = "/values/:key/fields",
+    responses(
+        (status = 200, description = "List of fields.", body = ValueFieldsResponse, headers(("ETag" = String, description = "Version of the value."))),
+        (status = 401, description = "Unauthorized request.", body = ResponseError),
+        (status = 404, description = "Value not found by key.", body = ResponseError),
+        (status = 409, description = "Value is not an object.", body = ResponseError),
+    ),
+    security(
+        (),
+        ("api_key" = [])
+    )
+)]
+pub async fn list_fields(
+    access: Access,
+    Keyspace(db): Keyspace,
+    Path(ValuePath { key }): Path<ValuePath>,
+) -> Result<impl IntoResponse, AppError> {
+    if !access.granted() {
+        return Err(AppError::Unauthorized);
+    }
+
+    let value_fields = db.try_list_fields(&key)?.ok_or(AppError::NotFound)?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::ETAG, entity_tag(value_fields.version))],
+        Json(value_fields),
+    )
+        .into_response())
+}
+
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
+#[utoipa::path(
+    put,
+    params(
+        ("key" = String, Path, description = "Value key."),
+        ("If-Match" = Option<String>, Header, description = "Expected version of the value.")
+    ),
     path = "/values/:key/pop-back",
     request_body = ValuePopBack,
     responses(
@@ -259,6 +717,7 @@ pub async fn list(
         (status = 401, description = "Unauthorized request.", body = ResponseError),
         (status = 404, description = "Value not found by key.", body = ResponseError),
         (status = 409, description = "Conflicting request.", body = ResponseError),
+        (status = 412, description = "Version does not match.", body = ResponseError),
         (status = 422, description = "Unprocessable entity."),
     ),
     security(
@@ -268,15 +727,17 @@ pub async fn list(
 )]
 pub async fn pop_back(
     access: Access,
-    State(db): State<Arc<Db>>,
-    Path(key): Path<String>,
-    Json(input): Json<ValuePopBack>,
+    Keyspace(db): Keyspace,
+    Path(ValuePath { key }): Path<ValuePath>,
+    if_match: IfMatch,
+    Json(mut input): Json<ValuePopBack>,
 ) -> Result<impl IntoResponse, AppError> {
     if !access.granted() {
         return Err(AppError::Unauthorized);
     }
 
     input.validate()?;
+    input.expected_version = if_match.expected_version(input.expected_version)?;
 
     db.try_read(&key)?.ok_or(AppError::NotFound)?;
 
@@ -285,11 +746,12 @@ pub async fn pop_back(
     Ok((StatusCode::OK, Json(values)).into_response())
 }
 
-#[axum_macros::debug_handler]
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
 #[utoipa::path(
     put,
     params(
-        ("key" = String, Path, description = "Value key.")
+        ("key" = String, Path, description = "Value key."),
+        ("If-Match" = Option<String>, Header, description = "Expected version of the value.")
     ),
     path = "/values/:key/pop-front",
     request_body = ValuePopFront,
@@ -298,6 +760,7 @@ pub async fn pop_back(
         (status = 401, description = "Unauthorized request.", body = ResponseError),
         (status = 404, description = "Value not found by key.", body = ResponseError),
         (status = 409, description = "Conflicting request.", body = ResponseError),
+        (status = 412, description = "Version does not match.", body = ResponseError),
         (status = 422, description = "Unprocessable entity."),
     ),
     security(
@@ -307,15 +770,17 @@ pub async fn pop_back(
 )]
 pub async fn pop_front(
     access: Access,
-    State(db): State<Arc<Db>>,
-    Path(key): Path<String>,
-    Json(input): Json<ValuePopFront>,
+    Keyspace(db): Keyspace,
+    Path(ValuePath { key }): Path<ValuePath>,
+    if_match: IfMatch,
+    Json(mut input): Json<ValuePopFront>,
 ) -> Result<impl IntoResponse, AppError> {
     if !access.granted() {
         return Err(AppError::Unauthorized);
     }
 
     input.validate()?;
+    input.expected_version = if_match.expected_version(input.expected_version)?;
 
     db.try_read(&key)?.ok_or(AppError::NotFound)?;
 
@@ -324,19 +789,21 @@ pub async fn pop_front(
     Ok((StatusCode::OK, Json(values)).into_response())
 }
 
-#[axum_macros::debug_handler]
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
 #[utoipa::path(
     put,
     params(
-        ("key" = String, Path, description = "Value key.")
+        ("key" = String, Path, description = "Value key."),
+        ("If-Match" = Option<String>, Header, description = "Expected version of the value.")
     ),
     path = "/values/:key/prepend",
     request_body = ValuePrepend,
     responses(
-        (status = 200, description = "Value prepended.", body = ValueResponse),
+        (status = 200, description = "Value prepended.", body = ValueResponse, headers(("ETag" = String, description = "Version of the value."))),
         (status = 401, description = "Unauthorized request.", body = ResponseError),
         (status = 404, description = "Value not found by key.", body = ResponseError),
         (status = 409, description = "Conflicting request.", body = ResponseError),
+        (status = 412, description = "Version does not match.", body = ResponseError),
     ),
     security(
         (),
@@ -345,24 +812,33 @@ pub async fn pop_front(
 )]
 pub async fn prepend(
     access: Access,
-    State(db): State<Arc<Db>>,
-    Path(key): Path<String>,
-    Json(input): Json<ValuePrepend>,
+    Keyspace(db): Keyspace,
+    Path(ValuePath { key }): Path<ValuePath>,
+    if_match: IfMatch,
+    Json(mut input): Json<ValuePrepend>,
 ) -> Result<impl IntoResponse, AppError> {
     if !access.granted() {
         return Err(AppError::Unauthorized);
     }
 
     input.validate()?;
+    input.expected_version = if_match.expected_version(input.expected_version)?;
 
-    db.try_read(&key)?.ok_or(AppError::NotFound)?;
+    if input.initial(Utc::now())?.is_none() {
+        db.try_read(&key)?.ok_or(AppError::NotFound)?;
+    }
 
     let value = db.try_prepend(&key, input)?.ok_or(AppError::Conflict)?;
 
-    Ok((StatusCode::OK, Json(value)).into_response())
+    Ok((
+        StatusCode::OK,
+        [(header::ETAG, entity_tag(value.version))],
+        Json(value),
+    )
+        .into_response())
 }
 
-#[axum_macros::debug_handler]
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
 #[utoipa::path(
     get,
     params(
@@ -370,7 +846,7 @@ pub async fn prepend(
     ),
     path = "/values/:key",
     responses(
-        (status = 200, description = "Value read.", body = ValueResponse),
+        (status = 200, description = "Value read.", body = ValueResponse, headers(("ETag" = String, description = "Version of the value."))),
         (status = 401, description = "Unauthorized request.", body = ResponseError),
         (status = 404, description = "Value not found by key.", body = ResponseError),
     ),
@@ -381,8 +857,8 @@ pub async fn prepend(
 )]
 pub async fn read(
     access: Access,
-    State(db): State<Arc<Db>>,
-    Path(key): Path<String>,
+    Keyspace(db): Keyspace,
+    Path(ValuePath { key }): Path<ValuePath>,
 ) -> Result<impl IntoResponse, AppError> {
     if !access.granted() {
         return Err(AppError::Unauthorized);
@@ -390,21 +866,153 @@ pub async fn read(
 
     let value = db.try_read(&key)?.ok_or(AppError::NotFound)?;
 
-    Ok((StatusCode::OK, Json(value)).into_response())
+    Ok((
+        StatusCode::OK,
+        [(header::ETAG, entity_tag(value.version))],
+        Json(value),
+    )
+        .into_response())
+}
+
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
+#[utoipa::path(
+    get,
+    params(
+        ("key" = String, Path, description = "Value key."),
+        ("field" = String, Path, description = "Field name.")
+    ),
+    path = "/values/:key/fields/:field",
+    responses(
+        (status = 200, description = "Field read.", body = ValueFieldResponse, headers(("ETag" = String, description = "Version of the value."))),
+        (status = 401, description = "Unauthorized request.", body = ResponseError),
+        (status = 404, description = "Value not found by key or field not found.", body = ResponseError),
+        (status = 409, description = "Value is not an object.", body = ResponseError),
+    ),
+    security(
+        (),
+        ("api_key" = [])
+    )
+)]
+pub async fn read_field(
+    access: Access,
+    Keyspace(db): Keyspace,
+    Path(ValueFieldPath { field, key }): Path<ValueFieldPath>,
+) -> Result<impl IntoResponse, AppError> {
+    if !access.granted() {
+        return Err(AppError::Unauthorized);
+    }
+
+    let value_field = db.try_read_field(&key, &field)?.ok_or(AppError::NotFound)?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::ETAG, entity_tag(value_field.version))],
+        Json(value_field),
+    )
+        .into_response())
+}
+
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
+#[utoipa::path(
+    get,
+    params(
+        ("key" = String, Path, description = "Value key.")
+    ),
+    path = "/values/:key/ttl",
+    responses(
+        (status = 200, description = "Value expiration read.", body = ValueTtlResponse, headers(("ETag" = String, description = "Version of the value."))),
+        (status = 401, description = "Unauthorized request.", body = ResponseError),
+        (status = 404, description = "Value not found by key.", body = ResponseError),
+    ),
+    security(
+        (),
+        ("api_key" = [])
+    )
+)]
+pub async fn read_ttl(
+    access: Access,
+    Keyspace(db): Keyspace,
+    Path(ValuePath { key }): Path<ValuePath>,
+) -> Result<impl IntoResponse, AppError> {
+    if !access.granted() {
+        return Err(AppError::Unauthorized);
+    }
+
+    let value_ttl = db.try_read_ttl(&key)?.ok_or(AppError::NotFound)?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::ETAG, entity_tag(value_ttl.version))],
+        Json(value_ttl),
+    )
+        .into_response())
 }
 
-#[axum_macros::debug_handler]
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
 #[utoipa::path(
     put,
     params(
         ("key" = String, Path, description = "Value key.")
     ),
+    path = "/values/:key/rename",
+    request_body = ValueRename,
+    responses(
+        (status = 200, description = "Value moved to the new key.", body = ValueResponse, headers(("ETag" = String, description = "Version of the value."))),
+        (status = 400, description = "Invalid request.", body = ResponseError),
+        (status = 401, description = "Unauthorized request.", body = ResponseError),
+        (status = 404, description = "Value not found by key.", body = ResponseError),
+        (status = 409, description = "Value already exists under the new key.", body = ResponseError),
+    ),
+    security(
+        (),
+        ("api_key" = [])
+    )
+)]
+pub async fn rename(
+    access: Access,
+    Keyspace(db): Keyspace,
+    Path(ValuePath { key }): Path<ValuePath>,
+    Json(input): Json<ValueRename>,
+) -> Result<impl IntoResponse, AppError> {
+    if !access.granted() {
+        return Err(AppError::Unauthorized);
+    }
+
+    input.validate()?;
+
+    db.try_read(&key)?.ok_or(AppError::NotFound)?;
+
+    if !input.overwrite && db.try_read(&input.to)?.is_some() {
+        return Err(AppError::Conflict);
+    }
+
+    let value = db
+        .try_rename(&key, &input.to, input.overwrite)?
+        .ok_or(AppError::NotFound)?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::ETAG, entity_tag(value.version))],
+        Json(value),
+    )
+        .into_response())
+}
+
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
+#[utoipa::path(
+    put,
+    params(
+        ("key" = String, Path, description = "Value key."),
+        ("If-Match" = Option<String>, Header, description = "Expected version of the value.")
+    ),
     path = "/values/:key",
     request_body = ValuePut,
     responses(
-        (status = 200, description = "Value updated.", body = ValueResponse),
+        (status = 200, description = "Value updated.", body = ValueResponse, headers(("ETag" = String, description = "Version of the value."))),
+        (status = 201, description = "Value created by upsert.", body = ValueResponse, headers(("ETag" = String, description = "Version of the value."))),
         (status = 401, description = "Unauthorized request.", body = ResponseError),
         (status = 404, description = "Value not found by key.", body = ResponseError),
+        (status = 412, description = "Version does not match.", body = ResponseError),
     ),
     security(
         (),
@@ -413,19 +1021,132 @@ pub async fn read(
 )]
 pub async fn update(
     access: Access,
-    State(db): State<Arc<Db>>,
-    Path(key): Path<String>,
-    Json(input): Json<ValuePut>,
+    Keyspace(db): Keyspace,
+    Path(ValuePath { key }): Path<ValuePath>,
+    if_match: IfMatch,
+    Json(mut input): Json<ValuePut>,
 ) -> Result<impl IntoResponse, AppError> {
     if !access.granted() {
         return Err(AppError::Unauthorized);
     }
 
     input.validate()?;
+    input.expected_version = if_match.expected_version(input.expected_version)?;
 
-    db.try_read(&key)?.ok_or(AppError::NotFound)?;
+    if input.initial(Utc::now())?.is_none() {
+        db.try_read(&key)?.ok_or(AppError::NotFound)?;
+    }
 
     let value = db.try_update(&key, input)?.ok_or(AppError::Conflict)?;
+    // Updates always increase the version, so the first one means the record was just created.
+    let status_code = if value.version == 1 {
+        StatusCode::CREATED
+    } else {
+        StatusCode::OK
+    };
+
+    Ok((
+        status_code,
+        [(header::ETAG, entity_tag(value.version))],
+        Json(value),
+    )
+        .into_response())
+}
 
-    Ok((StatusCode::OK, Json(value)).into_response())
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
+#[utoipa::path(
+    put,
+    params(
+        ("key" = String, Path, description = "Value key."),
+        ("field" = String, Path, description = "Field name."),
+        ("If-Match" = Option<String>, Header, description = "Expected version of the value.")
+    ),
+    path = "/values/:key/fields/:field",
+    request_body = ValueFieldPut,
+    responses(
+        (status = 200, description = "Field updated.", body = ValueFieldResponse, headers(("ETag" = String, description = "Version of the value."))),
+        (status = 401, description = "Unauthorized request.", body = ResponseError),
+        (status = 404, description = "Value not found by key.", body = ResponseError),
+        (status = 409, description = "Value is not an object.", body = ResponseError),
+        (status = 412, description = "Version does not match.", body = ResponseError),
+        (status = 422, description = "Unprocessable entity."),
+    ),
+    security(
+        (),
+        ("api_key" = [])
+    )
+)]
+pub async fn update_field(
+    access: Access,
+    Keyspace(db): Keyspace,
+    Path(ValueFieldPath { field, key }): Path<ValueFieldPath>,
+    if_match: IfMatch,
+    Json(mut input): Json<ValueFieldPut>,
+) -> Result<impl IntoResponse, AppError> {
+    if !access.granted() {
+        return Err(AppError::Unauthorized);
+    }
+
+    input.validate()?;
+    input.expected_version = if_match.expected_version(input.expected_version)?;
+
+    db.try_read(&key)?.ok_or(AppError::NotFound)?;
+
+    let value_field = db
+        .try_update_field(&key, &field, input)?
+        .ok_or(AppError::Conflict)?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::ETAG, entity_tag(value_field.version))],
+        Json(value_field),
+    )
+        .into_response())
+}
+
+#[axum_macros::debug_handler(state = Arc<Keyspaces>)]
+#[utoipa::path(
+    put,
+    params(
+        ("key" = String, Path, description = "Value key."),
+        ("If-Match" = Option<String>, Header, description = "Expected version of the value.")
+    ),
+    path = "/values/:key/ttl",
+    request_body = ValueExpire,
+    responses(
+        (status = 200, description = "Value expiration updated.", body = ValueTtlResponse, headers(("ETag" = String, description = "Version of the value."))),
+        (status = 400, description = "Invalid request.", body = ResponseError),
+        (status = 401, description = "Unauthorized request.", body = ResponseError),
+        (status = 404, description = "Value not found by key.", body = ResponseError),
+        (status = 412, description = "Version does not match.", body = ResponseError),
+    ),
+    security(
+        (),
+        ("api_key" = [])
+    )
+)]
+pub async fn update_ttl(
+    access: Access,
+    Keyspace(db): Keyspace,
+    Path(ValuePath { key }): Path<ValuePath>,
+    if_match: IfMatch,
+    Json(mut input): Json<ValueExpire>,
+) -> Result<impl IntoResponse, AppError> {
+    if !access.granted() {
+        return Err(AppError::Unauthorized);
+    }
+
+    input.validate()?;
+    input.expected_version = if_match.expected_version(input.expected_version)?;
+
+    db.try_read(&key)?.ok_or(AppError::NotFound)?;
+
+    let value_ttl = db.try_expire(&key, input)?.ok_or(AppError::Conflict)?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::ETAG, entity_tag(value_ttl.version))],
+        Json(value_ttl),
+    )
+        .into_response())
 }
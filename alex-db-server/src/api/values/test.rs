@@ -1,19 +1,30 @@
 #[cfg(test)]
 mod tests {
-    use crate::{app, config::Config};
+    use crate::{api::values::NEXT_CURSOR, app, config::Config};
     use alex_db_lib::{
+        batch::{BatchResponse, BatchStatus},
         config::Config as DbConfig,
-        value_record::{Value, ValueResponse},
+        value_record::{
+            Value, ValueCompareAndSwapResponse, ValueFieldResponse, ValueFieldsResponse,
+            ValueResponse, ValueTtlResponse,
+        },
     };
     use axum::{
         body::Body,
         http::{self, Request, StatusCode},
     };
+    use chrono::{Duration, SecondsFormat, Utc};
     use fake::{
         faker::lorem::en::{Paragraph, Word},
         Fake, Faker,
     };
-    use std::collections::VecDeque;
+    use std::{
+        collections::{BTreeMap, VecDeque},
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+    };
     use tower::ServiceExt;
 
     #[tokio::test]
@@ -933,6 +944,70 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn append_200_create() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/append"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "append": [&value],
+                            "create": true,
+                            "ttl": 100
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::String(value)]))
+        );
+        assert_eq!(body.version, 1);
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}/ttl"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueTtlResponse = serde_json::from_slice(&body).unwrap();
+
+        assert!(body.ttl.is_some());
+    }
+
     #[tokio::test]
     async fn append_401() {
         let db_config = DbConfig::default();
@@ -1087,7 +1162,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn create_201_array_array_boolean() {
+    async fn batch_delete_200() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -1095,10 +1170,11 @@ mod tests {
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
+        let cloned_router = router.clone();
+        let cloned_router2 = router.clone();
 
         let key = Word().fake::<String>();
-        let value: bool = Faker.fake();
-        let value_array = vec![vec![value]];
+        let wrong_key = format!("{key}_wrong");
 
         let response = router
             .oneshot(
@@ -1109,7 +1185,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": 10
                         })
                         .to_string(),
                     ))
@@ -1120,42 +1196,15 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
-                Value::Boolean(value)
-            ]))]))
-        );
-    }
-
-    #[tokio::test]
-    async fn create_201_array_array_float() {
-        let db_config = DbConfig {
-            enable_security_api_keys: false,
-            ..Default::default()
-        };
-        let config = Config::new(db_config, 10240);
-        let app = app::get_app(config).await.unwrap();
-        let router = app.router;
-
-        let key = Word().fake::<String>();
-        let value = 1.5;
-        let value_array = vec![vec![value]];
-
-        let response = router
+        let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::POST)
-                    .uri("/values")
+                    .uri("/batch/values/delete")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
                         serde_json::json!({
-                            "key": &key,
-                            "value": &value_array
+                            "keys": [&key, &wrong_key]
                         })
                         .to_string(),
                     ))
@@ -1164,44 +1213,50 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+        let body: BatchResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
-                Value::Float(value)
-            ]))]))
-        );
+        assert_eq!(body.results[0].key, key);
+        assert_eq!(body.results[0].status, BatchStatus::Deleted);
+        assert_eq!(body.results[1].key, wrong_key);
+        assert_eq!(body.results[1].status, BatchStatus::NotFound);
+
+        let response = cloned_router2
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 
     #[tokio::test]
-    async fn create_201_array_array_integer() {
-        let db_config = DbConfig {
-            enable_security_api_keys: false,
-            ..Default::default()
-        };
+    async fn batch_delete_401() {
+        let db_config = DbConfig::default();
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
 
         let key = Word().fake::<String>();
-        let value: i64 = Faker.fake();
-        let value_array = vec![vec![value]];
+        let wrong_key = format!("{key}_wrong");
 
         let response = router
             .oneshot(
                 Request::builder()
                     .method(http::Method::POST)
-                    .uri("/values")
+                    .uri("/batch/values/delete")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
                         serde_json::json!({
-                            "key": &key,
-                            "value": &value_array
+                            "keys": [&key, &wrong_key]
                         })
                         .to_string(),
                     ))
@@ -1210,22 +1265,11 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::CREATED);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
-                Value::Integer(value)
-            ]))]))
-        );
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
     }
 
     #[tokio::test]
-    async fn create_201_array_array_string() {
+    async fn batch_get_200() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -1233,10 +1277,10 @@ mod tests {
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
+        let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
-        let value_array = vec![vec![value.clone()]];
+        let wrong_key = format!("{key}_wrong");
 
         let response = router
             .oneshot(
@@ -1247,7 +1291,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": 10
                         })
                         .to_string(),
                     ))
@@ -1258,42 +1302,15 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
-                Value::String(value)
-            ]))]))
-        );
-    }
-
-    #[tokio::test]
-    async fn create_201_array_boolean() {
-        let db_config = DbConfig {
-            enable_security_api_keys: false,
-            ..Default::default()
-        };
-        let config = Config::new(db_config, 10240);
-        let app = app::get_app(config).await.unwrap();
-        let router = app.router;
-
-        let key = Word().fake::<String>();
-        let value: bool = Faker.fake();
-        let value_array = vec![value];
-
-        let response = router
+        let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::POST)
-                    .uri("/values")
+                    .uri("/batch/values/get")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
                         serde_json::json!({
-                            "key": &key,
-                            "value": &value_array
+                            "keys": [&key, &wrong_key]
                         })
                         .to_string(),
                     ))
@@ -1302,31 +1319,31 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+        let body: BatchResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body.key, key);
+        assert_eq!(body.results.len(), 2);
+        assert_eq!(body.results[0].status, BatchStatus::Found);
         assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Boolean(value)]))
+            body.results[0].value.as_ref().unwrap().value,
+            Value::Integer(10)
         );
+        assert_eq!(body.results[1].status, BatchStatus::NotFound);
+        assert!(body.results[1].value.is_none());
     }
 
     #[tokio::test]
-    async fn create_201_array_float() {
-        let db_config = DbConfig {
-            enable_security_api_keys: false,
-            ..Default::default()
-        };
+    async fn batch_get_200_authentication() {
+        let db_config = DbConfig::default();
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
+        let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = 1.5;
-        let value_array = vec![value];
+        let wrong_key = format!("{key}_wrong");
 
         let response = router
             .oneshot(
@@ -1334,10 +1351,11 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": 10
                         })
                         .to_string(),
                     ))
@@ -1348,40 +1366,46 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/batch/values/get")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "keys": [&key, &wrong_key]
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
 
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Float(value)]))
-        );
+        assert_eq!(response.status(), StatusCode::OK);
     }
 
     #[tokio::test]
-    async fn create_201_array_integer() {
-        let db_config = DbConfig {
-            enable_security_api_keys: false,
-            ..Default::default()
-        };
+    async fn batch_get_401() {
+        let db_config = DbConfig::default();
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
 
         let key = Word().fake::<String>();
-        let value: i64 = Faker.fake();
-        let value_array = vec![value];
+        let wrong_key = format!("{key}_wrong");
 
         let response = router
             .oneshot(
                 Request::builder()
                     .method(http::Method::POST)
-                    .uri("/values")
+                    .uri("/batch/values/get")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
                         serde_json::json!({
-                            "key": &key,
-                            "value": &value_array
+                            "keys": [&key, &wrong_key]
                         })
                         .to_string(),
                     ))
@@ -1390,20 +1414,11 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::CREATED);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Integer(value)]))
-        );
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
     }
 
     #[tokio::test]
-    async fn create_201_array_string() {
+    async fn batch_set_200() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -1411,10 +1426,11 @@ mod tests {
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
+        let cloned_router = router.clone();
+        let cloned_router2 = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
-        let value_array = vec![value.clone()];
+        let other_key = format!("{key}_other");
 
         let response = router
             .oneshot(
@@ -1425,7 +1441,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": 10
                         })
                         .to_string(),
                     ))
@@ -1436,39 +1452,18 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::String(value)]))
-        );
-    }
-
-    #[tokio::test]
-    async fn create_201_boolean() {
-        let db_config = DbConfig {
-            enable_security_api_keys: false,
-            ..Default::default()
-        };
-        let config = Config::new(db_config, 10240);
-        let app = app::get_app(config).await.unwrap();
-        let router = app.router;
-
-        let key = Word().fake::<String>();
-        let value = Faker.fake();
-
-        let response = router
+        let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::POST)
-                    .uri("/values")
+                    .uri("/batch/values/set")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
                         serde_json::json!({
-                            "key": &key,
-                            "value": &value
+                            "values": [
+                                { "key": &key, "value": 20 },
+                                { "key": &other_key, "value": 30 }
+                            ]
                         })
                         .to_string(),
                     ))
@@ -1477,17 +1472,41 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: BatchResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.results[0].status, BatchStatus::Conflict);
+        assert!(body.results[0].value.is_none());
+        assert_eq!(body.results[1].status, BatchStatus::Created);
+        assert_eq!(
+            body.results[1].value.as_ref().unwrap().value,
+            Value::Integer(30)
+        );
+
+        let response = cloned_router2
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Boolean(value));
+        assert_eq!(body.value, Value::Integer(10));
     }
 
     #[tokio::test]
-    async fn create_201_float() {
+    async fn batch_set_200_concurrent_read() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -1497,9 +1516,10 @@ mod tests {
         let router = app.router;
 
         let key = Word().fake::<String>();
-        let value = 1.5;
+        let other_key = format!("{key}_other");
 
         let response = router
+            .clone()
             .oneshot(
                 Request::builder()
                     .method(http::Method::POST)
@@ -1508,7 +1528,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value
+                            "value": 10
                         })
                         .to_string(),
                     ))
@@ -1519,15 +1539,48 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Float(value));
+        let db = app.keyspaces.default_keyspace();
+        let read_key = key.clone();
+        let is_finished = Arc::new(AtomicBool::new(false));
+        let is_reading = is_finished.clone();
+        let reads = std::thread::spawn(move || {
+            while !is_reading.load(Ordering::Relaxed) {
+                assert!(db.try_read(&read_key).unwrap().is_some());
+            }
+        });
+
+        for i in 0..500 {
+            let response = router
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .method(http::Method::POST)
+                        .uri("/batch/values/set")
+                        .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                        .body(Body::from(
+                            serde_json::json!({
+                                "upsert": true,
+                                "values": [
+                                    { "key": &key, "value": i },
+                                    { "key": &other_key, "value": i }
+                                ]
+                            })
+                            .to_string(),
+                        ))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        is_finished.store(true, Ordering::Relaxed);
+        reads.join().unwrap();
     }
 
     #[tokio::test]
-    async fn create_201_integer() {
+    async fn batch_set_200_upsert() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -1535,9 +1588,11 @@ mod tests {
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
+        let cloned_router = router.clone();
+        let cloned_router2 = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Faker.fake();
+        let other_key = format!("{key}_other");
 
         let response = router
             .oneshot(
@@ -1548,7 +1603,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value
+                            "value": 10
                         })
                         .to_string(),
                     ))
@@ -1559,36 +1614,19 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value));
-    }
-
-    #[tokio::test]
-    async fn create_201_string() {
-        let db_config = DbConfig {
-            enable_security_api_keys: false,
-            ..Default::default()
-        };
-        let config = Config::new(db_config, 10240);
-        let app = app::get_app(config).await.unwrap();
-        let router = app.router;
-
-        let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
-
-        let response = router
+        let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::POST)
-                    .uri("/values")
+                    .uri("/batch/values/set")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
                         serde_json::json!({
-                            "key": &key,
-                            "value": &value
+                            "upsert": true,
+                            "values": [
+                                { "key": &key, "value": 20 },
+                                { "key": &other_key, "value": 30 }
+                            ]
                         })
                         .to_string(),
                     ))
@@ -1597,36 +1635,59 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: BatchResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.results[0].status, BatchStatus::Updated);
+        assert_eq!(body.results[0].value.as_ref().unwrap().version, 2);
+        assert_eq!(body.results[1].status, BatchStatus::Created);
+
+        let response = cloned_router2
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
+        assert_eq!(body.value, Value::Integer(20));
     }
 
     #[tokio::test]
-    async fn create_201_authentication() {
-        let db_config = DbConfig::default();
+    async fn batch_set_400() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
                 Request::builder()
                     .method(http::Method::POST)
-                    .uri("/values")
+                    .uri("/batch/values/set")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
-                            "key": &key,
-                            "value": &value
+                            "values": [
+                                { "key": &key, "value": 20 },
+                                { "key": format!("{key}_other"), "ttl": 10, "ttl_ms": 10000, "value": 30 }
+                            ]
                         })
                         .to_string(),
                     ))
@@ -1635,35 +1696,31 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::CREATED);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
     #[tokio::test]
-    async fn create_401() {
+    async fn batch_set_401() {
         let db_config = DbConfig::default();
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
+        let other_key = format!("{key}_other");
 
         let response = router
             .oneshot(
                 Request::builder()
                     .method(http::Method::POST)
-                    .uri("/values")
+                    .uri("/batch/values/set")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
                         serde_json::json!({
-                            "key": &key,
-                            "value": &value
+                            "values": [
+                                { "key": &key, "value": 20 },
+                                { "key": &other_key, "value": 30 }
+                            ]
                         })
                         .to_string(),
                     ))
@@ -1676,7 +1733,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn create_409() {
+    async fn compare_and_swap_200() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -1687,7 +1744,6 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
@@ -1698,7 +1754,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value
+                            "value": false
                         })
                         .to_string(),
                     ))
@@ -1709,24 +1765,16 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
-
-        let value = Paragraph(2..10).fake::<String>();
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::POST)
-                    .uri("/values")
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/cas"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
                         serde_json::json!({
-                            "key": &key,
-                            "value": &value
+                            "expected": false,
+                            "new": true
                         })
                         .to_string(),
                     ))
@@ -1735,22 +1783,26 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[http::header::ETAG], "\"2\"");
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueCompareAndSwapResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert!(body.swapped);
+        assert_eq!(body.value, Value::Boolean(true));
     }
 
     #[tokio::test]
-    async fn decrement_200() {
-        let db_config = DbConfig {
-            enable_security_api_keys: false,
-            ..Default::default()
-        };
+    async fn compare_and_swap_200_authentication() {
+        let db_config = DbConfig::default();
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = 100;
 
         let response = router
             .oneshot(
@@ -1758,10 +1810,11 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value
+                            "value": false
                         })
                         .to_string(),
                     ))
@@ -1772,22 +1825,19 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value));
-
-        let decrement_value: i64 = 50;
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/decrement"))
+                    .uri(format!("/values/{key}/cas"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
-                        serde_json::json!({ "decrement": &decrement_value }).to_string(),
+                        serde_json::json!({
+                            "expected": false,
+                            "new": true
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
@@ -1795,16 +1845,10 @@ mod tests {
             .unwrap();
 
         assert_eq!(response.status(), StatusCode::OK);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value - decrement_value.abs()));
     }
 
     #[tokio::test]
-    async fn decrement_200_max() {
+    async fn compare_and_swap_200_not_swapped() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -1815,7 +1859,6 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = 0;
 
         let response = router
             .oneshot(
@@ -1826,7 +1869,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value
+                            "value": false
                         })
                         .to_string(),
                     ))
@@ -1837,22 +1880,18 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value));
-
-        let decrement_value = i64::MAX;
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/decrement"))
+                    .uri(format!("/values/{key}/cas"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "decrement": &decrement_value }).to_string(),
+                        serde_json::json!({
+                            "expected": true,
+                            "new": false
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
@@ -1860,27 +1899,24 @@ mod tests {
             .unwrap();
 
         assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[http::header::ETAG], "\"1\"");
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+        let body: ValueCompareAndSwapResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(i64::MIN + 1));
+        assert!(!body.swapped);
+        assert_eq!(body.value, Value::Boolean(false));
     }
 
     #[tokio::test]
-    async fn decrement_200_min() {
-        let db_config = DbConfig {
-            enable_security_api_keys: false,
-            ..Default::default()
-        };
+    async fn compare_and_swap_401() {
+        let db_config = DbConfig::default();
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = 0;
 
         let response = router
             .oneshot(
@@ -1888,10 +1924,11 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value
+                            "value": false
                         })
                         .to_string(),
                     ))
@@ -1902,39 +1939,29 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value));
-
-        let decrement_value = i64::MIN;
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/decrement"))
+                    .uri(format!("/values/{key}/cas"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "decrement": &decrement_value }).to_string(),
+                        serde_json::json!({
+                            "expected": false,
+                            "new": true
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(0));
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
     }
 
     #[tokio::test]
-    async fn decrement_200_min_plus_1() {
+    async fn compare_and_swap_404() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -1942,21 +1969,19 @@ mod tests {
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
-        let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = 0;
 
         let response = router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::POST)
-                    .uri("/values")
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/cas"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
                         serde_json::json!({
-                            "key": &key,
-                            "value": &value
+                            "expected": false,
+                            "new": true
                         })
                         .to_string(),
                     ))
@@ -1965,61 +1990,54 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::CREATED);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value));
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
 
-        let decrement_value = i64::MIN + 1;
+    #[tokio::test]
+    async fn copy_200() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+        let cloned_router2 = router.clone();
+        let cloned_router3 = router.clone();
+        let key = Word().fake::<String>();
+        let new_key = format!("{key}-new");
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/decrement"))
+                    .method(http::Method::POST)
+                    .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "decrement": &decrement_value }).to_string(),
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(i64::MIN + 1));
-    }
-
-    #[tokio::test]
-    async fn decrement_200_authentication() {
-        let db_config = DbConfig::default();
-        let config = Config::new(db_config, 10240);
-        let app = app::get_app(config).await.unwrap();
-        let router = app.router;
-        let cloned_router = router.clone();
-
-        let key = Word().fake::<String>();
-        let value = 100;
+        assert_eq!(response.status(), StatusCode::CREATED);
 
-        let response = router
+        let response = cloned_router2
             .oneshot(
                 Request::builder()
-                    .method(http::Method::POST)
-                    .uri("/values")
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/copy"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
-                            "key": &key,
-                            "value": &value
+                            "to": &new_key
                         })
                         .to_string(),
                     ))
@@ -2028,26 +2046,21 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value));
-
-        let decrement_value: i64 = 50;
+        assert_eq!(body.key, new_key);
+        assert_eq!(body.value, Value::String(value));
 
-        let response = cloned_router
+        let response = cloned_router3
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/decrement"))
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{new_key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
-                    .body(Body::from(
-                        serde_json::json!({ "decrement": &decrement_value }).to_string(),
-                    ))
+                    .body(Body::empty())
                     .unwrap(),
             )
             .await
@@ -2055,15 +2068,23 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::OK);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
 
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value - decrement_value.abs()));
+        assert_eq!(response.status(), StatusCode::OK);
     }
 
     #[tokio::test]
-    async fn decrement_200_no_decrement_value() {
+    async fn copy_200_overwrite() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -2072,11 +2093,13 @@ mod tests {
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
-
+        let cloned_router2 = router.clone();
+        let cloned_router3 = router.clone();
         let key = Word().fake::<String>();
-        let value = 100;
+        let new_key = format!("{key}-new");
+        let value = Paragraph(2..10).fake::<String>();
 
-        let response = router
+        let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::POST)
@@ -2096,19 +2119,53 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+        let response = cloned_router2
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &new_key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
 
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value));
+        assert_eq!(response.status(), StatusCode::CREATED);
 
-        let response = cloned_router
+        let response = cloned_router3
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/decrement"))
+                    .uri(format!("/values/{key}/copy"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(serde_json::json!({}).to_string()))
+                    .body(Body::from(
+                        serde_json::json!({
+                            "overwrite": true,
+                            "to": &new_key
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{new_key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
                     .unwrap(),
             )
             .await
@@ -2119,20 +2176,50 @@ mod tests {
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value - 1));
+        assert_eq!(body.value, Value::String(value));
     }
 
     #[tokio::test]
-    async fn decrement_401() {
+    async fn copy_400() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let key = Word().fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/copy"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "to": ""
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn copy_401() {
         let db_config = DbConfig::default();
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
-
         let key = Word().fake::<String>();
-        let value = 100;
+        let new_key = format!("{key}-new");
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
@@ -2155,22 +2242,17 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value));
-
-        let decrement_value: i64 = 50;
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/decrement"))
+                    .uri(format!("/values/{key}/copy"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "decrement": &decrement_value }).to_string(),
+                        serde_json::json!({
+                            "to": &new_key
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
@@ -2181,7 +2263,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn decrement_404() {
+    async fn copy_404() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -2189,18 +2271,20 @@ mod tests {
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
-
         let key = Word().fake::<String>();
-        let decrement_value: i64 = 50;
+        let new_key = format!("{key}-new");
 
         let response = router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/decrement"))
+                    .uri(format!("/values/{key}/copy"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "decrement": &decrement_value }).to_string(),
+                        serde_json::json!({
+                            "to": &new_key
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
@@ -2211,7 +2295,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn decrement_409() {
+    async fn copy_409() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -2220,11 +2304,12 @@ mod tests {
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
-
+        let cloned_router2 = router.clone();
         let key = Word().fake::<String>();
+        let new_key = format!("{key}-new");
         let value = Paragraph(2..10).fake::<String>();
 
-        let response = router
+        let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::POST)
@@ -2244,22 +2329,37 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
+        let response = cloned_router2
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &new_key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
 
-        let decrement_value: i64 = 50;
+        assert_eq!(response.status(), StatusCode::CREATED);
 
-        let response = cloned_router
+        let response = router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/decrement"))
+                    .uri(format!("/values/{key}/copy"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "decrement": &decrement_value }).to_string(),
+                        serde_json::json!({
+                            "to": &new_key
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
@@ -2270,7 +2370,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn decrement_422() {
+    async fn create_201_array_array_boolean() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -2278,10 +2378,10 @@ mod tests {
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
-        let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = 100;
+        let value: bool = Faker.fake();
+        let value_array = vec![vec![value]];
 
         let response = router
             .oneshot(
@@ -2292,7 +2392,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value
+                            "value": &value_array
                         })
                         .to_string(),
                     ))
@@ -2307,17 +2407,38 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value));
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
+                Value::Boolean(value)
+            ]))]))
+        );
+    }
 
-        let response = cloned_router
+    #[tokio::test]
+    async fn create_201_array_array_float() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let key = Word().fake::<String>();
+        let value = 1.5;
+        let value_array = vec![vec![value]];
+
+        let response = router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/decrement"))
+                    .method(http::Method::POST)
+                    .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
                         serde_json::json!({
-                            "decrement": "wrong_value"
+                            "key": &key,
+                            "value": &value_array
                         })
                         .to_string(),
                     ))
@@ -2326,11 +2447,22 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
+                Value::Float(value)
+            ]))]))
+        );
     }
 
     #[tokio::test]
-    async fn delete_204() {
+    async fn create_201_array_array_integer() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -2338,10 +2470,10 @@ mod tests {
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
-        let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
+        let value: i64 = Faker.fake();
+        let value_array = vec![vec![value]];
 
         let response = router
             .oneshot(
@@ -2352,7 +2484,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value
+                            "value": &value_array
                         })
                         .to_string(),
                     ))
@@ -2367,33 +2499,27 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
-
-        let response = cloned_router
-            .oneshot(
-                Request::builder()
-                    .method(http::Method::DELETE)
-                    .uri(format!("/values/{key}"))
-                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::empty())
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
-
-        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
+                Value::Integer(value)
+            ]))]))
+        );
     }
 
     #[tokio::test]
-    async fn delete_204_authentication() {
-        let db_config = DbConfig::default();
+    async fn create_201_array_array_string() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
-        let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
         let value = Paragraph(2..10).fake::<String>();
+        let value_array = vec![vec![value.clone()]];
 
         let response = router
             .oneshot(
@@ -2401,11 +2527,10 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value
+                            "value": &value_array
                         })
                         .to_string(),
                     ))
@@ -2420,34 +2545,27 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
-
-        let response = cloned_router
-            .oneshot(
-                Request::builder()
-                    .method(http::Method::DELETE)
-                    .uri(format!("/values/{key}"))
-                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
-                    .body(Body::empty())
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
-
-        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
+                Value::String(value)
+            ]))]))
+        );
     }
 
     #[tokio::test]
-    async fn delete_401() {
-        let db_config = DbConfig::default();
+    async fn create_201_array_boolean() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
-        let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
+        let value: bool = Faker.fake();
+        let value_array = vec![value];
 
         let response = router
             .oneshot(
@@ -2455,11 +2573,10 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value
+                            "value": &value_array
                         })
                         .to_string(),
                     ))
@@ -2474,25 +2591,14 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
-
-        let response = cloned_router
-            .oneshot(
-                Request::builder()
-                    .method(http::Method::DELETE)
-                    .uri(format!("/values/{key}"))
-                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::empty())
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
-
-        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::Boolean(value)]))
+        );
     }
 
     #[tokio::test]
-    async fn delete_404() {
+    async fn create_201_array_float() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -2500,11 +2606,10 @@ mod tests {
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
-        let cloned_router = router.clone();
-        let second_cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
+        let value = 1.5;
+        let value_array = vec![value];
 
         let response = router
             .oneshot(
@@ -2515,7 +2620,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value
+                            "value": &value_array
                         })
                         .to_string(),
                     ))
@@ -2530,39 +2635,14 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
-
-        let response = cloned_router
-            .oneshot(
-                Request::builder()
-                    .method(http::Method::DELETE)
-                    .uri(format!("/values/{key}"))
-                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::empty())
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
-
-        assert_eq!(response.status(), StatusCode::NO_CONTENT);
-
-        let response = second_cloned_router
-            .oneshot(
-                Request::builder()
-                    .method(http::Method::DELETE)
-                    .uri(format!("/values/{key}"))
-                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::empty())
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
-
-        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::Float(value)]))
+        );
     }
 
     #[tokio::test]
-    async fn increment_200() {
+    async fn create_201_array_integer() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -2570,10 +2650,10 @@ mod tests {
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
-        let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = 100;
+        let value: i64 = Faker.fake();
+        let value_array = vec![value];
 
         let response = router
             .oneshot(
@@ -2584,7 +2664,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value
+                            "value": &value_array
                         })
                         .to_string(),
                     ))
@@ -2599,35 +2679,58 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value));
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::Integer(value)]))
+        );
+    }
 
-        let increment_value: i64 = 50;
+    #[tokio::test]
+    async fn create_201_array_string() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
 
-        let response = cloned_router
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+        let value_array = vec![value.clone()];
+
+        let response = router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/increment"))
+                    .method(http::Method::POST)
+                    .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "increment": &increment_value }).to_string(),
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.status(), StatusCode::CREATED);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value + increment_value.abs()));
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::String(value)]))
+        );
     }
 
     #[tokio::test]
-    async fn increment_200_max() {
+    async fn create_201_boolean() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -2635,10 +2738,9 @@ mod tests {
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
-        let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = 0;
+        let value = Faker.fake();
 
         let response = router
             .oneshot(
@@ -2664,35 +2766,51 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value));
+        assert_eq!(body.value, Value::Boolean(value));
+    }
 
-        let increment_value = i64::MAX;
+    #[tokio::test]
+    async fn create_201_float() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
 
-        let response = cloned_router
+        let key = Word().fake::<String>();
+        let value = 1.5;
+
+        let response = router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/increment"))
+                    .method(http::Method::POST)
+                    .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "increment": &increment_value }).to_string(),
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.status(), StatusCode::CREATED);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(i64::MAX));
+        assert_eq!(body.value, Value::Float(value));
     }
 
     #[tokio::test]
-    async fn increment_200_min() {
+    async fn create_201_integer() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -2700,10 +2818,9 @@ mod tests {
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
-        let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = 0;
+        let value = Faker.fake();
 
         let response = router
             .oneshot(
@@ -2730,34 +2847,57 @@ mod tests {
 
         assert_eq!(body.key, key);
         assert_eq!(body.value, Value::Integer(value));
+    }
 
-        let increment_value = i64::MIN;
+    #[tokio::test]
+    async fn create_201_object() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
 
-        let response = cloned_router
+        let key = Word().fake::<String>();
+        let name = Paragraph(2..10).fake::<String>();
+        let visits: i64 = (0..1000).fake();
+
+        let response = router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/increment"))
+                    .method(http::Method::POST)
+                    .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "increment": &increment_value }).to_string(),
+                        serde_json::json!({
+                            "key": &key,
+                            "value": { "name": &name, "visits": visits }
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.status(), StatusCode::CREATED);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(0));
+        assert_eq!(
+            body.value,
+            Value::Object(BTreeMap::from([
+                ("name".to_string(), Value::String(name)),
+                ("visits".to_string(), Value::Integer(visits)),
+            ]))
+        );
     }
 
     #[tokio::test]
-    async fn increment_200_min_plus1() {
+    async fn create_201_string() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -2765,10 +2905,9 @@ mod tests {
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
-        let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = 0;
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
@@ -2794,43 +2933,61 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value));
+        assert_eq!(body.value, Value::String(value));
+    }
 
-        let increment_value = i64::MIN + 1;
+    #[tokio::test]
+    async fn create_201_authentication() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
 
-        let response = cloned_router
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/increment"))
+                    .method(http::Method::POST)
+                    .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
-                        serde_json::json!({ "increment": &increment_value }).to_string(),
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.status(), StatusCode::CREATED);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(i64::MAX));
+        assert_eq!(body.value, Value::String(value));
     }
 
     #[tokio::test]
-    async fn increment_200_authentication() {
-        let db_config = DbConfig::default();
+    async fn create_201_expire_at() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = 100;
+        let value = Paragraph(2..10).fake::<String>();
+        let expire_at = Utc::now() + Duration::seconds(1000);
 
         let response = router
             .oneshot(
@@ -2838,9 +2995,9 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
+                            "expire_at": expire_at,
                             "key": &key,
                             "value": &value
                         })
@@ -2853,24 +3010,13 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value));
-
-        let increment_value: i64 = 50;
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/increment"))
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}/ttl"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
-                    .body(Body::from(
-                        serde_json::json!({ "increment": &increment_value }).to_string(),
-                    ))
+                    .body(Body::empty())
                     .unwrap(),
             )
             .await
@@ -2879,14 +3025,13 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+        let body: ValueTtlResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value + increment_value.abs()));
+        assert_eq!(body.expire_at, Some(expire_at));
     }
 
     #[tokio::test]
-    async fn increment_200_no_increment_value() {
+    async fn create_201_ttl_ms() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -2897,7 +3042,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = 100;
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
@@ -2908,6 +3053,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
+                            "ttl_ms": 100000,
                             "value": &value
                         })
                         .to_string(),
@@ -2919,19 +3065,13 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value));
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/increment"))
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}/ttl"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(serde_json::json!({}).to_string()))
+                    .body(Body::empty())
                     .unwrap(),
             )
             .await
@@ -2940,22 +3080,25 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+        let body: ValueTtlResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value + 1));
+        let ttl_ms = body.ttl_ms.unwrap();
+
+        assert!(ttl_ms > 90000 && ttl_ms <= 100000);
     }
 
     #[tokio::test]
-    async fn increment_401() {
-        let db_config = DbConfig::default();
+    async fn create_400_expiration() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
-        let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = 100;
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
@@ -2963,10 +3106,11 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
+                            "ttl": 100,
+                            "ttl_ms": 100000,
                             "value": &value
                         })
                         .to_string(),
@@ -2976,35 +3120,46 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::CREATED);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
 
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value));
+    #[tokio::test]
+    async fn create_400_expire_at() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
 
-        let increment_value: i64 = 50;
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
 
-        let response = cloned_router
+        let response = router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/increment"))
+                    .method(http::Method::POST)
+                    .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "increment": &increment_value }).to_string(),
+                        serde_json::json!({
+                            "expire_at": "3000-01-01T00:00:00Z",
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
     #[tokio::test]
-    async fn increment_404() {
+    async fn create_400_ttl_ms() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -3014,27 +3169,63 @@ mod tests {
         let router = app.router;
 
         let key = Word().fake::<String>();
-        let increment_value: i64 = 50;
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/increment"))
+                    .method(http::Method::POST)
+                    .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "increment": &increment_value }).to_string(),
+                        serde_json::json!({
+                            "key": &key,
+                            "ttl_ms": i64::MAX,
+                            "value": &value
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
     #[tokio::test]
-    async fn increment_409() {
+    async fn create_401() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn create_409() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -3073,16 +3264,20 @@ mod tests {
         assert_eq!(body.key, key);
         assert_eq!(body.value, Value::String(value));
 
-        let increment_value: i64 = 50;
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/increment"))
+                    .method(http::Method::POST)
+                    .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "increment": &increment_value }).to_string(),
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
@@ -3093,7 +3288,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn increment_422() {
+    async fn decrement_200() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -3132,28 +3327,33 @@ mod tests {
         assert_eq!(body.key, key);
         assert_eq!(body.value, Value::Integer(value));
 
+        let decrement_value: i64 = 50;
+
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/increment"))
+                    .uri(format!("/values/{key}/decrement"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({
-                            "increment": "wrong_value"
-                        })
-                        .to_string(),
+                        serde_json::json!({ "decrement": &decrement_value }).to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::Integer(value - decrement_value.abs()));
     }
 
     #[tokio::test]
-    async fn list_one_200() {
+    async fn decrement_200_max() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -3164,7 +3364,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
+        let value = 0;
 
         let response = router
             .oneshot(
@@ -3190,15 +3390,19 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
+        assert_eq!(body.value, Value::Integer(value));
+
+        let decrement_value = i64::MAX;
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::GET)
-                    .uri("/values")
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/decrement"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::empty())
+                    .body(Body::from(
+                        serde_json::json!({ "decrement": &decrement_value }).to_string(),
+                    ))
                     .unwrap(),
             )
             .await
@@ -3207,13 +3411,14 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<ValueResponse> = serde_json::from_slice(&body).unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body.len(), 1);
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::Integer(i64::MIN + 1));
     }
 
     #[tokio::test]
-    async fn list_two_200() {
+    async fn decrement_200_min() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -3222,10 +3427,9 @@ mod tests {
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
-        let second_cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
+        let value = 0;
 
         let response = router
             .oneshot(
@@ -3251,74 +3455,84 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
+        assert_eq!(body.value, Value::Integer(value));
 
-        let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
+        let decrement_value = i64::MIN;
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::POST)
-                    .uri("/values")
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/decrement"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({
-                            "key": &key,
-                            "value": &value
-                        })
-                        .to_string(),
+                        serde_json::json!({ "decrement": &decrement_value }).to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
+        assert_eq!(body.value, Value::Integer(0));
+    }
 
-        let response = second_cloned_router
+    #[tokio::test]
+    async fn decrement_200_min_plus_1() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = 0;
+
+        let response = router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::GET)
+                    .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::empty())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.status(), StatusCode::CREATED);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<ValueResponse> = serde_json::from_slice(&body).unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body.len(), 2);
-    }
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::Integer(value));
 
-    #[tokio::test]
-    async fn list_empty_200() {
-        let db_config = DbConfig {
-            enable_security_api_keys: false,
-            ..Default::default()
-        };
-        let config = Config::new(db_config, 10240);
-        let app = app::get_app(config).await.unwrap();
-        let router = app.router;
+        let decrement_value = i64::MIN + 1;
 
-        let response = router
+        let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::GET)
-                    .uri("/values")
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/decrement"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::empty())
+                    .body(Body::from(
+                        serde_json::json!({ "decrement": &decrement_value }).to_string(),
+                    ))
                     .unwrap(),
             )
             .await
@@ -3327,63 +3541,78 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<ValueResponse> = serde_json::from_slice(&body).unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body.len(), 0);
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::Integer(i64::MIN + 1));
     }
 
     #[tokio::test]
-    async fn list_empty_200_authentication() {
+    async fn decrement_200_authentication() {
         let db_config = DbConfig::default();
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = 100;
 
         let response = router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::GET)
+                    .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
-                    .body(Body::empty())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.status(), StatusCode::CREATED);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<ValueResponse> = serde_json::from_slice(&body).unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body.len(), 0);
-    }
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::Integer(value));
 
-    #[tokio::test]
-    async fn list_empty_401() {
-        let db_config = DbConfig::default();
-        let config = Config::new(db_config, 10240);
-        let app = app::get_app(config).await.unwrap();
-        let router = app.router;
+        let decrement_value: i64 = 50;
 
-        let response = router
+        let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::GET)
-                    .uri("/values")
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/decrement"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::empty())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::from(
+                        serde_json::json!({ "decrement": &decrement_value }).to_string(),
+                    ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::Integer(value - decrement_value.abs()));
     }
 
     #[tokio::test]
-    async fn pop_back_200_array_boolean() {
+    async fn decrement_200_no_decrement_value() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -3394,9 +3623,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value1: bool = Faker.fake();
-        let value2: bool = Faker.fake();
-        let value_array = vec![value1, value2];
+        let value = 100;
 
         let response = router
             .oneshot(
@@ -3407,7 +3634,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -3422,25 +3649,15 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::Boolean(value1),
-                Value::Boolean(value2)
-            ]))
-        );
-
-        let pop_back_value = 1;
+        assert_eq!(body.value, Value::Integer(value));
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-back"))
+                    .uri(format!("/values/{key}/decrement"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(
-                        serde_json::json!({ "pop_back": &pop_back_value }).to_string(),
-                    ))
+                    .body(Body::from(serde_json::json!({}).to_string()))
                     .unwrap(),
             )
             .await
@@ -3449,13 +3666,14 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body, vec![Value::Boolean(value2)]);
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::Integer(value - 1));
     }
 
     #[tokio::test]
-    async fn pop_back_200_array_boolean_more() {
+    async fn decrement_200_create() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -3464,23 +3682,19 @@ mod tests {
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
-
         let key = Word().fake::<String>();
-        let value1: bool = Faker.fake();
-        let value2: bool = Faker.fake();
-        let value3: bool = Faker.fake();
-        let value_array = vec![value1, value2, value3];
 
-        let response = router
+        let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::POST)
-                    .uri("/values")
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/decrement"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
                         serde_json::json!({
-                            "key": &key,
-                            "value": &value_array
+                            "create": true,
+                            "decrement": 5,
+                            "ttl": 100
                         })
                         .to_string(),
                     ))
@@ -3489,32 +3703,22 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::Boolean(value1),
-                Value::Boolean(value2),
-                Value::Boolean(value3)
-            ]))
-        );
-
-        let pop_back_value = 2;
+        assert_eq!(body.value, Value::Integer(-5));
+        assert_eq!(body.version, 1);
 
-        let response = cloned_router
+        let response = router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-back"))
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}/ttl"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(
-                        serde_json::json!({ "pop_back": &pop_back_value }).to_string(),
-                    ))
+                    .body(Body::empty())
                     .unwrap(),
             )
             .await
@@ -3523,26 +3727,21 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+        let body: ValueTtlResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body, vec![Value::Boolean(value3), Value::Boolean(value2)]);
+        assert!(body.ttl.is_some());
     }
 
     #[tokio::test]
-    async fn pop_back_200_array_float() {
-        let db_config = DbConfig {
-            enable_security_api_keys: false,
-            ..Default::default()
-        };
+    async fn decrement_401() {
+        let db_config = DbConfig::default();
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value1 = 1.5;
-        let value2 = 1.75;
-        let value_array = vec![value1, value2];
+        let value = 100;
 
         let response = router
             .oneshot(
@@ -3550,10 +3749,11 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -3568,33 +3768,59 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Float(value1), Value::Float(value2)]))
-        );
+        assert_eq!(body.value, Value::Integer(value));
+
+        let decrement_value: i64 = 50;
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-back"))
+                    .uri(format!("/values/{key}/decrement"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(serde_json::json!({}).to_string()))
+                    .body(Body::from(
+                        serde_json::json!({ "decrement": &decrement_value }).to_string(),
+                    ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+    #[tokio::test]
+    async fn decrement_404() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
 
-        assert_eq!(body, vec![Value::Float(value2)]);
+        let key = Word().fake::<String>();
+        let decrement_value: i64 = 50;
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/decrement"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "decrement": &decrement_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 
     #[tokio::test]
-    async fn pop_back_200_array_integer() {
+    async fn decrement_409() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -3605,9 +3831,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value1: i64 = Faker.fake();
-        let value2: i64 = Faker.fake();
-        let value_array = vec![value1, value2];
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
@@ -3618,7 +3842,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -3633,36 +3857,29 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::Integer(value1),
-                Value::Integer(value2)
-            ]))
-        );
+        assert_eq!(body.value, Value::String(value));
+
+        let decrement_value: i64 = 50;
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-back"))
+                    .uri(format!("/values/{key}/decrement"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(serde_json::json!({}).to_string()))
+                    .body(Body::from(
+                        serde_json::json!({ "decrement": &decrement_value }).to_string(),
+                    ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body, vec![Value::Integer(value2)]);
+        assert_eq!(response.status(), StatusCode::CONFLICT);
     }
 
     #[tokio::test]
-    async fn pop_back_200_array_float_more() {
+    async fn decrement_422() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -3673,11 +3890,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value1 = 1.5;
-        let value2 = 1.75;
-        let value3 = 2.0;
-        let value4 = 2.25;
-        let value_array = vec![value1, value2, value3, value4];
+        let value = 100;
 
         let response = router
             .oneshot(
@@ -3688,7 +3901,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -3703,49 +3916,30 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::Float(value1),
-                Value::Float(value2),
-                Value::Float(value3),
-                Value::Float(value4)
-            ]))
-        );
-
-        let pop_back_value = 3;
+        assert_eq!(body.value, Value::Integer(value));
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-back"))
+                    .uri(format!("/values/{key}/decrement"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "pop_back": &pop_back_value }).to_string(),
+                        serde_json::json!({
+                            "decrement": "wrong_value"
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(
-            body,
-            vec![
-                Value::Float(value4),
-                Value::Float(value3),
-                Value::Float(value2)
-            ]
-        );
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
     }
 
     #[tokio::test]
-    async fn pop_back_200_array_integer_more() {
+    async fn delete_204() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -3756,11 +3950,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value1: i64 = Faker.fake();
-        let value2: i64 = Faker.fake();
-        let value3: i64 = Faker.fake();
-        let value4: i64 = Faker.fake();
-        let value_array = vec![value1, value2, value3, value4];
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
@@ -3771,7 +3961,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -3786,62 +3976,33 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::Integer(value1),
-                Value::Integer(value2),
-                Value::Integer(value3),
-                Value::Integer(value4)
-            ]))
-        );
-
-        let pop_back_value = 3;
+        assert_eq!(body.value, Value::String(value));
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-back"))
+                    .method(http::Method::DELETE)
+                    .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(
-                        serde_json::json!({ "pop_back": &pop_back_value }).to_string(),
-                    ))
+                    .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(
-            body,
-            vec![
-                Value::Integer(value4),
-                Value::Integer(value3),
-                Value::Integer(value2)
-            ]
-        );
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
     }
 
     #[tokio::test]
-    async fn pop_back_200_array_string() {
-        let db_config = DbConfig {
-            enable_security_api_keys: false,
-            ..Default::default()
-        };
+    async fn delete_204_authentication() {
+        let db_config = DbConfig::default();
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value1 = Paragraph(2..10).fake::<String>();
-        let value2 = Paragraph(2..10).fake::<String>();
-        let value_array = vec![value1.clone(), value2.clone()];
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
@@ -3849,10 +4010,11 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -3867,40 +4029,26 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::String(value1.clone()),
-                Value::String(value2.clone())
-            ]))
-        );
-
-        let pop_back_value = 1;
+        assert_eq!(body.value, Value::String(value));
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-back"))
+                    .method(http::Method::DELETE)
+                    .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(
-                        serde_json::json!({ "pop_back": &pop_back_value }).to_string(),
-                    ))
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body, vec![Value::String(value2)]);
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
     }
 
     #[tokio::test]
-    async fn pop_back_200_authentication() {
+    async fn delete_401() {
         let db_config = DbConfig::default();
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
@@ -3908,9 +4056,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value1 = Paragraph(2..10).fake::<String>();
-        let value2 = Paragraph(2..10).fake::<String>();
-        let value_array = vec![value1.clone(), value2.clone()];
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
@@ -3922,7 +4068,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -3937,51 +4083,37 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::String(value1.clone()),
-                Value::String(value2.clone())
-            ]))
-        );
-
-        let pop_back_value = 1;
+        assert_eq!(body.value, Value::String(value));
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-back"))
+                    .method(http::Method::DELETE)
+                    .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
-                    .body(Body::from(
-                        serde_json::json!({ "pop_back": &pop_back_value }).to_string(),
-                    ))
+                    .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body, vec![Value::String(value2)]);
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
     }
 
     #[tokio::test]
-    async fn pop_back_401() {
-        let db_config = DbConfig::default();
+    async fn delete_404() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
+        let second_cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value1 = Paragraph(2..10).fake::<String>();
-        let value2 = Paragraph(2..10).fake::<String>();
-        let value_array = vec![value1.clone(), value2.clone()];
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
@@ -3989,11 +4121,10 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -4008,55 +4139,29 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::String(value1.clone()),
-                Value::String(value2.clone())
-            ]))
-        );
-
-        let pop_back_value = 1;
+        assert_eq!(body.value, Value::String(value));
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-back"))
+                    .method(http::Method::DELETE)
+                    .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(
-                        serde_json::json!({ "pop_back": &pop_back_value }).to_string(),
-                    ))
+                    .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
-    }
-
-    #[tokio::test]
-    async fn pop_back_404() {
-        let db_config = DbConfig {
-            enable_security_api_keys: false,
-            ..Default::default()
-        };
-        let config = Config::new(db_config, 10240);
-        let app = app::get_app(config).await.unwrap();
-        let router = app.router;
-
-        let key = Word().fake::<String>();
-        let pop_back_value = 1;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
 
-        let response = router
+        let response = second_cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-back"))
+                    .method(http::Method::DELETE)
+                    .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(
-                        serde_json::json!({ "pop_back": &pop_back_value }).to_string(),
-                    ))
+                    .body(Body::empty())
                     .unwrap(),
             )
             .await
@@ -4066,7 +4171,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn pop_back_409() {
+    async fn delete_412() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -4075,9 +4180,10 @@ mod tests {
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
+        let cloned_router2 = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
+        let value = Word().fake::<String>();
 
         let response = router
             .oneshot(
@@ -4099,33 +4205,38 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value.clone()));
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::DELETE)
+                    .uri(format!("/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header(http::header::IF_MATCH, "\"2\"")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
 
-        let pop_back_value = 1;
+        assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
 
-        let response = cloned_router
+        let response = cloned_router2
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-back"))
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(
-                        serde_json::json!({ "pop_back": &pop_back_value }).to_string(),
-                    ))
+                    .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert_eq!(response.status(), StatusCode::OK);
     }
 
     #[tokio::test]
-    async fn pop_back_422() {
+    async fn delete_field_200() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -4136,8 +4247,8 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
-        let value_array = vec![value.clone()];
+        let name = Paragraph(2..10).fake::<String>();
+        let visits: i64 = (0..1000).fake();
 
         let response = router
             .oneshot(
@@ -4148,7 +4259,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": serde_json::json!({ "name": &name, "visits": visits })
                         })
                         .to_string(),
                     ))
@@ -4159,36 +4270,30 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::String(value)]))
-        );
-
-        let value = Word().fake::<String>();
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-back"))
+                    .method(http::Method::DELETE)
+                    .uri(format!("/values/{key}/fields/name"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(
-                        serde_json::json!({ "pop_back": &value }).to_string(),
-                    ))
+                    .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueFieldResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.field, "name");
+        assert_eq!(body.value, Value::String(name));
+        assert_eq!(body.version, 2);
     }
 
     #[tokio::test]
-    async fn pop_front_200_array_boolean() {
+    async fn delete_field_404() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -4199,9 +4304,8 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value1: bool = Faker.fake();
-        let value2: bool = Faker.fake();
-        let value_array = vec![value1, value2];
+        let name = Paragraph(2..10).fake::<String>();
+        let visits: i64 = (0..1000).fake();
 
         let response = router
             .oneshot(
@@ -4212,7 +4316,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": serde_json::json!({ "name": &name, "visits": visits })
                         })
                         .to_string(),
                     ))
@@ -4223,44 +4327,23 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::Boolean(value1),
-                Value::Boolean(value2)
-            ]))
-        );
-
-        let pop_front_value = 1;
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-front"))
+                    .method(http::Method::DELETE)
+                    .uri(format!("/values/{key}/fields/wrong_field"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(
-                        serde_json::json!({ "pop_front": &pop_front_value }).to_string(),
-                    ))
+                    .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body, vec![Value::Boolean(value1)]);
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 
     #[tokio::test]
-    async fn pop_front_200_array_boolean_more() {
+    async fn delete_ttl_200() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -4271,10 +4354,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value1: bool = Faker.fake();
-        let value2: bool = Faker.fake();
-        let value3: bool = Faker.fake();
-        let value_array = vec![value1, value2, value3];
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
@@ -4285,7 +4365,8 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "ttl": 100,
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -4296,30 +4377,13 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::Boolean(value1),
-                Value::Boolean(value2),
-                Value::Boolean(value3)
-            ]))
-        );
-
-        let pop_front_value = 2;
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-front"))
+                    .method(http::Method::DELETE)
+                    .uri(format!("/values/{key}/ttl"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(
-                        serde_json::json!({ "pop_front": &pop_front_value }).to_string(),
-                    ))
+                    .body(Body::empty())
                     .unwrap(),
             )
             .await
@@ -4328,26 +4392,23 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+        let body: ValueTtlResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body, vec![Value::Boolean(value1), Value::Boolean(value2)]);
+        assert_eq!(body.key, key);
+        assert_eq!(body.expire_at, None);
+        assert_eq!(body.ttl, None);
     }
 
     #[tokio::test]
-    async fn pop_front_200_array_float() {
-        let db_config = DbConfig {
-            enable_security_api_keys: false,
-            ..Default::default()
-        };
+    async fn delete_ttl_401() {
+        let db_config = DbConfig::default();
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value1 = 1.5;
-        let value2 = 1.75;
-        let value_array = vec![value1, value2];
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
@@ -4355,10 +4416,12 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "ttl": 100,
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -4369,37 +4432,50 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Float(value1), Value::Float(value2)]))
-        );
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-front"))
+                    .method(http::Method::DELETE)
+                    .uri(format!("/values/{key}/ttl"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(serde_json::json!({}).to_string()))
+                    .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+    #[tokio::test]
+    async fn delete_ttl_404() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
 
-        assert_eq!(body, vec![Value::Float(value1)]);
+        let key = Word().fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::DELETE)
+                    .uri(format!("/values/{key}/ttl"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 
     #[tokio::test]
-    async fn pop_front_200_array_integer() {
+    async fn increment_200() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -4410,9 +4486,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value1: i64 = Faker.fake();
-        let value2: i64 = Faker.fake();
-        let value_array = vec![value1, value2];
+        let value = 100;
 
         let response = router
             .oneshot(
@@ -4423,7 +4497,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -4438,21 +4512,19 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::Integer(value1),
-                Value::Integer(value2)
-            ]))
-        );
+        assert_eq!(body.value, Value::Integer(value));
+
+        let increment_value: i64 = 50;
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-front"))
+                    .uri(format!("/values/{key}/increment"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(serde_json::json!({}).to_string()))
+                    .body(Body::from(
+                        serde_json::json!({ "increment": &increment_value }).to_string(),
+                    ))
                     .unwrap(),
             )
             .await
@@ -4461,13 +4533,14 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body, vec![Value::Integer(value1)]);
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::Integer(value + increment_value.abs()));
     }
 
     #[tokio::test]
-    async fn pop_front_200_array_float_more() {
+    async fn increment_200_max() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -4478,11 +4551,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value1 = 1.5;
-        let value2 = 1.75;
-        let value3 = 2.0;
-        let value4 = 2.25;
-        let value_array = vec![value1, value2, value3, value4];
+        let value = 0;
 
         let response = router
             .oneshot(
@@ -4493,7 +4562,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -4508,26 +4577,18 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::Float(value1),
-                Value::Float(value2),
-                Value::Float(value3),
-                Value::Float(value4)
-            ]))
-        );
+        assert_eq!(body.value, Value::Integer(value));
 
-        let pop_front_value = 3;
+        let increment_value = i64::MAX;
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-front"))
+                    .uri(format!("/values/{key}/increment"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "pop_front": &pop_front_value }).to_string(),
+                        serde_json::json!({ "increment": &increment_value }).to_string(),
                     ))
                     .unwrap(),
             )
@@ -4537,20 +4598,14 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(
-            body,
-            vec![
-                Value::Float(value1),
-                Value::Float(value2),
-                Value::Float(value3)
-            ]
-        );
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::Integer(i64::MAX));
     }
 
     #[tokio::test]
-    async fn pop_front_200_array_integer_more() {
+    async fn increment_200_min() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -4561,11 +4616,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value1: i64 = Faker.fake();
-        let value2: i64 = Faker.fake();
-        let value3: i64 = Faker.fake();
-        let value4: i64 = Faker.fake();
-        let value_array = vec![value1, value2, value3, value4];
+        let value = 0;
 
         let response = router
             .oneshot(
@@ -4576,7 +4627,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -4591,26 +4642,18 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::Integer(value1),
-                Value::Integer(value2),
-                Value::Integer(value3),
-                Value::Integer(value4)
-            ]))
-        );
+        assert_eq!(body.value, Value::Integer(value));
 
-        let pop_front_value = 3;
+        let increment_value = i64::MIN;
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-front"))
+                    .uri(format!("/values/{key}/increment"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "pop_front": &pop_front_value }).to_string(),
+                        serde_json::json!({ "increment": &increment_value }).to_string(),
                     ))
                     .unwrap(),
             )
@@ -4620,20 +4663,14 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(
-            body,
-            vec![
-                Value::Integer(value1),
-                Value::Integer(value2),
-                Value::Integer(value3)
-            ]
-        );
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::Integer(0));
     }
 
     #[tokio::test]
-    async fn pop_front_200_array_string() {
+    async fn increment_200_min_plus1() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -4644,9 +4681,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value1 = Paragraph(2..10).fake::<String>();
-        let value2 = Paragraph(2..10).fake::<String>();
-        let value_array = vec![value1.clone(), value2.clone()];
+        let value = 0;
 
         let response = router
             .oneshot(
@@ -4657,7 +4692,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -4672,24 +4707,18 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::String(value1.clone()),
-                Value::String(value2.clone())
-            ]))
-        );
+        assert_eq!(body.value, Value::Integer(value));
 
-        let pop_front_value = 1;
+        let increment_value = i64::MIN + 1;
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-front"))
+                    .uri(format!("/values/{key}/increment"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "pop_front": &pop_front_value }).to_string(),
+                        serde_json::json!({ "increment": &increment_value }).to_string(),
                     ))
                     .unwrap(),
             )
@@ -4699,13 +4728,14 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body, vec![Value::String(value1)]);
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::Integer(i64::MAX));
     }
 
     #[tokio::test]
-    async fn pop_front_200_authentication() {
+    async fn increment_200_authentication() {
         let db_config = DbConfig::default();
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
@@ -4713,9 +4743,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value1 = Paragraph(2..10).fake::<String>();
-        let value2 = Paragraph(2..10).fake::<String>();
-        let value_array = vec![value1.clone(), value2.clone()];
+        let value = 100;
 
         let response = router
             .oneshot(
@@ -4727,7 +4755,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -4742,25 +4770,19 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::String(value1.clone()),
-                Value::String(value2.clone())
-            ]))
-        );
+        assert_eq!(body.value, Value::Integer(value));
 
-        let pop_front_value = 1;
+        let increment_value: i64 = 50;
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-front"))
+                    .uri(format!("/values/{key}/increment"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
-                        serde_json::json!({ "pop_front": &pop_front_value }).to_string(),
+                        serde_json::json!({ "increment": &increment_value }).to_string(),
                     ))
                     .unwrap(),
             )
@@ -4770,23 +4792,25 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body, vec![Value::String(value1)]);
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::Integer(value + increment_value.abs()));
     }
 
     #[tokio::test]
-    async fn pop_front_401() {
-        let db_config = DbConfig::default();
+    async fn increment_200_no_increment_value() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value1 = Paragraph(2..10).fake::<String>();
-        let value2 = Paragraph(2..10).fake::<String>();
-        let value_array = vec![value1.clone(), value2.clone()];
+        let value = 100;
 
         let response = router
             .oneshot(
@@ -4794,11 +4818,10 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -4813,35 +4836,31 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::String(value1.clone()),
-                Value::String(value2.clone())
-            ]))
-        );
-
-        let pop_front_value = 1;
+        assert_eq!(body.value, Value::Integer(value));
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-front"))
+                    .uri(format!("/values/{key}/increment"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(
-                        serde_json::json!({ "pop_front": &pop_front_value }).to_string(),
-                    ))
+                    .body(Body::from(serde_json::json!({}).to_string()))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::Integer(value + 1));
     }
 
     #[tokio::test]
-    async fn pop_front_404() {
+    async fn increment_200_create() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -4849,29 +4868,59 @@ mod tests {
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
-
+        let cloned_router = router.clone();
         let key = Word().fake::<String>();
-        let pop_front_value = 1;
 
-        let response = router
+        let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-front"))
+                    .uri(format!("/values/{key}/increment"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "pop_front": &pop_front_value }).to_string(),
+                        serde_json::json!({
+                            "create": true,
+                            "increment": 5,
+                            "ttl": 100
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::Integer(5));
+        assert_eq!(body.version, 1);
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}/ttl"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueTtlResponse = serde_json::from_slice(&body).unwrap();
+
+        assert!(body.ttl.is_some());
     }
 
     #[tokio::test]
-    async fn pop_front_409() {
+    async fn increment_400_ttl() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -4879,10 +4928,39 @@ mod tests {
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
+        let key = Word().fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/increment"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "create": true,
+                            "ttl": i64::MAX
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn increment_401() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
+        let value = 100;
 
         let response = router
             .oneshot(
@@ -4890,6 +4968,7 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
@@ -4908,29 +4987,59 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value.clone()));
+        assert_eq!(body.value, Value::Integer(value));
 
-        let pop_front_value = 1;
+        let increment_value: i64 = 50;
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-front"))
+                    .uri(format!("/values/{key}/increment"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "pop_front": &pop_front_value }).to_string(),
+                        serde_json::json!({ "increment": &increment_value }).to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
     }
 
     #[tokio::test]
-    async fn pop_front_422() {
+    async fn increment_404() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let key = Word().fake::<String>();
+        let increment_value: i64 = 50;
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/increment"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "increment": &increment_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn increment_409() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -4942,7 +5051,6 @@ mod tests {
 
         let key = Word().fake::<String>();
         let value = Paragraph(2..10).fake::<String>();
-        let value_array = vec![value.clone()];
 
         let response = router
             .oneshot(
@@ -4953,7 +5061,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -4968,32 +5076,29 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::String(value)]))
-        );
+        assert_eq!(body.value, Value::String(value));
 
-        let value = Word().fake::<String>();
+        let increment_value: i64 = 50;
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/pop-front"))
+                    .uri(format!("/values/{key}/increment"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "pop_front": &value }).to_string(),
+                        serde_json::json!({ "increment": &increment_value }).to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(response.status(), StatusCode::CONFLICT);
     }
 
     #[tokio::test]
-    async fn prepend_200_array_array_boolean() {
+    async fn increment_422() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -5004,8 +5109,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value: bool = Faker.fake();
-        let value_array = vec![vec![value]];
+        let value = 100;
 
         let response = router
             .oneshot(
@@ -5016,7 +5120,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -5031,47 +5135,30 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
-                Value::Boolean(value)
-            ]))]))
-        );
-
-        let prepend_value: bool = Faker.fake();
-        let prepend_value_array = vec![vec![prepend_value]];
+        assert_eq!(body.value, Value::Integer(value));
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/prepend"))
+                    .uri(format!("/values/{key}/increment"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                        serde_json::json!({
+                            "increment": "wrong_value"
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::Array(VecDeque::from([Value::Boolean(prepend_value)])),
-                Value::Array(VecDeque::from([Value::Boolean(value)]))
-            ]))
-        );
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
     }
 
     #[tokio::test]
-    async fn prepend_200_array_array_float() {
+    async fn increment_field_200() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -5082,8 +5169,8 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = 1.5;
-        let value_array = vec![vec![value]];
+        let name = Paragraph(2..10).fake::<String>();
+        let visits: i64 = (0..1000).fake();
 
         let response = router
             .oneshot(
@@ -5094,7 +5181,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": serde_json::json!({ "name": &name, "visits": visits })
                         })
                         .to_string(),
                     ))
@@ -5105,28 +5192,16 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
-                Value::Float(value)
-            ]))]))
-        );
-
-        let prepend_value = 1.75;
-        let prepend_value_array = vec![vec![prepend_value]];
+        let increment_value: i64 = -50;
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/prepend"))
+                    .uri(format!("/values/{key}/fields/visits/increment"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                        serde_json::json!({ "increment": &increment_value }).to_string(),
                     ))
                     .unwrap(),
             )
@@ -5136,20 +5211,230 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+        let body: ValueFieldResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.field, "visits");
+        assert_eq!(body.value, Value::Integer(visits + increment_value));
+    }
+
+    #[tokio::test]
+    async fn increment_field_409() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let name = Paragraph(2..10).fake::<String>();
+        let visits: i64 = (0..1000).fake();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": serde_json::json!({ "name": &name, "visits": visits })
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/fields/name/increment"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "increment": 1 }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn list_cursor_200() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        for key in ["test_key1", "test_key2", "test_key3"] {
+            let response = router
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .method(http::Method::POST)
+                        .uri("/values")
+                        .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                        .body(Body::from(
+                            serde_json::json!({
+                                "key": key,
+                                "value": 10
+                            })
+                            .to_string(),
+                        ))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::CREATED);
+        }
+
+        let response = router
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/values?sort=key&limit=2")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let next_cursor = response.headers()[NEXT_CURSOR]
+            .to_str()
+            .unwrap()
+            .to_string();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<ValueResponse> = serde_json::from_slice(&body).unwrap();
+        let keys = body
+            .iter()
+            .map(|value| value.key.as_str())
+            .collect::<Vec<&str>>();
+
+        assert_eq!(keys, vec!["test_key1", "test_key2"]);
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values?sort=key&limit=2&cursor={next_cursor}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get(NEXT_CURSOR).is_none());
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<ValueResponse> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.len(), 1);
+        assert_eq!(body[0].key, "test_key3");
+    }
+
+    #[tokio::test]
+    async fn list_cursor_400() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/values?cursor=wrong_cursor")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn list_fields_200() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let name = Paragraph(2..10).fake::<String>();
+        let visits: i64 = (0..1000).fake();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": serde_json::json!({ "name": &name, "visits": visits })
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}/fields"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueFieldsResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::Array(VecDeque::from([Value::Float(prepend_value)])),
-                Value::Array(VecDeque::from([Value::Float(value)]))
-            ]))
-        );
+        assert_eq!(body.fields, vec!["name".to_string(), "visits".to_string()]);
     }
 
     #[tokio::test]
-    async fn prepend_200_array_array_integer() {
+    async fn list_fields_409() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -5160,8 +5445,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value: i64 = Faker.fake();
-        let value_array = vec![vec![value]];
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
@@ -5172,7 +5456,56 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}/fields"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn list_one_200() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -5187,47 +5520,4277 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
-                Value::Integer(value)
-            ]))]))
-        );
+        assert_eq!(body.value, Value::String(value));
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<ValueResponse> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn list_pattern_200() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        for key in ["user:1:session", "user:1:token", "user:2:session", "users"] {
+            let response = router
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .method(http::Method::POST)
+                        .uri("/values")
+                        .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                        .body(Body::from(
+                            serde_json::json!({
+                                "key": key,
+                                "value": 10
+                            })
+                            .to_string(),
+                        ))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::CREATED);
+        }
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/values?sort=key&pattern=user:*:session")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<ValueResponse> = serde_json::from_slice(&body).unwrap();
+        let keys = body
+            .iter()
+            .map(|value| value.key.as_str())
+            .collect::<Vec<&str>>();
+
+        assert_eq!(keys, vec!["user:1:session", "user:2:session"]);
+    }
+
+    #[tokio::test]
+    async fn list_prefix_200() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        for key in ["user:1:session", "user:1:token", "user:2:session", "users"] {
+            let response = router
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .method(http::Method::POST)
+                        .uri("/values")
+                        .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                        .body(Body::from(
+                            serde_json::json!({
+                                "key": key,
+                                "value": 10
+                            })
+                            .to_string(),
+                        ))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::CREATED);
+        }
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/values?sort=key&direction=desc&prefix=user:")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<ValueResponse> = serde_json::from_slice(&body).unwrap();
+        let keys = body
+            .iter()
+            .map(|value| value.key.as_str())
+            .collect::<Vec<&str>>();
+
+        assert_eq!(
+            keys,
+            vec!["user:2:session", "user:1:token", "user:1:session"]
+        );
+    }
+
+    #[tokio::test]
+    async fn list_time_range_200() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        for (key, ttl) in [("test_key1", 10), ("test_key2", 1000)] {
+            let response = router
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .method(http::Method::POST)
+                        .uri("/values")
+                        .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                        .body(Body::from(
+                            serde_json::json!({
+                                "key": key,
+                                "ttl": ttl,
+                                "value": 10
+                            })
+                            .to_string(),
+                        ))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::CREATED);
+        }
+
+        let ends_at =
+            (Utc::now() + Duration::seconds(100)).to_rfc3339_opts(SecondsFormat::Secs, true);
+
+        let response = router
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values?sort=delete_at&ends_at={ends_at}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<ValueResponse> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.len(), 1);
+        assert_eq!(body[0].key, "test_key1");
+
+        let starts_at =
+            (Utc::now() + Duration::seconds(100)).to_rfc3339_opts(SecondsFormat::Secs, true);
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values?sort=delete_at&starts_at={starts_at}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<ValueResponse> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.len(), 1);
+        assert_eq!(body[0].key, "test_key2");
+    }
+
+    #[tokio::test]
+    async fn list_two_200() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+        let second_cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::String(value));
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::String(value));
+
+        let response = second_cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<ValueResponse> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn list_empty_200() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<ValueResponse> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn list_empty_200_authentication() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<ValueResponse> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn list_empty_401() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn pop_back_200_array_boolean() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value1: bool = Faker.fake();
+        let value2: bool = Faker.fake();
+        let value_array = vec![value1, value2];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::Boolean(value1),
+                Value::Boolean(value2)
+            ]))
+        );
+
+        let pop_back_value = 1;
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-back"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_back": &pop_back_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body, vec![Value::Boolean(value2)]);
+    }
+
+    #[tokio::test]
+    async fn pop_back_200_array_boolean_more() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value1: bool = Faker.fake();
+        let value2: bool = Faker.fake();
+        let value3: bool = Faker.fake();
+        let value_array = vec![value1, value2, value3];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::Boolean(value1),
+                Value::Boolean(value2),
+                Value::Boolean(value3)
+            ]))
+        );
+
+        let pop_back_value = 2;
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-back"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_back": &pop_back_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body, vec![Value::Boolean(value3), Value::Boolean(value2)]);
+    }
+
+    #[tokio::test]
+    async fn pop_back_200_array_float() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value1 = 1.5;
+        let value2 = 1.75;
+        let value_array = vec![value1, value2];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::Float(value1), Value::Float(value2)]))
+        );
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-back"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(serde_json::json!({}).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body, vec![Value::Float(value2)]);
+    }
+
+    #[tokio::test]
+    async fn pop_back_200_array_integer() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value1: i64 = Faker.fake();
+        let value2: i64 = Faker.fake();
+        let value_array = vec![value1, value2];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::Integer(value1),
+                Value::Integer(value2)
+            ]))
+        );
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-back"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(serde_json::json!({}).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body, vec![Value::Integer(value2)]);
+    }
+
+    #[tokio::test]
+    async fn pop_back_200_array_float_more() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value1 = 1.5;
+        let value2 = 1.75;
+        let value3 = 2.0;
+        let value4 = 2.25;
+        let value_array = vec![value1, value2, value3, value4];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::Float(value1),
+                Value::Float(value2),
+                Value::Float(value3),
+                Value::Float(value4)
+            ]))
+        );
+
+        let pop_back_value = 3;
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-back"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_back": &pop_back_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(
+            body,
+            vec![
+                Value::Float(value4),
+                Value::Float(value3),
+                Value::Float(value2)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn pop_back_200_array_integer_more() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value1: i64 = Faker.fake();
+        let value2: i64 = Faker.fake();
+        let value3: i64 = Faker.fake();
+        let value4: i64 = Faker.fake();
+        let value_array = vec![value1, value2, value3, value4];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::Integer(value1),
+                Value::Integer(value2),
+                Value::Integer(value3),
+                Value::Integer(value4)
+            ]))
+        );
+
+        let pop_back_value = 3;
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-back"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_back": &pop_back_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(
+            body,
+            vec![
+                Value::Integer(value4),
+                Value::Integer(value3),
+                Value::Integer(value2)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn pop_back_200_array_string() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value1 = Paragraph(2..10).fake::<String>();
+        let value2 = Paragraph(2..10).fake::<String>();
+        let value_array = vec![value1.clone(), value2.clone()];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::String(value1.clone()),
+                Value::String(value2.clone())
+            ]))
+        );
+
+        let pop_back_value = 1;
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-back"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_back": &pop_back_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body, vec![Value::String(value2)]);
+    }
+
+    #[tokio::test]
+    async fn pop_back_200_authentication() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value1 = Paragraph(2..10).fake::<String>();
+        let value2 = Paragraph(2..10).fake::<String>();
+        let value_array = vec![value1.clone(), value2.clone()];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::String(value1.clone()),
+                Value::String(value2.clone())
+            ]))
+        );
+
+        let pop_back_value = 1;
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-back"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_back": &pop_back_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body, vec![Value::String(value2)]);
+    }
+
+    #[tokio::test]
+    async fn pop_back_401() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value1 = Paragraph(2..10).fake::<String>();
+        let value2 = Paragraph(2..10).fake::<String>();
+        let value_array = vec![value1.clone(), value2.clone()];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::String(value1.clone()),
+                Value::String(value2.clone())
+            ]))
+        );
+
+        let pop_back_value = 1;
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-back"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_back": &pop_back_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn pop_back_404() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let key = Word().fake::<String>();
+        let pop_back_value = 1;
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-back"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_back": &pop_back_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn pop_back_409() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::String(value.clone()));
+
+        let pop_back_value = 1;
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-back"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_back": &pop_back_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn pop_back_422() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+        let value_array = vec![value.clone()];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::String(value)]))
+        );
+
+        let value = Word().fake::<String>();
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-back"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_back": &value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn pop_front_200_array_boolean() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value1: bool = Faker.fake();
+        let value2: bool = Faker.fake();
+        let value_array = vec![value1, value2];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::Boolean(value1),
+                Value::Boolean(value2)
+            ]))
+        );
+
+        let pop_front_value = 1;
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-front"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_front": &pop_front_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body, vec![Value::Boolean(value1)]);
+    }
+
+    #[tokio::test]
+    async fn pop_front_200_array_boolean_more() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value1: bool = Faker.fake();
+        let value2: bool = Faker.fake();
+        let value3: bool = Faker.fake();
+        let value_array = vec![value1, value2, value3];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::Boolean(value1),
+                Value::Boolean(value2),
+                Value::Boolean(value3)
+            ]))
+        );
+
+        let pop_front_value = 2;
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-front"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_front": &pop_front_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body, vec![Value::Boolean(value1), Value::Boolean(value2)]);
+    }
+
+    #[tokio::test]
+    async fn pop_front_200_array_float() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value1 = 1.5;
+        let value2 = 1.75;
+        let value_array = vec![value1, value2];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::Float(value1), Value::Float(value2)]))
+        );
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-front"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(serde_json::json!({}).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body, vec![Value::Float(value1)]);
+    }
+
+    #[tokio::test]
+    async fn pop_front_200_array_integer() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value1: i64 = Faker.fake();
+        let value2: i64 = Faker.fake();
+        let value_array = vec![value1, value2];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::Integer(value1),
+                Value::Integer(value2)
+            ]))
+        );
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-front"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(serde_json::json!({}).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body, vec![Value::Integer(value1)]);
+    }
+
+    #[tokio::test]
+    async fn pop_front_200_array_float_more() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value1 = 1.5;
+        let value2 = 1.75;
+        let value3 = 2.0;
+        let value4 = 2.25;
+        let value_array = vec![value1, value2, value3, value4];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::Float(value1),
+                Value::Float(value2),
+                Value::Float(value3),
+                Value::Float(value4)
+            ]))
+        );
+
+        let pop_front_value = 3;
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-front"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_front": &pop_front_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(
+            body,
+            vec![
+                Value::Float(value1),
+                Value::Float(value2),
+                Value::Float(value3)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn pop_front_200_array_integer_more() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value1: i64 = Faker.fake();
+        let value2: i64 = Faker.fake();
+        let value3: i64 = Faker.fake();
+        let value4: i64 = Faker.fake();
+        let value_array = vec![value1, value2, value3, value4];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::Integer(value1),
+                Value::Integer(value2),
+                Value::Integer(value3),
+                Value::Integer(value4)
+            ]))
+        );
+
+        let pop_front_value = 3;
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-front"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_front": &pop_front_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(
+            body,
+            vec![
+                Value::Integer(value1),
+                Value::Integer(value2),
+                Value::Integer(value3)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn pop_front_200_array_string() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value1 = Paragraph(2..10).fake::<String>();
+        let value2 = Paragraph(2..10).fake::<String>();
+        let value_array = vec![value1.clone(), value2.clone()];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::String(value1.clone()),
+                Value::String(value2.clone())
+            ]))
+        );
+
+        let pop_front_value = 1;
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-front"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_front": &pop_front_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body, vec![Value::String(value1)]);
+    }
+
+    #[tokio::test]
+    async fn pop_front_200_authentication() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value1 = Paragraph(2..10).fake::<String>();
+        let value2 = Paragraph(2..10).fake::<String>();
+        let value_array = vec![value1.clone(), value2.clone()];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::String(value1.clone()),
+                Value::String(value2.clone())
+            ]))
+        );
+
+        let pop_front_value = 1;
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-front"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_front": &pop_front_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Vec<Value> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body, vec![Value::String(value1)]);
+    }
+
+    #[tokio::test]
+    async fn pop_front_401() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value1 = Paragraph(2..10).fake::<String>();
+        let value2 = Paragraph(2..10).fake::<String>();
+        let value_array = vec![value1.clone(), value2.clone()];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::String(value1.clone()),
+                Value::String(value2.clone())
+            ]))
+        );
+
+        let pop_front_value = 1;
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-front"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_front": &pop_front_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn pop_front_404() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let key = Word().fake::<String>();
+        let pop_front_value = 1;
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-front"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_front": &pop_front_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn pop_front_409() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::String(value.clone()));
+
+        let pop_front_value = 1;
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-front"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_front": &pop_front_value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn pop_front_422() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+        let value_array = vec![value.clone()];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::String(value)]))
+        );
+
+        let value = Word().fake::<String>();
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/pop-front"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "pop_front": &value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn prepend_200_array_array_boolean() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value: bool = Faker.fake();
+        let value_array = vec![vec![value]];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
+                Value::Boolean(value)
+            ]))]))
+        );
+
+        let prepend_value: bool = Faker.fake();
+        let prepend_value_array = vec![vec![prepend_value]];
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/prepend"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::Array(VecDeque::from([Value::Boolean(prepend_value)])),
+                Value::Array(VecDeque::from([Value::Boolean(value)]))
+            ]))
+        );
+    }
+
+    #[tokio::test]
+    async fn prepend_200_array_array_float() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = 1.5;
+        let value_array = vec![vec![value]];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
+                Value::Float(value)
+            ]))]))
+        );
+
+        let prepend_value = 1.75;
+        let prepend_value_array = vec![vec![prepend_value]];
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/prepend"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::Array(VecDeque::from([Value::Float(prepend_value)])),
+                Value::Array(VecDeque::from([Value::Float(value)]))
+            ]))
+        );
+    }
+
+    #[tokio::test]
+    async fn prepend_200_array_array_integer() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value: i64 = Faker.fake();
+        let value_array = vec![vec![value]];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
+                Value::Integer(value)
+            ]))]))
+        );
+
+        let prepend_value: i64 = Faker.fake();
+        let prepend_value_array = vec![vec![prepend_value]];
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/prepend"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::Array(VecDeque::from([Value::Integer(prepend_value)])),
+                Value::Array(VecDeque::from([Value::Integer(value)]))
+            ]))
+        );
+    }
+
+    #[tokio::test]
+    async fn prepend_200_array_boolean() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value: bool = Faker.fake();
+        let value_array = vec![value];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::Boolean(value)]))
+        );
+
+        let prepend_value: bool = Faker.fake();
+        let prepend_value_array = vec![prepend_value];
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/prepend"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::Boolean(prepend_value),
+                Value::Boolean(value)
+            ]))
+        );
+    }
+
+    #[tokio::test]
+    async fn prepend_200_array_boolean_and_float() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value: bool = Faker.fake();
+        let value_array = vec![value];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::Boolean(value)]))
+        );
+
+        let prepend_value = 1.5;
+        let prepend_value_array = vec![prepend_value];
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/prepend"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::Float(prepend_value),
+                Value::Boolean(value)
+            ]))
+        );
+    }
+
+    #[tokio::test]
+    async fn prepend_200_array_boolean_and_integer() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value: bool = Faker.fake();
+        let value_array = vec![value];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::Boolean(value)]))
+        );
+
+        let prepend_value: i64 = Faker.fake();
+        let prepend_value_array = vec![prepend_value];
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/prepend"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::Integer(prepend_value),
+                Value::Boolean(value)
+            ]))
+        );
+    }
+
+    #[tokio::test]
+    async fn prepend_200_array_float() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = 1.5;
+        let value_array = vec![value];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::Float(value)]))
+        );
+
+        let prepend_value = 1.75;
+        let prepend_value_array = vec![prepend_value];
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/prepend"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::Float(prepend_value),
+                Value::Float(value)
+            ]))
+        );
+    }
+
+    #[tokio::test]
+    async fn prepend_200_array_integer() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value: i64 = Faker.fake();
+        let value_array = vec![value];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::Integer(value)]))
+        );
+
+        let prepend_value: i64 = Faker.fake();
+        let prepend_value_array = vec![prepend_value];
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/prepend"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::Integer(prepend_value),
+                Value::Integer(value)
+            ]))
+        );
+    }
+
+    #[tokio::test]
+    async fn prepend_200_array_float_and_string() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = 1.5;
+        let value_array = vec![value];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::Float(value)]))
+        );
+
+        let prepend_value = Paragraph(2..10).fake::<String>();
+        let prepend_value_array = vec![prepend_value.clone()];
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/prepend"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::String(prepend_value),
+                Value::Float(value)
+            ]))
+        );
+    }
+
+    #[tokio::test]
+    async fn prepend_200_array_integer_and_string() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value: i64 = Faker.fake();
+        let value_array = vec![value];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::Integer(value)]))
+        );
+
+        let prepend_value = Paragraph(2..10).fake::<String>();
+        let prepend_value_array = vec![prepend_value.clone()];
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/prepend"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::String(prepend_value),
+                Value::Integer(value)
+            ]))
+        );
+    }
+
+    #[tokio::test]
+    async fn prepend_200_array_string() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+        let value_array = vec![value.clone()];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::String(value.clone())]))
+        );
+
+        let prepend_value = Paragraph(2..10).fake::<String>();
+        let prepend_value_array = vec![prepend_value.clone()];
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/prepend"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::String(prepend_value),
+                Value::String(value)
+            ]))
+        );
+    }
+
+    #[tokio::test]
+    async fn prepend_200_array_string_and_boolean() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+        let value_array = vec![value.clone()];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::String(value.clone())]))
+        );
+
+        let prepend_value: bool = Faker.fake();
+        let prepend_value_array = vec![prepend_value];
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/prepend"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::Boolean(prepend_value),
+                Value::String(value)
+            ]))
+        );
+    }
+
+    #[tokio::test]
+    async fn prepend_200_authentication() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+        let value_array = vec![value.clone()];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::String(value.clone())]))
+        );
+
+        let prepend_value = Paragraph(2..10).fake::<String>();
+        let prepend_value_array = vec![prepend_value.clone()];
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/prepend"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::from(
+                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([
+                Value::String(prepend_value),
+                Value::String(value)
+            ]))
+        );
+    }
+
+    #[tokio::test]
+    async fn prepend_200_create() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/prepend"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "create": true,
+                            "prepend": [&value],
+                            "ttl": 100
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::String(value)]))
+        );
+        assert_eq!(body.version, 1);
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}/ttl"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueTtlResponse = serde_json::from_slice(&body).unwrap();
+
+        assert!(body.ttl.is_some());
+    }
+
+    #[tokio::test]
+    async fn prepend_401() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+        let value_array = vec![value.clone()];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value_array
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(
+            body.value,
+            Value::Array(VecDeque::from([Value::String(value)]))
+        );
+
+        let prepend_value = Paragraph(2..10).fake::<String>();
+        let prepend_value_array = vec![prepend_value.clone()];
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/prepend"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn prepend_404() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let key = Word().fake::<String>();
+        let prepend_value = Paragraph(2..10).fake::<String>();
+        let prepend_value_array = vec![prepend_value.clone()];
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/prepend"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn prepend_409() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::String(value));
+
+        let prepend_value = Paragraph(2..10).fake::<String>();
+        let prepend_value_array = vec![prepend_value.clone()];
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/prepend"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn read_200() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::String(value.clone()));
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::String(value));
+    }
+
+    #[tokio::test]
+    async fn read_200_batch_key() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = "batch-get".to_string();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::String(value.clone()));
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::String(value));
+    }
+
+    #[tokio::test]
+    async fn read_200_authentication() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::String(value.clone()));
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::String(value));
+    }
+
+    #[tokio::test]
+    async fn read_401() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::String(value));
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn read_404() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let key = Word().fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn read_404_expired() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "ttl": 0,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn read_field_200() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let name = Paragraph(2..10).fake::<String>();
+        let visits: i64 = (0..1000).fake();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": serde_json::json!({ "name": &name, "visits": visits })
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}/fields/name"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[http::header::ETAG], "\"1\"");
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueFieldResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.field, "name");
+        assert_eq!(body.value, Value::String(name));
+    }
+
+    #[tokio::test]
+    async fn read_field_404() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let name = Paragraph(2..10).fake::<String>();
+        let visits: i64 = (0..1000).fake();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": serde_json::json!({ "name": &name, "visits": visits })
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}/fields/wrong_field"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn read_ttl_200() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "ttl": 100,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}/ttl"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueTtlResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert!(body.expire_at.is_some());
+        assert!(body.ttl.unwrap() > 90);
+    }
+
+    #[tokio::test]
+    async fn read_ttl_200_no_ttl() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}/ttl"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueTtlResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.expire_at, None);
+        assert_eq!(body.ttl, None);
+    }
+
+    #[tokio::test]
+    async fn read_ttl_401() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "ttl": 100,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}/ttl"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn read_ttl_404() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+
+        let key = Word().fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}/ttl"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn rename_200() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+        let cloned_router2 = router.clone();
+        let cloned_router3 = router.clone();
+        let key = Word().fake::<String>();
+        let new_key = format!("{key}-new");
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router2
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/rename"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "to": &new_key
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, new_key);
+        assert_eq!(body.value, Value::String(value));
+
+        let response = cloned_router3
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{new_key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn rename_200_concurrent_read() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let key = Word().fake::<String>();
+        let keys = (0..=500)
+            .map(|i| format!("{key}-{i}"))
+            .collect::<Vec<String>>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &keys[0],
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        // A key missing after a rename must already be readable under one of the following names.
+        let db = app.keyspaces.default_keyspace();
+        let read_keys = keys.clone();
+        let reads = std::thread::spawn(move || {
+            let mut current = 0;
+            while current < read_keys.len() - 1 {
+                while db.try_read(&read_keys[current]).unwrap().is_none() {
+                    current += 1;
+                    assert!(current < read_keys.len());
+                }
+            }
+        });
+
+        for pair in keys.windows(2) {
+            let response = router
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .method(http::Method::PUT)
+                        .uri(format!("/values/{}/rename", pair[0]))
+                        .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                        .body(Body::from(
+                            serde_json::json!({
+                                "to": &pair[1]
+                            })
+                            .to_string(),
+                        ))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        reads.join().unwrap();
+    }
+
+    #[tokio::test]
+    async fn rename_200_overwrite() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+        let cloned_router2 = router.clone();
+        let cloned_router3 = router.clone();
+        let key = Word().fake::<String>();
+        let new_key = format!("{key}-new");
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router2
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &new_key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router3
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/rename"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "overwrite": true,
+                            "to": &new_key
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{new_key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.value, Value::String(value));
+    }
+
+    #[tokio::test]
+    async fn rename_400() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let key = Word().fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/rename"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "to": ""
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn rename_401() {
+        let db_config = DbConfig::default();
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+        let key = Word().fake::<String>();
+        let new_key = format!("{key}-new");
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/rename"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "to": &new_key
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn rename_404() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let key = Word().fake::<String>();
+        let new_key = format!("{key}-new");
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/rename"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "to": &new_key
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn rename_409() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
+        let cloned_router = router.clone();
+        let cloned_router2 = router.clone();
+        let key = Word().fake::<String>();
+        let new_key = format!("{key}-new");
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = cloned_router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = cloned_router2
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/values")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "key": &new_key,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
 
-        let prepend_value: i64 = Faker.fake();
-        let prepend_value_array = vec![vec![prepend_value]];
+        assert_eq!(response.status(), StatusCode::CREATED);
 
-        let response = cloned_router
+        let response = router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/prepend"))
+                    .uri(format!("/values/{key}/rename"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                        serde_json::json!({
+                            "to": &new_key
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::Array(VecDeque::from([Value::Integer(prepend_value)])),
-                Value::Array(VecDeque::from([Value::Integer(value)]))
-            ]))
-        );
+        assert_eq!(response.status(), StatusCode::CONFLICT);
     }
 
     #[tokio::test]
-    async fn prepend_200_array_boolean() {
+    async fn update_200_array_array_boolean() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -5239,7 +9802,7 @@ mod tests {
 
         let key = Word().fake::<String>();
         let value: bool = Faker.fake();
-        let value_array = vec![value];
+        let value_array = vec![vec![value]];
 
         let response = router
             .oneshot(
@@ -5267,20 +9830,22 @@ mod tests {
         assert_eq!(body.key, key);
         assert_eq!(
             body.value,
-            Value::Array(VecDeque::from([Value::Boolean(value)]))
+            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
+                Value::Boolean(value)
+            ]))]))
         );
 
-        let prepend_value: bool = Faker.fake();
-        let prepend_value_array = vec![prepend_value];
+        let value: bool = Faker.fake();
+        let value_array = vec![vec![value]];
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/prepend"))
+                    .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                        serde_json::json!({ "value": &value_array }).to_string(),
                     ))
                     .unwrap(),
             )
@@ -5295,15 +9860,14 @@ mod tests {
         assert_eq!(body.key, key);
         assert_eq!(
             body.value,
-            Value::Array(VecDeque::from([
-                Value::Boolean(prepend_value),
+            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
                 Value::Boolean(value)
-            ]))
+            ]))]))
         );
     }
 
     #[tokio::test]
-    async fn prepend_200_array_boolean_and_float() {
+    async fn update_200_array_array_float() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -5314,8 +9878,8 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value: bool = Faker.fake();
-        let value_array = vec![value];
+        let value = 1.5;
+        let value_array = vec![vec![value]];
 
         let response = router
             .oneshot(
@@ -5343,20 +9907,22 @@ mod tests {
         assert_eq!(body.key, key);
         assert_eq!(
             body.value,
-            Value::Array(VecDeque::from([Value::Boolean(value)]))
+            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
+                Value::Float(value)
+            ]))]))
         );
 
-        let prepend_value = 1.5;
-        let prepend_value_array = vec![prepend_value];
+        let value = 1.5;
+        let value_array = vec![vec![value]];
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/prepend"))
+                    .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                        serde_json::json!({ "value": &value_array }).to_string(),
                     ))
                     .unwrap(),
             )
@@ -5371,15 +9937,14 @@ mod tests {
         assert_eq!(body.key, key);
         assert_eq!(
             body.value,
-            Value::Array(VecDeque::from([
-                Value::Float(prepend_value),
-                Value::Boolean(value)
-            ]))
+            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
+                Value::Float(value)
+            ]))]))
         );
     }
 
     #[tokio::test]
-    async fn prepend_200_array_boolean_and_integer() {
+    async fn update_200_array_array_integer() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -5390,8 +9955,8 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value: bool = Faker.fake();
-        let value_array = vec![value];
+        let value: i64 = Faker.fake();
+        let value_array = vec![vec![value]];
 
         let response = router
             .oneshot(
@@ -5419,20 +9984,22 @@ mod tests {
         assert_eq!(body.key, key);
         assert_eq!(
             body.value,
-            Value::Array(VecDeque::from([Value::Boolean(value)]))
+            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
+                Value::Integer(value)
+            ]))]))
         );
 
-        let prepend_value: i64 = Faker.fake();
-        let prepend_value_array = vec![prepend_value];
+        let value: i64 = Faker.fake();
+        let value_array = vec![vec![value]];
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/prepend"))
+                    .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                        serde_json::json!({ "value": &value_array }).to_string(),
                     ))
                     .unwrap(),
             )
@@ -5447,15 +10014,14 @@ mod tests {
         assert_eq!(body.key, key);
         assert_eq!(
             body.value,
-            Value::Array(VecDeque::from([
-                Value::Integer(prepend_value),
-                Value::Boolean(value)
-            ]))
+            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
+                Value::Integer(value)
+            ]))]))
         );
     }
 
     #[tokio::test]
-    async fn prepend_200_array_float() {
+    async fn update_200_array_array_string() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -5466,8 +10032,8 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = 1.5;
-        let value_array = vec![value];
+        let value = Paragraph(2..10).fake::<String>();
+        let value_array = vec![vec![value.clone()]];
 
         let response = router
             .oneshot(
@@ -5495,20 +10061,22 @@ mod tests {
         assert_eq!(body.key, key);
         assert_eq!(
             body.value,
-            Value::Array(VecDeque::from([Value::Float(value)]))
+            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
+                Value::String(value)
+            ]))]))
         );
 
-        let prepend_value = 1.75;
-        let prepend_value_array = vec![prepend_value];
+        let value = Paragraph(2..10).fake::<String>();
+        let value_array = vec![vec![value.clone()]];
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/prepend"))
+                    .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                        serde_json::json!({ "value": &value_array }).to_string(),
                     ))
                     .unwrap(),
             )
@@ -5523,15 +10091,14 @@ mod tests {
         assert_eq!(body.key, key);
         assert_eq!(
             body.value,
-            Value::Array(VecDeque::from([
-                Value::Float(prepend_value),
-                Value::Float(value)
-            ]))
+            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
+                Value::String(value)
+            ]))]))
         );
     }
 
     #[tokio::test]
-    async fn prepend_200_array_integer() {
+    async fn update_200_array_boolean() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -5542,7 +10109,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value: i64 = Faker.fake();
+        let value: bool = Faker.fake();
         let value_array = vec![value];
 
         let response = router
@@ -5571,20 +10138,20 @@ mod tests {
         assert_eq!(body.key, key);
         assert_eq!(
             body.value,
-            Value::Array(VecDeque::from([Value::Integer(value)]))
+            Value::Array(VecDeque::from([Value::Boolean(value)]))
         );
 
-        let prepend_value: i64 = Faker.fake();
-        let prepend_value_array = vec![prepend_value];
+        let value: bool = Faker.fake();
+        let value_array = vec![value];
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/prepend"))
+                    .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                        serde_json::json!({ "value": &value_array }).to_string(),
                     ))
                     .unwrap(),
             )
@@ -5599,15 +10166,12 @@ mod tests {
         assert_eq!(body.key, key);
         assert_eq!(
             body.value,
-            Value::Array(VecDeque::from([
-                Value::Integer(prepend_value),
-                Value::Integer(value)
-            ]))
+            Value::Array(VecDeque::from([Value::Boolean(value)]))
         );
     }
 
     #[tokio::test]
-    async fn prepend_200_array_float_and_string() {
+    async fn update_200_array_float() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -5650,17 +10214,17 @@ mod tests {
             Value::Array(VecDeque::from([Value::Float(value)]))
         );
 
-        let prepend_value = Paragraph(2..10).fake::<String>();
-        let prepend_value_array = vec![prepend_value.clone()];
+        let value = 1.5;
+        let value_array = vec![value];
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/prepend"))
+                    .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                        serde_json::json!({ "value": &value_array }).to_string(),
                     ))
                     .unwrap(),
             )
@@ -5675,15 +10239,12 @@ mod tests {
         assert_eq!(body.key, key);
         assert_eq!(
             body.value,
-            Value::Array(VecDeque::from([
-                Value::String(prepend_value),
-                Value::Float(value)
-            ]))
+            Value::Array(VecDeque::from([Value::Float(value)]))
         );
     }
 
     #[tokio::test]
-    async fn prepend_200_array_integer_and_string() {
+    async fn update_200_array_integer() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -5726,17 +10287,17 @@ mod tests {
             Value::Array(VecDeque::from([Value::Integer(value)]))
         );
 
-        let prepend_value = Paragraph(2..10).fake::<String>();
-        let prepend_value_array = vec![prepend_value.clone()];
+        let value: i64 = Faker.fake();
+        let value_array = vec![value];
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/prepend"))
+                    .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                        serde_json::json!({ "value": &value_array }).to_string(),
                     ))
                     .unwrap(),
             )
@@ -5751,15 +10312,12 @@ mod tests {
         assert_eq!(body.key, key);
         assert_eq!(
             body.value,
-            Value::Array(VecDeque::from([
-                Value::String(prepend_value),
-                Value::Integer(value)
-            ]))
+            Value::Array(VecDeque::from([Value::Integer(value)]))
         );
     }
 
     #[tokio::test]
-    async fn prepend_200_array_string() {
+    async fn update_200_array_string() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -5799,20 +10357,20 @@ mod tests {
         assert_eq!(body.key, key);
         assert_eq!(
             body.value,
-            Value::Array(VecDeque::from([Value::String(value.clone())]))
+            Value::Array(VecDeque::from([Value::String(value)]))
         );
 
-        let prepend_value = Paragraph(2..10).fake::<String>();
-        let prepend_value_array = vec![prepend_value.clone()];
+        let value = Paragraph(2..10).fake::<String>();
+        let value_array = vec![value.clone()];
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/prepend"))
+                    .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                        serde_json::json!({ "value": &value_array }).to_string(),
                     ))
                     .unwrap(),
             )
@@ -5827,15 +10385,12 @@ mod tests {
         assert_eq!(body.key, key);
         assert_eq!(
             body.value,
-            Value::Array(VecDeque::from([
-                Value::String(prepend_value),
-                Value::String(value)
-            ]))
+            Value::Array(VecDeque::from([Value::String(value)]))
         );
     }
 
     #[tokio::test]
-    async fn prepend_200_array_string_and_boolean() {
+    async fn update_200_boolean() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -5846,8 +10401,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
-        let value_array = vec![value.clone()];
+        let value = Faker.fake();
 
         let response = router
             .oneshot(
@@ -5858,7 +10412,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -5873,22 +10427,18 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::String(value.clone())]))
-        );
+        assert_eq!(body.value, Value::Boolean(value));
 
-        let prepend_value: bool = Faker.fake();
-        let prepend_value_array = vec![prepend_value];
+        let value = Faker.fake();
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/prepend"))
+                    .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                        serde_json::json!({ "value": &value }).to_string(),
                     ))
                     .unwrap(),
             )
@@ -5901,26 +10451,22 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::Boolean(prepend_value),
-                Value::String(value)
-            ]))
-        );
+        assert_eq!(body.value, Value::Boolean(value));
     }
 
     #[tokio::test]
-    async fn prepend_200_authentication() {
-        let db_config = DbConfig::default();
+    async fn update_200_boolean_to_float() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
-        let value_array = vec![value.clone()];
+        let value = Faker.fake();
 
         let response = router
             .oneshot(
@@ -5928,11 +10474,10 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -5947,23 +10492,18 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::String(value.clone())]))
-        );
+        assert_eq!(body.value, Value::Boolean(value));
 
-        let prepend_value = Paragraph(2..10).fake::<String>();
-        let prepend_value_array = vec![prepend_value.clone()];
+        let value = 1.5;
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/prepend"))
+                    .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
-                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                        serde_json::json!({ "value": &value }).to_string(),
                     ))
                     .unwrap(),
             )
@@ -5976,26 +10516,22 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([
-                Value::String(prepend_value),
-                Value::String(value)
-            ]))
-        );
+        assert_eq!(body.value, Value::Float(value));
     }
 
     #[tokio::test]
-    async fn prepend_401() {
-        let db_config = DbConfig::default();
+    async fn update_200_boolean_to_integer() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
-        let value_array = vec![value.clone()];
+        let value = Faker.fake();
 
         let response = router
             .oneshot(
@@ -6003,11 +10539,10 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -6022,64 +10557,35 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::String(value)]))
-        );
+        assert_eq!(body.value, Value::Boolean(value));
 
-        let prepend_value = Paragraph(2..10).fake::<String>();
-        let prepend_value_array = vec![prepend_value.clone()];
+        let value = Faker.fake();
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/prepend"))
+                    .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                        serde_json::json!({ "value": &value }).to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
-    }
-
-    #[tokio::test]
-    async fn prepend_404() {
-        let db_config = DbConfig {
-            enable_security_api_keys: false,
-            ..Default::default()
-        };
-        let config = Config::new(db_config, 10240);
-        let app = app::get_app(config).await.unwrap();
-        let router = app.router;
-
-        let key = Word().fake::<String>();
-        let prepend_value = Paragraph(2..10).fake::<String>();
-        let prepend_value_array = vec![prepend_value.clone()];
+        assert_eq!(response.status(), StatusCode::OK);
 
-        let response = router
-            .oneshot(
-                Request::builder()
-                    .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/prepend"))
-                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(
-                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
-                    ))
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::Integer(value));
     }
 
     #[tokio::test]
-    async fn prepend_409() {
+    async fn update_200_float() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -6090,7 +10596,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
+        let value = 1.5;
 
         let response = router
             .oneshot(
@@ -6116,30 +10622,35 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
+        assert_eq!(body.value, Value::Float(value));
 
-        let prepend_value = Paragraph(2..10).fake::<String>();
-        let prepend_value_array = vec![prepend_value.clone()];
+        let value = 1.5;
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}/prepend"))
+                    .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "prepend": &prepend_value_array }).to_string(),
+                        serde_json::json!({ "value": &value }).to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::Float(value));
     }
 
     #[tokio::test]
-    async fn read_200() {
+    async fn update_200_integer() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -6150,7 +10661,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
+        let value = Faker.fake();
 
         let response = router
             .oneshot(
@@ -6176,15 +10687,19 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value.clone()));
+        assert_eq!(body.value, Value::Integer(value));
+
+        let value = Faker.fake();
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::GET)
+                    .method(http::Method::PUT)
                     .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::empty())
+                    .body(Body::from(
+                        serde_json::json!({ "value": &value }).to_string(),
+                    ))
                     .unwrap(),
             )
             .await
@@ -6196,19 +10711,22 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
+        assert_eq!(body.value, Value::Integer(value));
     }
 
     #[tokio::test]
-    async fn read_200_authentication() {
-        let db_config = DbConfig::default();
+    async fn update_200_float_to_boolean() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
+        let value = 1.5;
 
         let response = router
             .oneshot(
@@ -6216,7 +10734,6 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
@@ -6235,16 +10752,19 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value.clone()));
+        assert_eq!(body.value, Value::Float(value));
+
+        let value = Faker.fake();
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::GET)
+                    .method(http::Method::PUT)
                     .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
-                    .body(Body::empty())
+                    .body(Body::from(
+                        serde_json::json!({ "value": &value }).to_string(),
+                    ))
                     .unwrap(),
             )
             .await
@@ -6256,19 +10776,22 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
+        assert_eq!(body.value, Value::Boolean(value));
     }
 
     #[tokio::test]
-    async fn read_401() {
-        let db_config = DbConfig::default();
+    async fn update_200_integer_to_boolean() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
+        let value = Faker.fake();
 
         let response = router
             .oneshot(
@@ -6276,7 +10799,6 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
@@ -6295,52 +10817,35 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
+        assert_eq!(body.value, Value::Integer(value));
+
+        let value = Faker.fake();
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::GET)
+                    .method(http::Method::PUT)
                     .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::empty())
+                    .body(Body::from(
+                        serde_json::json!({ "value": &value }).to_string(),
+                    ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
-    }
-
-    #[tokio::test]
-    async fn read_404() {
-        let db_config = DbConfig {
-            enable_security_api_keys: false,
-            ..Default::default()
-        };
-        let config = Config::new(db_config, 10240);
-        let app = app::get_app(config).await.unwrap();
-        let router = app.router;
-
-        let key = Word().fake::<String>();
+        assert_eq!(response.status(), StatusCode::OK);
 
-        let response = router
-            .oneshot(
-                Request::builder()
-                    .method(http::Method::GET)
-                    .uri(format!("/values/{key}"))
-                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::empty())
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::Boolean(value));
     }
 
     #[tokio::test]
-    async fn update_200_array_array_boolean() {
+    async fn update_200_string() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -6351,8 +10856,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value: bool = Faker.fake();
-        let value_array = vec![vec![value]];
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
@@ -6363,7 +10867,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -6378,15 +10882,9 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
-                Value::Boolean(value)
-            ]))]))
-        );
+        assert_eq!(body.value, Value::String(value));
 
-        let value: bool = Faker.fake();
-        let value_array = vec![vec![value]];
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = cloned_router
             .oneshot(
@@ -6395,7 +10893,7 @@ mod tests {
                     .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "value": &value_array }).to_string(),
+                        serde_json::json!({ "value": &value }).to_string(),
                     ))
                     .unwrap(),
             )
@@ -6408,16 +10906,11 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
-                Value::Boolean(value)
-            ]))]))
-        );
+        assert_eq!(body.value, Value::String(value));
     }
 
     #[tokio::test]
-    async fn update_200_array_array_float() {
+    async fn update_200_string_to_boolean() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -6428,8 +10921,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = 1.5;
-        let value_array = vec![vec![value]];
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
@@ -6440,7 +10932,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -6455,15 +10947,9 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
-                Value::Float(value)
-            ]))]))
-        );
+        assert_eq!(body.value, Value::String(value));
 
-        let value = 1.5;
-        let value_array = vec![vec![value]];
+        let value = Faker.fake();
 
         let response = cloned_router
             .oneshot(
@@ -6472,7 +10958,7 @@ mod tests {
                     .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "value": &value_array }).to_string(),
+                        serde_json::json!({ "value": &value }).to_string(),
                     ))
                     .unwrap(),
             )
@@ -6485,28 +10971,19 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
-                Value::Float(value)
-            ]))]))
-        );
+        assert_eq!(body.value, Value::Boolean(value));
     }
 
     #[tokio::test]
-    async fn update_200_array_array_integer() {
-        let db_config = DbConfig {
-            enable_security_api_keys: false,
-            ..Default::default()
-        };
+    async fn update_200_authentication() {
+        let db_config = DbConfig::default();
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value: i64 = Faker.fake();
-        let value_array = vec![vec![value]];
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
@@ -6514,10 +10991,11 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -6532,15 +11010,9 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
-                Value::Integer(value)
-            ]))]))
-        );
+        assert_eq!(body.value, Value::String(value));
 
-        let value: i64 = Faker.fake();
-        let value_array = vec![vec![value]];
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = cloned_router
             .oneshot(
@@ -6548,8 +11020,9 @@ mod tests {
                     .method(http::Method::PUT)
                     .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
-                        serde_json::json!({ "value": &value_array }).to_string(),
+                        serde_json::json!({ "value": &value }).to_string(),
                     ))
                     .unwrap(),
             )
@@ -6557,21 +11030,16 @@ mod tests {
             .unwrap();
 
         assert_eq!(response.status(), StatusCode::OK);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
-                Value::Integer(value)
-            ]))]))
-        );
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(body.key, key);
+        assert_eq!(body.value, Value::String(value));
     }
 
     #[tokio::test]
-    async fn update_200_array_array_string() {
+    async fn update_200_keep_ttl() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -6580,10 +11048,10 @@ mod tests {
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
+        let cloned_router2 = router.clone();
 
         let key = Word().fake::<String>();
         let value = Paragraph(2..10).fake::<String>();
-        let value_array = vec![vec![value.clone()]];
 
         let response = router
             .oneshot(
@@ -6594,7 +11062,8 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "ttl": 100,
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -6605,20 +11074,6 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
-                Value::String(value)
-            ]))]))
-        );
-
-        let value = Paragraph(2..10).fake::<String>();
-        let value_array = vec![vec![value.clone()]];
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
@@ -6626,7 +11081,11 @@ mod tests {
                     .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "value": &value_array }).to_string(),
+                        serde_json::json!({
+                            "keep_ttl": true,
+                            "value": &value
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
@@ -6635,20 +11094,28 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::OK);
 
+        let response = cloned_router2
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}/ttl"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+        let body: ValueTtlResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Array(VecDeque::from([
-                Value::String(value)
-            ]))]))
-        );
+        assert!(body.ttl.unwrap() > 90);
     }
 
     #[tokio::test]
-    async fn update_200_array_boolean() {
+    async fn update_200_if_match() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -6659,8 +11126,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value: bool = Faker.fake();
-        let value_array = vec![value];
+        let value = Word().fake::<String>();
 
         let response = router
             .oneshot(
@@ -6671,7 +11137,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -6681,18 +11147,7 @@ mod tests {
             .unwrap();
 
         assert_eq!(response.status(), StatusCode::CREATED);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Boolean(value)]))
-        );
-
-        let value: bool = Faker.fake();
-        let value_array = vec![value];
+        assert_eq!(response.headers()[http::header::ETAG], "\"1\"");
 
         let response = cloned_router
             .oneshot(
@@ -6700,8 +11155,12 @@ mod tests {
                     .method(http::Method::PUT)
                     .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header(http::header::IF_MATCH, "\"1\"")
                     .body(Body::from(
-                        serde_json::json!({ "value": &value_array }).to_string(),
+                        serde_json::json!({
+                            "value": 10
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
@@ -6709,19 +11168,17 @@ mod tests {
             .unwrap();
 
         assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[http::header::ETAG], "\"2\"");
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Boolean(value)]))
-        );
+        assert_eq!(body.value, Value::Integer(10));
+        assert_eq!(body.version, 2);
     }
 
     #[tokio::test]
-    async fn update_200_array_float() {
+    async fn update_400_keep_ttl_and_ttl() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -6732,8 +11189,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = 1.5;
-        let value_array = vec![value];
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
@@ -6744,7 +11200,8 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "ttl": 100,
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -6755,18 +11212,6 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Float(value)]))
-        );
-
-        let value = 1.5;
-        let value_array = vec![value];
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
@@ -6774,27 +11219,58 @@ mod tests {
                     .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "value": &value_array }).to_string(),
+                        serde_json::json!({
+                            "keep_ttl": true,
+                            "ttl": 100,
+                            "value": &value
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+    #[tokio::test]
+    async fn update_400_ttl() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
 
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Float(value)]))
-        );
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({
+                            "ttl": i64::MAX,
+                            "upsert": true,
+                            "value": &value
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
     #[tokio::test]
-    async fn update_200_array_integer() {
+    async fn update_201_upsert() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -6803,21 +11279,19 @@ mod tests {
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
-
         let key = Word().fake::<String>();
-        let value: i64 = Faker.fake();
-        let value_array = vec![value];
+        let value = Paragraph(2..10).fake::<String>();
 
-        let response = router
+        let response = cloned_router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::POST)
-                    .uri("/values")
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
                         serde_json::json!({
-                            "key": &key,
-                            "value": &value_array
+                            "upsert": true,
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -6831,23 +11305,21 @@ mod tests {
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Integer(value)]))
-        );
-
-        let value: i64 = Faker.fake();
-        let value_array = vec![value];
+        assert_eq!(body.value, Value::String(value.clone()));
+        assert_eq!(body.version, 1);
 
-        let response = cloned_router
+        let response = router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
                     .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "value": &value_array }).to_string(),
+                        serde_json::json!({
+                            "upsert": true,
+                            "value": &value
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
@@ -6859,19 +11331,12 @@ mod tests {
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::Integer(value)]))
-        );
+        assert_eq!(body.version, 2);
     }
 
     #[tokio::test]
-    async fn update_200_array_string() {
-        let db_config = DbConfig {
-            enable_security_api_keys: false,
-            ..Default::default()
-        };
+    async fn update_401() {
+        let db_config = DbConfig::default();
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
@@ -6879,7 +11344,6 @@ mod tests {
 
         let key = Word().fake::<String>();
         let value = Paragraph(2..10).fake::<String>();
-        let value_array = vec![value.clone()];
 
         let response = router
             .oneshot(
@@ -6887,10 +11351,11 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value_array
+                            "value": &value
                         })
                         .to_string(),
                     ))
@@ -6905,13 +11370,9 @@ mod tests {
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::String(value)]))
-        );
+        assert_eq!(body.value, Value::String(value));
 
         let value = Paragraph(2..10).fake::<String>();
-        let value_array = vec![value.clone()];
 
         let response = cloned_router
             .oneshot(
@@ -6920,27 +11381,48 @@ mod tests {
                     .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "value": &value_array }).to_string(),
+                        serde_json::json!({ "value": &value }).to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+    #[tokio::test]
+    async fn update_404() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
 
-        assert_eq!(body.key, key);
-        assert_eq!(
-            body.value,
-            Value::Array(VecDeque::from([Value::String(value)]))
-        );
+        let key = Word().fake::<String>();
+        let value = Paragraph(2..10).fake::<String>();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(
+                        serde_json::json!({ "value": &value }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 
     #[tokio::test]
-    async fn update_200_boolean() {
+    async fn update_412() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -6949,9 +11431,10 @@ mod tests {
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
+        let cloned_router2 = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Faker.fake();
+        let value = Word().fake::<String>();
 
         let response = router
             .oneshot(
@@ -6973,39 +11456,49 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Boolean(value));
-
-        let value = Faker.fake();
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
                     .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header(http::header::IF_MATCH, "\"2\"")
                     .body(Body::from(
-                        serde_json::json!({ "value": &value }).to_string(),
+                        serde_json::json!({
+                            "value": 10
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
+        assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+
+        let response = cloned_router2
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Boolean(value));
+        assert_eq!(body.value, Value::String(value));
+        assert_eq!(body.version, 1);
     }
 
     #[tokio::test]
-    async fn update_200_boolean_to_float() {
+    async fn update_412_expected_version() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -7016,7 +11509,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Faker.fake();
+        let value = Word().fake::<String>();
 
         let response = router
             .oneshot(
@@ -7038,14 +11531,6 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Boolean(value));
-
-        let value = 1.5;
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
@@ -7053,24 +11538,22 @@ mod tests {
                     .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "value": &value }).to_string(),
+                        serde_json::json!({
+                            "expected_version": 2,
+                            "value": 10
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Float(value));
+        assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
     }
 
     #[tokio::test]
-    async fn update_200_boolean_to_integer() {
+    async fn update_412_weak_if_match() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -7081,7 +11564,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Faker.fake();
+        let value = Word().fake::<String>();
 
         let response = router
             .oneshot(
@@ -7103,39 +11586,29 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Boolean(value));
-
-        let value = Faker.fake();
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
                     .uri(format!("/values/{key}"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .header(http::header::IF_MATCH, "W/\"1\"")
                     .body(Body::from(
-                        serde_json::json!({ "value": &value }).to_string(),
+                        serde_json::json!({
+                            "value": 10
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value));
+        assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
     }
 
     #[tokio::test]
-    async fn update_200_float() {
+    async fn update_field_200() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -7144,9 +11617,11 @@ mod tests {
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
         let cloned_router = router.clone();
+        let cloned_router2 = router.clone();
 
         let key = Word().fake::<String>();
-        let value = 1.5;
+        let name = Paragraph(2..10).fake::<String>();
+        let visits: i64 = (0..1000).fake();
 
         let response = router
             .oneshot(
@@ -7157,7 +11632,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
-                            "value": &value
+                            "value": serde_json::json!({ "name": &name, "visits": visits })
                         })
                         .to_string(),
                     ))
@@ -7168,22 +11643,16 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Float(value));
-
-        let value = 1.5;
+        let city = Word().fake::<String>();
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}"))
+                    .uri(format!("/values/{key}/fields/address"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "value": &value }).to_string(),
+                        serde_json::json!({ "value": { "city": &city } }).to_string(),
                     ))
                     .unwrap(),
             )
@@ -7192,80 +11661,95 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::OK);
 
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: ValueFieldResponse = serde_json::from_slice(&body).unwrap();
+
+        let address = Value::Object(BTreeMap::from([("city".to_string(), Value::String(city))]));
+
+        assert_eq!(body.field, "address");
+        assert_eq!(body.value, address);
+        assert_eq!(body.version, 2);
+
+        let response = cloned_router2
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri(format!("/values/{key}"))
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
         let body: ValueResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Float(value));
+        assert_eq!(
+            body.value,
+            Value::Object(BTreeMap::from([
+                ("address".to_string(), address),
+                ("name".to_string(), Value::String(name)),
+                ("visits".to_string(), Value::Integer(visits)),
+            ]))
+        );
     }
 
     #[tokio::test]
-    async fn update_200_integer() {
-        let db_config = DbConfig {
-            enable_security_api_keys: false,
-            ..Default::default()
-        };
+    async fn update_field_401() {
+        let db_config = DbConfig::default();
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
-        let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Faker.fake();
 
         let response = router
             .oneshot(
                 Request::builder()
-                    .method(http::Method::POST)
-                    .uri("/values")
+                    .method(http::Method::PUT)
+                    .uri(format!("/values/{key}/fields/name"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(
-                        serde_json::json!({
-                            "key": &key,
-                            "value": &value
-                        })
-                        .to_string(),
-                    ))
+                    .body(Body::from(serde_json::json!({ "value": 10 }).to_string()))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::CREATED);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
 
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value));
+    #[tokio::test]
+    async fn update_field_404() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
+        let config = Config::new(db_config, 10240);
+        let app = app::get_app(config).await.unwrap();
+        let router = app.router;
 
-        let value = Faker.fake();
+        let key = Word().fake::<String>();
 
-        let response = cloned_router
+        let response = router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}"))
+                    .uri(format!("/values/{key}/fields/name"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(
-                        serde_json::json!({ "value": &value }).to_string(),
-                    ))
+                    .body(Body::from(serde_json::json!({ "value": 10 }).to_string()))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value));
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 
     #[tokio::test]
-    async fn update_200_float_to_boolean() {
+    async fn update_ttl_200() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -7276,7 +11760,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = 1.5;
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
@@ -7298,22 +11782,17 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Float(value));
-
-        let value = Faker.fake();
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}"))
+                    .uri(format!("/values/{key}/ttl"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "value": &value }).to_string(),
+                        serde_json::json!({
+                            "ttl": 100
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
@@ -7323,14 +11802,15 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+        let body: ValueTtlResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Boolean(value));
+        assert!(body.expire_at.is_some());
+        assert!(body.ttl.unwrap() > 90);
     }
 
     #[tokio::test]
-    async fn update_200_integer_to_boolean() {
+    async fn update_ttl_200_expire_at() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -7341,7 +11821,7 @@ mod tests {
         let cloned_router = router.clone();
 
         let key = Word().fake::<String>();
-        let value = Faker.fake();
+        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
@@ -7352,6 +11832,7 @@ mod tests {
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
+                            "ttl": 100,
                             "value": &value
                         })
                         .to_string(),
@@ -7363,22 +11844,19 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Integer(value));
-
-        let value = Faker.fake();
+        let expire_at = Utc::now() + Duration::seconds(1000);
 
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}"))
+                    .uri(format!("/values/{key}/ttl"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "value": &value }).to_string(),
+                        serde_json::json!({
+                            "expire_at": expire_at
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
@@ -7388,14 +11866,15 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
 
         let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
+        let body: ValueTtlResponse = serde_json::from_slice(&body).unwrap();
 
         assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Boolean(value));
+        assert_eq!(body.expire_at, Some(expire_at));
+        assert!(body.ttl.unwrap() > 990);
     }
 
     #[tokio::test]
-    async fn update_200_string() {
+    async fn update_ttl_400() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -7428,39 +11907,23 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
-
-        let value = Paragraph(2..10).fake::<String>();
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}"))
+                    .uri(format!("/values/{key}/ttl"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .body(Body::from(
-                        serde_json::json!({ "value": &value }).to_string(),
-                    ))
+                    .body(Body::from(serde_json::json!({}).to_string()))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
     #[tokio::test]
-    async fn update_200_string_to_boolean() {
+    async fn update_ttl_400_expire_at() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -7493,40 +11956,32 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
-
-        let value = Faker.fake();
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}"))
+                    .uri(format!("/values/{key}/ttl"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "value": &value }).to_string(),
+                        serde_json::json!({
+                            "expire_at": "3000-01-01T00:00:00Z"
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::Boolean(value));
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
     #[tokio::test]
-    async fn update_200_authentication() {
-        let db_config = DbConfig::default();
+    async fn update_ttl_400_ttl() {
+        let db_config = DbConfig {
+            enable_security_api_keys: false,
+            ..Default::default()
+        };
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
         let router = app.router;
@@ -7541,7 +11996,6 @@ mod tests {
                     .method(http::Method::POST)
                     .uri("/values")
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
                         serde_json::json!({
                             "key": &key,
@@ -7556,40 +12010,28 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
-
-        let value = Paragraph(2..10).fake::<String>();
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}"))
+                    .uri(format!("/values/{key}/ttl"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
-                    .header("X-Auth-Token".to_string(), app.api_key.unwrap().to_string())
                     .body(Body::from(
-                        serde_json::json!({ "value": &value }).to_string(),
+                        serde_json::json!({
+                            "ttl": i64::MAX
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
-
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
     #[tokio::test]
-    async fn update_401() {
+    async fn update_ttl_401() {
         let db_config = DbConfig::default();
         let config = Config::new(db_config, 10240);
         let app = app::get_app(config).await.unwrap();
@@ -7620,22 +12062,17 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
-        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
-        let body: ValueResponse = serde_json::from_slice(&body).unwrap();
-
-        assert_eq!(body.key, key);
-        assert_eq!(body.value, Value::String(value));
-
-        let value = Paragraph(2..10).fake::<String>();
-
         let response = cloned_router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}"))
+                    .uri(format!("/values/{key}/ttl"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "value": &value }).to_string(),
+                        serde_json::json!({
+                            "ttl": 100
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
@@ -7646,7 +12083,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn update_404() {
+    async fn update_ttl_404() {
         let db_config = DbConfig {
             enable_security_api_keys: false,
             ..Default::default()
@@ -7656,16 +12093,18 @@ mod tests {
         let router = app.router;
 
         let key = Word().fake::<String>();
-        let value = Paragraph(2..10).fake::<String>();
 
         let response = router
             .oneshot(
                 Request::builder()
                     .method(http::Method::PUT)
-                    .uri(format!("/values/{key}"))
+                    .uri(format!("/values/{key}/ttl"))
                     .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
                     .body(Body::from(
-                        serde_json::json!({ "value": &value }).to_string(),
+                        serde_json::json!({
+                            "ttl": 100
+                        })
+                        .to_string(),
                     ))
                     .unwrap(),
             )
@@ -1,35 +1,34 @@
-use crate::{api, config::Config, Result};
-use alex_db_lib::db::Db;
+use crate::{api, config::Config, metrics::Metrics, Result};
+use alex_db_lib::keyspaces::Keyspaces;
 use axum::Router;
 use std::sync::Arc;
-use tracing::info;
 use uuid::Uuid;
 
 pub struct App {
+    /// API key created on the first start with API keys enabled.
     pub api_key: Option<Uuid>,
-    pub db: Arc<Db>,
+    pub keyspaces: Arc<Keyspaces>,
     pub router: Router,
 }
 
 pub async fn get_app(config: Config) -> Result<App> {
-    let mut db = Db::new(config.db_config.clone());
-    db.restore()?;
+    let mut keyspaces = Keyspaces::new(config.db_config.clone());
+    keyspaces.restore()?;
     let mut api_key = None;
 
     if config.db_config.enable_security_api_keys {
-        api_key = db.api_key_init()?;
-        if api_key.is_some() {
-            info!("initial api key created: {:?}", api_key);
-        }
+        api_key = keyspaces.default_keyspace().api_key_init()?;
     }
 
-    let db = Arc::new(db);
+    let keyspaces = Arc::new(keyspaces);
 
-    let router = api::router(db.clone()).await;
+    let metrics = Arc::new(Metrics::new(config.metrics_require_api_key));
+
+    let router = api::router(keyspaces.clone(), metrics).await;
 
     let app = App {
         api_key,
-        db,
+        keyspaces,
         router,
     };
 
@@ -1,27 +1,41 @@
 use crate::{Args, Result};
-use alex_db_lib::config::Config as DbConfig;
+use alex_db_lib::{
+    config::Config as DbConfig, encryption::EncryptionKey, snapshot::SnapshotCompression,
+    wal::WalSyncPolicy,
+};
 use tracing::info;
 
 #[derive(Clone, Debug)]
 pub struct Config {
     pub db_config: DbConfig,
+    pub metrics_require_api_key: bool,
     pub port: u16,
 }
 
 impl Config {
     pub fn new(db_config: DbConfig, port: u16) -> Self {
-        Self { db_config, port }
+        Self {
+            db_config,
+            metrics_require_api_key: true,
+            port,
+        }
     }
 }
 
 pub fn load(args: Args) -> Result<Config> {
     let mut data_dir = None;
     let mut enable_security_api_keys = true;
+    let mut encryption_key = None;
+    let mut metrics_require_api_key = true;
     let mut port = 8080;
+    let mut previous_encryption_keys = vec![];
     let mut save_triggered_after_ms = 600000;
     let mut save_triggered_by_threshold = 8;
     let mut sleep_time_between_gc_ms = 1000;
     let mut sleep_time_between_saves_ms = 10000;
+    let mut snapshot_compression = SnapshotCompression::default();
+    let mut use_persisted_indexes = false;
+    let mut wal_sync_policy = WalSyncPolicy::default();
 
     if let Ok(val) = std::env::var("ALEX_DB_DATA_DIR") {
         data_dir = Some(val)
@@ -39,6 +53,26 @@ pub fn load(args: Args) -> Result<Config> {
         enable_security_api_keys = val
     }
 
+    if let Ok(val) = std::env::var("ALEX_DB_ENCRYPTION_KEY") {
+        encryption_key = Some(val.parse::<EncryptionKey>()?)
+    }
+
+    if let Ok(val) = std::env::var("ALEX_DB_ENCRYPTION_KEY_FILE") {
+        encryption_key = Some(std::fs::read_to_string(val)?.parse::<EncryptionKey>()?)
+    }
+
+    if let Some(val) = args.encryption_key_file {
+        encryption_key = Some(std::fs::read_to_string(val)?.parse::<EncryptionKey>()?)
+    }
+
+    if let Ok(val) = std::env::var("ALEX_DB_METRICS_REQUIRE_API_KEY") {
+        metrics_require_api_key = val.parse::<bool>()?
+    }
+
+    if let Some(val) = args.metrics_require_api_key {
+        metrics_require_api_key = val
+    }
+
     if let Ok(val) = std::env::var("ALEX_DB_PORT") {
         port = val.parse::<u16>()?
     }
@@ -47,6 +81,18 @@ pub fn load(args: Args) -> Result<Config> {
         port = val
     }
 
+    if let Ok(val) = std::env::var("ALEX_DB_PREVIOUS_ENCRYPTION_KEYS") {
+        previous_encryption_keys = parse_encryption_keys(&val)?
+    }
+
+    if let Ok(val) = std::env::var("ALEX_DB_PREVIOUS_ENCRYPTION_KEYS_FILE") {
+        previous_encryption_keys = parse_encryption_keys(&std::fs::read_to_string(val)?)?
+    }
+
+    if let Some(val) = args.previous_encryption_keys_file {
+        previous_encryption_keys = parse_encryption_keys(&std::fs::read_to_string(val)?)?
+    }
+
     if let Ok(val) = std::env::var("ALEX_DB_SAVE_TRIGGERED_AFTER_MS") {
         save_triggered_after_ms = val.parse::<i64>()?
     }
@@ -79,9 +125,39 @@ pub fn load(args: Args) -> Result<Config> {
         sleep_time_between_saves_ms = val
     }
 
+    if let Ok(val) = std::env::var("ALEX_DB_SNAPSHOT_COMPRESSION") {
+        snapshot_compression = val.parse::<SnapshotCompression>()?
+    }
+
+    if let Some(val) = args.snapshot_compression {
+        snapshot_compression = val
+    }
+
+    if let Ok(val) = std::env::var("ALEX_DB_USE_PERSISTED_INDEXES") {
+        use_persisted_indexes = val.parse::<bool>()?
+    }
+
+    if let Some(val) = args.use_persisted_indexes {
+        use_persisted_indexes = val
+    }
+
+    if let Ok(val) = std::env::var("ALEX_DB_WAL_SYNC_POLICY") {
+        wal_sync_policy = val.parse::<WalSyncPolicy>()?
+    }
+
+    if let Some(val) = args.wal_sync_policy {
+        wal_sync_policy = val
+    }
+
     info!("data_dir = {:?}", data_dir);
     info!("enable_security_api_keys = {}", enable_security_api_keys);
+    info!("encryption = {}", encryption_key.is_some());
+    info!("metrics_require_api_key = {}", metrics_require_api_key);
     info!("port = {}", port);
+    info!(
+        "previous_encryption_keys = {}",
+        previous_encryption_keys.len()
+    );
     info!("save_triggered_after_ms = {}", save_triggered_after_ms);
     info!(
         "save_triggered_by_threshold = {}",
@@ -92,17 +168,39 @@ pub fn load(args: Args) -> Result<Config> {
         "sleep_time_between_saves_ms = {}",
         sleep_time_between_saves_ms
     );
+    info!("snapshot_compression = {}", snapshot_compression);
+    info!("use_persisted_indexes = {}", use_persisted_indexes);
+    info!("wal_sync_policy = {}", wal_sync_policy);
 
     let db_config = DbConfig::new(
         data_dir,
         enable_security_api_keys,
+        encryption_key,
+        previous_encryption_keys,
         save_triggered_after_ms,
         save_triggered_by_threshold,
         sleep_time_between_gc_ms,
         sleep_time_between_saves_ms,
+        snapshot_compression,
+        use_persisted_indexes,
+        wal_sync_policy,
     );
 
-    let config = Config::new(db_config, port);
+    let mut config = Config::new(db_config, port);
+    config.metrics_require_api_key = metrics_require_api_key;
 
     Ok(config)
 }
+
+fn parse_encryption_keys(val: &str) -> Result<Vec<EncryptionKey>> {
+    let mut encryption_keys = vec![];
+
+    for encryption_key in val
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|encryption_key| !encryption_key.is_empty())
+    {
+        encryption_keys.append(&mut vec![encryption_key.parse::<EncryptionKey>()?]);
+    }
+
+    Ok(encryption_keys)
+}
@@ -1,3 +1,4 @@
+use alex_db_lib::error::Error as DbError;
 use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
@@ -13,9 +14,15 @@ use validator::ValidationErrors;
 #[derive(Debug)]
 pub enum AppError {
     Conflict,
+    Cursor,
+    Expiry,
+    ExpiryRange,
     Generic(Box<dyn Error + Send + Sync>),
     Header(ToStrError),
+    KeyspaceName,
     NotFound,
+    PreconditionFailed,
+    Transaction(String),
     Unauthorized,
     Uuid(uuid::Error),
     Validation(ValidationErrors),
@@ -24,17 +31,43 @@ pub enum AppError {
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
         let (status, error_message) = match self {
-            AppError::Conflict => (StatusCode::CONFLICT, "Conflicting request."),
-            AppError::Generic(_error) => (StatusCode::INTERNAL_SERVER_ERROR, "Generic error."),
-            AppError::Header(_error) => (StatusCode::BAD_REQUEST, "Invalid header."),
-            AppError::NotFound => (StatusCode::NOT_FOUND, "Not found."),
-            AppError::Unauthorized => (StatusCode::UNAUTHORIZED, "Unauthorized request."),
-            AppError::Uuid(_error) => (StatusCode::BAD_REQUEST, "Invalid API key."),
-            AppError::Validation(_error) => (StatusCode::BAD_REQUEST, "Invalid key."),
+            AppError::Conflict => (StatusCode::CONFLICT, "Conflicting request.".to_string()),
+            AppError::Cursor => (StatusCode::BAD_REQUEST, "Invalid cursor.".to_string()),
+            AppError::Expiry => (
+                StatusCode::BAD_REQUEST,
+                "Expiration time or time to live is required.".to_string(),
+            ),
+            AppError::ExpiryRange => (
+                StatusCode::BAD_REQUEST,
+                "Expiration time is out of range.".to_string(),
+            ),
+            AppError::Generic(_error) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Generic error.".to_string(),
+            ),
+            AppError::Header(_error) => (StatusCode::BAD_REQUEST, "Invalid header.".to_string()),
+            AppError::KeyspaceName => (
+                StatusCode::BAD_REQUEST,
+                "Invalid keyspace name.".to_string(),
+            ),
+            AppError::NotFound => (StatusCode::NOT_FOUND, "Not found.".to_string()),
+            AppError::PreconditionFailed => (
+                StatusCode::PRECONDITION_FAILED,
+                "Version does not match.".to_string(),
+            ),
+            AppError::Transaction(error) => (StatusCode::CONFLICT, error),
+            AppError::Unauthorized => (
+                StatusCode::UNAUTHORIZED,
+                "Unauthorized request.".to_string(),
+            ),
+            AppError::Uuid(_error) => (StatusCode::BAD_REQUEST, "Invalid API key.".to_string()),
+            AppError::Validation(_error) => {
+                (StatusCode::BAD_REQUEST, "Invalid request.".to_string())
+            }
         };
 
         let body = Json(json!(ResponseError {
-            error: error_message.to_string(),
+            error: error_message,
         }));
 
         (status, body).into_response()
@@ -43,7 +76,18 @@ impl IntoResponse for AppError {
 
 impl From<Box<dyn Error + Send + Sync>> for AppError {
     fn from(inner: Box<dyn Error + Send + Sync>) -> Self {
-        AppError::Generic(inner)
+        match inner.downcast_ref::<DbError>() {
+            Some(DbError::CursorParse) => AppError::Cursor,
+            Some(DbError::ExpiryMissing) => AppError::Expiry,
+            Some(DbError::ExpiryRange) => AppError::ExpiryRange,
+            Some(DbError::KeyExists) => AppError::Conflict,
+            Some(DbError::KeyspaceDefault) => AppError::Conflict,
+            Some(DbError::KeyspaceName) => AppError::KeyspaceName,
+            Some(DbError::NotFound) => AppError::NotFound,
+            Some(DbError::ValueType) => AppError::Conflict,
+            Some(DbError::VersionMismatch) => AppError::PreconditionFailed,
+            _ => AppError::Generic(inner),
+        }
     }
 }
 
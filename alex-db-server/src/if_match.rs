@@ -0,0 +1,60 @@
+use crate::error::AppError;
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{header, request::Parts, HeaderValue},
+};
+use std::convert::Infallible;
+
+/// Version of the record expected by the `If-Match` header.
+///
+/// The header carries a single strong entity tag holding the version, or `*` matching any version.
+#[derive(Clone, Debug)]
+pub struct IfMatch {
+    if_match_header: Option<HeaderValue>,
+}
+
+impl IfMatch {
+    /// Returns the version expected by the header, falling back to the one from the request body.
+    /// An entity tag that is not a version can't match any record.
+    pub fn expected_version(&self, expected_version: Option<u64>) -> Result<Option<u64>, AppError> {
+        let if_match_header = match &self.if_match_header {
+            None => return Ok(expected_version),
+            Some(if_match_header) => if_match_header,
+        };
+
+        match if_match_header.to_str()?.trim() {
+            "*" => Ok(expected_version),
+            entity_tag => {
+                let version = entity_tag
+                    .strip_prefix('"')
+                    .and_then(|entity_tag| entity_tag.strip_suffix('"'))
+                    .and_then(|version| version.parse::<u64>().ok())
+                    .ok_or(AppError::PreconditionFailed)?;
+
+                Ok(Some(version))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for IfMatch
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let if_match = IfMatch {
+            if_match_header: parts.headers.get(header::IF_MATCH).cloned(),
+        };
+
+        Ok(if_match)
+    }
+}
+
+/// Formats the version of a record as a strong entity tag.
+pub fn entity_tag(version: u64) -> String {
+    format!("\"{version}\"")
+}
@@ -0,0 +1,40 @@
+use crate::error::AppError;
+use alex_db_lib::{db::Db, keyspaces::Keyspaces};
+use axum::{
+    async_trait,
+    extract::{FromRef, FromRequestParts, Path},
+    http::request::Parts,
+};
+use std::{collections::HashMap, sync::Arc};
+
+/// Database of the keyspace a request is routed to.
+///
+/// Routes nested under `/keyspaces/:name` use the keyspace named in the path, all other routes
+/// use the default keyspace.
+#[derive(Clone, Debug)]
+pub struct Keyspace(pub Arc<Db>);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for Keyspace
+where
+    Arc<Keyspaces>: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let keyspaces = Arc::<Keyspaces>::from_ref(state);
+
+        let name = Path::<HashMap<String, String>>::from_request_parts(parts, state)
+            .await
+            .ok()
+            .and_then(|Path(params)| params.get("name").cloned());
+
+        let db = match name {
+            None => keyspaces.default_keyspace(),
+            Some(name) => keyspaces.get(&name)?.ok_or(AppError::NotFound)?,
+        };
+
+        Ok(Keyspace(db))
+    }
+}
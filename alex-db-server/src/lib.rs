@@ -1,8 +1,9 @@
+use alex_db_lib::{snapshot::SnapshotCompression, wal::WalSyncPolicy};
 use clap::Parser;
 use std::{error::Error, net::SocketAddr};
 use tokio::{
-    task,
-    time::{sleep, Duration},
+    signal, task,
+    time::{sleep, Duration, Instant},
 };
 use tracing::{error, info};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -14,6 +15,9 @@ mod api;
 mod app;
 mod config;
 mod error;
+mod if_match;
+mod keyspace;
+mod metrics;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -26,10 +30,22 @@ pub struct Args {
     #[arg(short, long)]
     pub enable_security_api_keys: Option<bool>,
 
+    /// File with the encryption key (64 hex characters) for data at rest
+    #[arg(long)]
+    pub encryption_key_file: Option<String>,
+
+    /// Require an API key for the metrics endpoint
+    #[arg(long)]
+    pub metrics_require_api_key: Option<bool>,
+
     /// Port
     #[arg(short, long)]
     pub port: Option<u16>,
 
+    /// File with previous encryption keys still accepted for decryption, one per line
+    #[arg(long)]
+    pub previous_encryption_keys_file: Option<String>,
+
     /// Database save triggered after write operations threshold
     #[arg(long)]
     pub save_triggered_by_threshold: Option<u16>,
@@ -45,6 +61,18 @@ pub struct Args {
     /// Sleep time between database saves in ms
     #[arg(long)]
     pub sleep_time_between_saves_ms: Option<u64>,
+
+    /// Snapshot compression codec (none, lz4 or zstd)
+    #[arg(long)]
+    pub snapshot_compression: Option<SnapshotCompression>,
+
+    /// Load indexes persisted in the snapshot instead of rebuilding them
+    #[arg(long)]
+    pub use_persisted_indexes: Option<bool>,
+
+    /// Write-ahead log sync policy (always, never or interval in ms)
+    #[arg(long)]
+    pub wal_sync_policy: Option<WalSyncPolicy>,
 }
 
 pub async fn run() -> Result<()> {
@@ -62,10 +90,14 @@ pub async fn run() -> Result<()> {
 
     let app = app::get_app(config.clone()).await?;
 
-    let db_for_deleting = app.db.clone();
-    task::spawn(async move {
+    if app.api_key.is_some() {
+        info!("initial api key created: {:?}", app.api_key);
+    }
+
+    let keyspaces_for_deleting = app.keyspaces.clone();
+    let gc_task = task::spawn(async move {
         loop {
-            let res = db_for_deleting.gc();
+            let res = keyspaces_for_deleting.gc();
 
             if let Err(e) = res {
                 error!("Error: {:?}", e);
@@ -78,10 +110,10 @@ pub async fn run() -> Result<()> {
         }
     });
 
-    let db_for_saving = app.db;
-    task::spawn(async move {
+    let keyspaces_for_saving = app.keyspaces.clone();
+    let save_task = task::spawn(async move {
         loop {
-            let res = db_for_saving.save();
+            let res = keyspaces_for_saving.save();
 
             if let Err(e) = res {
                 error!("Error: {:?}", e);
@@ -94,12 +126,67 @@ pub async fn run() -> Result<()> {
         }
     });
 
+    // Entries appended right before a quiet period are synced by this task, as no later append
+    // comes to sync them.
+    let keyspaces_for_syncing = app.keyspaces.clone();
+    let wal_sync_task = match config.db_config.wal_sync_policy {
+        WalSyncPolicy::EveryMs(ms) if ms > 0 => Some(task::spawn(async move {
+            loop {
+                sleep(Duration::from_millis(ms)).await;
+
+                let res = keyspaces_for_syncing.sync_wal();
+
+                if let Err(e) = res {
+                    error!("Error: {:?}", e);
+                }
+            }
+        })),
+        _ => None,
+    };
+
     let addr = SocketAddr::from(([0, 0, 0, 0], config.port));
     info!("listening on {}", addr);
     axum::Server::bind(&addr)
         .serve(app.router.into_make_service())
-        .await
-        .unwrap();
+        .with_graceful_shutdown(shutdown_signal())
+        .await?;
+
+    gc_task.abort();
+    save_task.abort();
+    if let Some(wal_sync_task) = wal_sync_task {
+        wal_sync_task.abort();
+    }
+
+    info!("saving database before shutdown");
+    let started_at = Instant::now();
+    app.keyspaces.force_save()?;
+    info!("final save completed in {:?}", started_at.elapsed());
 
     Ok(())
 }
+
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        signal::ctrl_c()
+            .await
+            .expect("failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        signal::unix::signal(signal::unix::SignalKind::terminate())
+            .expect("failed to install signal handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+
+    info!("shutdown signal received, draining in-flight requests");
+}
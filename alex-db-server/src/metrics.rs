@@ -0,0 +1,402 @@
+use crate::Result;
+use alex_db_lib::stat_record::StatRecord;
+use axum::{
+    extract::{MatchedPath, State},
+    http::Request,
+    middleware::Next,
+    response::Response,
+};
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+use tracing::error;
+
+const LATENCY_BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Stats of a keyspace rendered by the metrics endpoint.
+#[derive(Debug)]
+pub struct KeyspaceStats {
+    pub keys: usize,
+    pub keys_with_ttl: usize,
+    pub name: String,
+    pub stat_record: StatRecord,
+}
+
+/// Request latencies and settings of the metrics endpoint.
+#[derive(Debug)]
+pub struct Metrics {
+    pub require_api_key: bool,
+    request_durations: Mutex<BTreeMap<(String, String), Histogram>>,
+}
+
+#[derive(Debug, Default)]
+struct Histogram {
+    bucket_counts: [u64; LATENCY_BUCKETS.len()],
+    count: u64,
+    sum: f64,
+}
+
+impl Histogram {
+    fn observe(&mut self, value: f64) {
+        for (bucket_count, le) in self.bucket_counts.iter_mut().zip(LATENCY_BUCKETS) {
+            if value <= le {
+                *bucket_count += 1;
+            }
+        }
+
+        self.count += 1;
+        self.sum += value;
+    }
+}
+
+impl Metrics {
+    pub fn new(require_api_key: bool) -> Self {
+        Self {
+            require_api_key,
+            request_durations: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn observe_request(&self, method: &str, route: &str, seconds: f64) -> Result<()> {
+        let mut request_durations = self
+            .request_durations
+            .lock()
+            .map_err(|_| "request durations lock poisoned")?;
+
+        request_durations
+            .entry((method.to_string(), route.to_string()))
+            .or_default()
+            .observe(seconds);
+
+        Ok(())
+    }
+
+    /// Renders the metrics in the Prometheus text exposition format. Database metrics are
+    /// labeled with the name of their keyspace.
+    pub fn render(&self, keyspaces: &[KeyspaceStats]) -> Result<String> {
+        let mut output = String::new();
+
+        write_metric(
+            &mut output,
+            "alex_db_requests_total",
+            "counter",
+            "Number of database requests.",
+            &samples(keyspaces, |keyspace| {
+                vec![("", keyspace.stat_record.requests.to_string())]
+            }),
+        )?;
+        write_metric(
+            &mut output,
+            "alex_db_reads_total",
+            "counter",
+            "Number of records read.",
+            &samples(keyspaces, |keyspace| {
+                vec![("", keyspace.stat_record.reads.to_string())]
+            }),
+        )?;
+        write_metric(
+            &mut output,
+            "alex_db_writes_total",
+            "counter",
+            "Number of records written.",
+            &samples(keyspaces, |keyspace| {
+                vec![("", keyspace.stat_record.writes.to_string())]
+            }),
+        )?;
+        write_metric(
+            &mut output,
+            "alex_db_saved_writes",
+            "gauge",
+            "Number of writes included in the last save.",
+            &samples(keyspaces, |keyspace| {
+                vec![("", keyspace.stat_record.saved_writes.to_string())]
+            }),
+        )?;
+        write_metric(
+            &mut output,
+            "alex_db_operations_total",
+            "counter",
+            "Number of database operations by kind.",
+            &samples(keyspaces, |keyspace| {
+                let operations = &keyspace.stat_record.operations;
+
+                vec![
+                    ("operation=\"append\"", operations.append.to_string()),
+                    (
+                        "operation=\"batch_delete\"",
+                        operations.batch_delete.to_string(),
+                    ),
+                    (
+                        "operation=\"batch_read\"",
+                        operations.batch_read.to_string(),
+                    ),
+                    ("operation=\"batch_set\"", operations.batch_set.to_string()),
+                    (
+                        "operation=\"compare_and_swap\"",
+                        operations.compare_and_swap.to_string(),
+                    ),
+                    ("operation=\"copy\"", operations.copy.to_string()),
+                    ("operation=\"create\"", operations.create.to_string()),
+                    ("operation=\"decrement\"", operations.decrement.to_string()),
+                    ("operation=\"delete\"", operations.delete.to_string()),
+                    ("operation=\"expire\"", operations.expire.to_string()),
+                    ("operation=\"field\"", operations.field.to_string()),
+                    (
+                        "operation=\"gc_expired\"",
+                        operations.gc_expired.to_string(),
+                    ),
+                    ("operation=\"increment\"", operations.increment.to_string()),
+                    ("operation=\"list\"", operations.list.to_string()),
+                    ("operation=\"persist\"", operations.persist.to_string()),
+                    ("operation=\"pop\"", operations.pop.to_string()),
+                    ("operation=\"prepend\"", operations.prepend.to_string()),
+                    ("operation=\"read\"", operations.read.to_string()),
+                    ("operation=\"rename\"", operations.rename.to_string()),
+                    (
+                        "operation=\"transaction\"",
+                        operations.transaction.to_string(),
+                    ),
+                    ("operation=\"ttl\"", operations.ttl.to_string()),
+                    ("operation=\"update\"", operations.update.to_string()),
+                ]
+            }),
+        )?;
+        write_metric(
+            &mut output,
+            "alex_db_errors_total",
+            "counter",
+            "Number of failed database operations by cause.",
+            &samples(keyspaces, |keyspace| {
+                let errors = &keyspace.stat_record.errors;
+
+                vec![
+                    ("error=\"conflict\"", errors.conflict.to_string()),
+                    ("error=\"lock\"", errors.lock.to_string()),
+                    ("error=\"not_found\"", errors.not_found.to_string()),
+                ]
+            }),
+        )?;
+        write_metric(
+            &mut output,
+            "alex_db_keys",
+            "gauge",
+            "Number of keys.",
+            &samples(keyspaces, |keyspace| vec![("", keyspace.keys.to_string())]),
+        )?;
+        write_metric(
+            &mut output,
+            "alex_db_keys_with_ttl",
+            "gauge",
+            "Number of keys with a time to live.",
+            &samples(keyspaces, |keyspace| {
+                vec![("", keyspace.keys_with_ttl.to_string())]
+            }),
+        )?;
+        write_metric(
+            &mut output,
+            "alex_db_gc_runs_total",
+            "counter",
+            "Number of garbage collector runs.",
+            &samples(keyspaces, |keyspace| {
+                vec![("", keyspace.stat_record.gc_runs.to_string())]
+            }),
+        )?;
+        write_metric(
+            &mut output,
+            "alex_db_gc_expired_total",
+            "counter",
+            "Number of records removed by the garbage collector after expiring.",
+            &samples(keyspaces, |keyspace| {
+                vec![("", keyspace.stat_record.operations.gc_expired.to_string())]
+            }),
+        )?;
+
+        let saved_keyspaces = keyspaces
+            .iter()
+            .filter_map(|keyspace| {
+                keyspace
+                    .stat_record
+                    .last_save
+                    .as_ref()
+                    .map(|last_save| (keyspace, last_save))
+            })
+            .collect::<Vec<_>>();
+
+        if !saved_keyspaces.is_empty() {
+            write_metric(
+                &mut output,
+                "alex_db_last_save_duration_seconds",
+                "gauge",
+                "Duration of the last save.",
+                &saved_keyspaces
+                    .iter()
+                    .map(|(keyspace, last_save)| {
+                        (
+                            keyspace_label(keyspace),
+                            (last_save.duration_us as f64 / 1_000_000.0).to_string(),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            )?;
+            write_metric(
+                &mut output,
+                "alex_db_last_save_success",
+                "gauge",
+                "Whether the last save succeeded.",
+                &saved_keyspaces
+                    .iter()
+                    .map(|(keyspace, last_save)| {
+                        (
+                            keyspace_label(keyspace),
+                            u8::from(last_save.succeeded).to_string(),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            )?;
+            write_metric(
+                &mut output,
+                "alex_db_last_save_timestamp_seconds",
+                "gauge",
+                "Time when the last save finished.",
+                &saved_keyspaces
+                    .iter()
+                    .map(|(keyspace, last_save)| {
+                        (
+                            keyspace_label(keyspace),
+                            last_save.finished_at.timestamp().to_string(),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            )?;
+        }
+
+        let request_durations = self
+            .request_durations
+            .lock()
+            .map_err(|_| "request durations lock poisoned")?;
+
+        writeln!(
+            output,
+            "# HELP alex_db_http_request_duration_seconds Duration of HTTP requests by route."
+        )?;
+        writeln!(
+            output,
+            "# TYPE alex_db_http_request_duration_seconds histogram"
+        )?;
+        for ((method, route), histogram) in request_durations.iter() {
+            let labels = format!("method=\"{method}\",route=\"{}\"", escape(route));
+
+            for (bucket_count, le) in histogram.bucket_counts.iter().zip(LATENCY_BUCKETS) {
+                writeln!(
+                    output,
+                    "alex_db_http_request_duration_seconds_bucket{{{labels},le=\"{le}\"}} {bucket_count}"
+                )?;
+            }
+            writeln!(
+                output,
+                "alex_db_http_request_duration_seconds_bucket{{{labels},le=\"+Inf\"}} {}",
+                histogram.count
+            )?;
+            writeln!(
+                output,
+                "alex_db_http_request_duration_seconds_sum{{{labels}}} {}",
+                histogram.sum
+            )?;
+            writeln!(
+                output,
+                "alex_db_http_request_duration_seconds_count{{{labels}}} {}",
+                histogram.count
+            )?;
+        }
+
+        Ok(output)
+    }
+}
+
+/// Middleware measuring the latency of requests by matched route.
+pub async fn track<B>(
+    State(metrics): State<Arc<Metrics>>,
+    request: Request<B>,
+    next: Next<B>,
+) -> Response {
+    let method = request.method().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|matched_path| matched_path.as_str().to_string());
+    let started_at = Instant::now();
+
+    let response = next.run(request).await;
+
+    if let Some(route) = route {
+        let res = metrics.observe_request(&method, &route, started_at.elapsed().as_secs_f64());
+
+        if let Err(e) = res {
+            error!("Error: {:?}", e);
+        }
+    }
+
+    response
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn keyspace_label(keyspace: &KeyspaceStats) -> String {
+    format!("keyspace=\"{}\"", escape(&keyspace.name))
+}
+
+/// Collects the samples of a metric from all keyspaces, adding the keyspace label
+/// to the labels of each sample.
+fn samples<F>(keyspaces: &[KeyspaceStats], keyspace_samples: F) -> Vec<(String, String)>
+where
+    F: Fn(&KeyspaceStats) -> Vec<(&'static str, String)>,
+{
+    let mut samples = vec![];
+
+    for keyspace in keyspaces {
+        let keyspace_label = keyspace_label(keyspace);
+
+        for (labels, value) in keyspace_samples(keyspace) {
+            let labels = if labels.is_empty() {
+                keyspace_label.clone()
+            } else {
+                format!("{keyspace_label},{labels}")
+            };
+
+            samples.append(&mut vec![(labels, value)]);
+        }
+    }
+
+    samples
+}
+
+fn write_metric(
+    output: &mut String,
+    name: &str,
+    metric_type: &str,
+    help: &str,
+    samples: &[(String, String)],
+) -> Result<()> {
+    writeln!(output, "# HELP {name} {help}")?;
+    writeln!(output, "# TYPE {name} {metric_type}")?;
+
+    for (labels, value) in samples {
+        if labels.is_empty() {
+            writeln!(output, "{name} {value}")?;
+        } else {
+            writeln!(output, "{name}{{{labels}}} {value}")?;
+        }
+    }
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use alex_db_lib::{
     config::Config,
-    db::{Db, Direction, Sort},
+    db::{Db, Direction, ListFilter, Sort},
     value_record::{
         Value, ValueAppend, ValueDecrement, ValueIncrement, ValuePopBack, ValuePopFront, ValuePost,
         ValuePrepend, ValuePut,
@@ -14,15 +14,24 @@ fn main() {
 
     println!("Our starting point is an empty database.");
     let value_responses = db
-        .list(Direction::Asc, None, None, Sort::CreatedAt)
+        .list(
+            None,
+            Direction::Asc,
+            &ListFilter::default(),
+            None,
+            None,
+            Sort::CreatedAt,
+        )
         .unwrap();
     println!("List value_responses = {value_responses:?}\n");
 
     let key = "test_key";
     println!("Our next step is to create a record in the database.");
     let value_post = ValuePost {
+        expire_at: None,
         key: key.to_string(),
         ttl: None,
+        ttl_ms: None,
         value: Value::String("test_value".to_string()),
     };
     let value_response = db.try_create(value_post).unwrap().unwrap();
@@ -34,7 +43,12 @@ fn main() {
 
     println!("Our next step is to update a record in the database.");
     let value_put = ValuePut {
+        expected_version: None,
+        expire_at: None,
+        keep_ttl: false,
         ttl: None,
+        ttl_ms: None,
+        upsert: false,
         value: Value::Integer(10),
     };
     let value_response = db.try_update(key, value_put).unwrap().unwrap();
@@ -42,33 +56,61 @@ fn main() {
 
     println!("Our next step is to list values from the database.");
     let value_responses = db
-        .list(Direction::Asc, None, None, Sort::CreatedAt)
+        .list(
+            None,
+            Direction::Asc,
+            &ListFilter::default(),
+            None,
+            None,
+            Sort::CreatedAt,
+        )
         .unwrap();
     println!("List value_responses = {value_responses:?}\n");
 
     println!("Our next steps is to increment a value in the database.");
-    let value_increment = ValueIncrement { increment: None };
+    let value_increment = ValueIncrement {
+        create: false,
+        expected_version: None,
+        increment: None,
+        ttl: None,
+    };
     let value_response = db.try_increment(key, value_increment).unwrap().unwrap();
     println!("Increment value_response = {value_response:?}");
     let value_increment = ValueIncrement {
+        create: false,
+        expected_version: None,
         increment: Some(10),
+        ttl: None,
     };
     let value_response = db.try_increment(key, value_increment).unwrap().unwrap();
     println!("Increment value_response = {value_response:?}\n");
 
     println!("Our next steps is to decrement the value in the database.");
-    let value_decrement = ValueDecrement { decrement: None };
+    let value_decrement = ValueDecrement {
+        create: false,
+        decrement: None,
+        expected_version: None,
+        ttl: None,
+    };
     let value_response = db.try_decrement(key, value_decrement).unwrap().unwrap();
     println!("Decrement value_response = {value_response:?}");
     let value_decrement = ValueDecrement {
+        create: false,
         decrement: Some(10),
+        expected_version: None,
+        ttl: None,
     };
     let value_response = db.try_decrement(key, value_decrement).unwrap().unwrap();
     println!("Decrement value_response = {value_response:?}\n");
 
     println!("Our next step is to update a record in the database.");
     let value_put = ValuePut {
+        expected_version: None,
+        expire_at: None,
+        keep_ttl: false,
         ttl: None,
+        ttl_ms: None,
+        upsert: false,
         value: Value::Array(VecDeque::from([Value::String("value1".to_string())])),
     };
     let value_response = db.try_update(key, value_put).unwrap().unwrap();
@@ -79,6 +121,9 @@ fn main() {
         append: Value::Array(VecDeque::from([Value::String(
             "appended-value1".to_string(),
         )])),
+        create: false,
+        expected_version: None,
+        ttl: None,
     };
     let value_response = db.try_append(key, value_append).unwrap().unwrap();
     println!("Append value_response = {value_response:?}\n");
@@ -89,31 +134,43 @@ fn main() {
             Value::String("appended-value2".to_string()),
             Value::String("appended-value3".to_string()),
         ])),
+        create: false,
+        expected_version: None,
+        ttl: None,
     };
     let value_response = db.try_append(key, value_append).unwrap().unwrap();
     println!("Append value_response = {value_response:?}\n");
 
     println!("Our next step is to prepend a value to the database.");
     let value_prepend = ValuePrepend {
+        create: false,
+        expected_version: None,
         prepend: Value::Array(VecDeque::from([Value::String(
             "prepended-value1".to_string(),
         )])),
+        ttl: None,
     };
     let value_response = db.try_prepend(key, value_prepend).unwrap().unwrap();
     println!("Prepend value_response = {value_response:?}\n");
 
     println!("Our next step is to prepend more values to the database.");
     let value_prepend = ValuePrepend {
+        create: false,
+        expected_version: None,
         prepend: Value::Array(VecDeque::from([
             Value::String("prepended-value2".to_string()),
             Value::String("prepended-value3".to_string()),
         ])),
+        ttl: None,
     };
     let value_response = db.try_prepend(key, value_prepend).unwrap().unwrap();
     println!("Prepend value_response = {value_response:?}\n");
 
     println!("Our next step is to pop back a value from the database.");
-    let value_pop_back = ValuePopBack { pop_back: None };
+    let value_pop_back = ValuePopBack {
+        expected_version: None,
+        pop_back: None,
+    };
     let value_response = db.try_pop_back(key, value_pop_back).unwrap().unwrap();
     println!("Pop back value_response = {value_response:?}\n");
 
@@ -122,7 +179,10 @@ fn main() {
     println!("Read value_response = {value_response:?}\n");
 
     println!("Our next step is to pop back more values from the database.");
-    let value_pop_back = ValuePopBack { pop_back: Some(2) };
+    let value_pop_back = ValuePopBack {
+        expected_version: None,
+        pop_back: Some(2),
+    };
     let value_response = db.try_pop_back(key, value_pop_back).unwrap().unwrap();
     println!("Pop back value_response = {value_response:?}\n");
 
@@ -131,7 +191,10 @@ fn main() {
     println!("Read value_response = {value_response:?}\n");
 
     println!("Our next step is to pop front a value from the database.");
-    let value_pop_front = ValuePopFront { pop_front: None };
+    let value_pop_front = ValuePopFront {
+        expected_version: None,
+        pop_front: None,
+    };
     let value_response = db.try_pop_front(key, value_pop_front).unwrap().unwrap();
     println!("Pop front value_response = {value_response:?}\n");
 
@@ -140,7 +203,10 @@ fn main() {
     println!("Read value_response = {value_response:?}\n");
 
     println!("Our next step is to pop front more values from the database.");
-    let value_pop_front = ValuePopFront { pop_front: Some(2) };
+    let value_pop_front = ValuePopFront {
+        expected_version: None,
+        pop_front: Some(2),
+    };
     let value_response = db.try_pop_front(key, value_pop_front).unwrap().unwrap();
     println!("Pop front value_response = {value_response:?}\n");
 
@@ -149,12 +215,19 @@ fn main() {
     println!("Read value_response = {value_response:?}\n");
 
     println!("Our next step is to delete a record from the database.");
-    let value_response = db.try_delete(key).unwrap().unwrap();
+    let value_response = db.try_delete(key, None).unwrap().unwrap();
     println!("Read value_response = {value_response:?}\n");
 
     println!("Our next step is to list values from the database.");
     let value_responses = db
-        .list(Direction::Asc, None, None, Sort::CreatedAt)
+        .list(
+            None,
+            Direction::Asc,
+            &ListFilter::default(),
+            None,
+            None,
+            Sort::CreatedAt,
+        )
         .unwrap();
     println!("List value_responses = {value_responses:?}\n");
 }